@@ -1,3 +1,6 @@
+// Without the `generate` feature the generator is compiled, but not run.
+#![cfg_attr(not(feature = "generate"), allow(dead_code, unused_imports))]
+
 use std::{
     collections::HashMap,
     fmt::Display,
@@ -11,6 +14,10 @@ use once_cell::sync::Lazy;
 use quote::{quote, ToTokens, __private::TokenStream};
 use regex::Regex;
 
+#[path = "build/names.rs"]
+mod names;
+use names::{screaming_snake_case, variant_name};
+
 pub struct FuncDef {
     name: String,
     returns: String,
@@ -31,6 +38,7 @@ impl FuncDef {
         static C_TYPES: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
             let mut m = HashMap::new();
             m.insert("void", "()");
+            m.insert("void*", stringify!(*mut std::ffi::c_void));
             m.insert("int", stringify!(std::ffi::c_int));
             m.insert("int*", stringify!(*mut std::ffi::c_int));
             m.insert("const char*", stringify!(*const std::ffi::c_char));
@@ -147,9 +155,6 @@ pub fn walk_header(header: impl Into<PathBuf>) -> Result<Headers, String> {
                 .as_str()
                 .trim_start_matches("ImGui_")
                 .to_string();
-            if name.contains("ValidatePtr") {
-                continue;
-            }
             let returns = s
                 .get(1)
                 .expect(format!("Can not parse returns of func def: {:?}", s).as_str())
@@ -213,9 +218,36 @@ pub fn func_extern(fdef: &FuncDef) -> TokenStream {
         .map(|c_type| c_type.parse::<TokenStream>().expect("Can no parse"));
     quote!(fn(#(#c_types),*) -> #returns)
 }
-pub fn func_method(fdef: &FuncDef) -> TokenStream {
+/// Checks of the handle arguments, compiled only with `debug-validate` feature.
+pub fn func_validations(fdef: &FuncDef, class_defs: &[String]) -> TokenStream {
+    let name = fdef.name.as_str();
+    let validations = fdef
+        .arg_types
+        .iter()
+        .zip(fdef.args.iter())
+        .filter(|(c_type, _)| class_defs.contains(c_type))
+        .map(|(c_type, arg)| {
+            let type_name = format!("ImGui_{}*", c_type);
+            let allow_null = c_type == "Font";
+            let arg_name = arg.trim();
+            let arg = arg.parse::<TokenStream>().expect("Can no parse");
+            quote!(
+                #[cfg(feature = "debug-validate")]
+                self.validate_handle(
+                    #arg,
+                    c_str_macro::c_str!(#type_name),
+                    #allow_null,
+                    #name,
+                    #arg_name,
+                );
+            )
+        });
+    quote!(#(#validations)*)
+}
+pub fn func_method(fdef: &FuncDef, class_defs: &[String]) -> TokenStream {
     let returns = fdef.returns.parse::<TokenStream>().expect("Can no parse");
     let name = fdef.name.parse::<TokenStream>().expect("Can no parse");
+    let validations = func_validations(fdef, class_defs);
     let c_types = fdef
         .arg_types
         .iter()
@@ -231,6 +263,9 @@ pub fn func_method(fdef: &FuncDef) -> TokenStream {
     let args1 = args.clone();
     quote!(
         pub unsafe fn #name(&self, #(#args: #c_types),*) -> #returns{
+            #[cfg(debug_assertions)]
+            self.assert_main_thread(stringify!(#name));
+            #validations
             match self.pointers.#name{
                 None => panic!(
                     "Attempt to use a function that has not been loaded: {}",
@@ -242,10 +277,314 @@ pub fn func_method(fdef: &FuncDef) -> TokenStream {
     )
 }
 
+/// Families of constants, that get a typed enum in the bindings: name and doc.
+const ENUM_FAMILIES: &[(&str, &str)] = &[
+    ("Col", "Color of a style element (`Col_*`)."),
+    ("StyleVar", "Style variable (`StyleVar_*`)."),
+    ("Dir", "Direction (`Dir_*`)."),
+    ("Cond", "Condition for the `SetNext*` functions (`Cond_*`)."),
+    ("MouseCursor", "Mouse cursor shape (`MouseCursor_*`)."),
+    ("MouseButton", "Mouse button (`MouseButton_*`)."),
+    ("TableBgTarget", "Table background to color (`TableBgTarget_*`)."),
+    ("SortDirection", "Table sort direction (`SortDirection_*`)."),
+    ("Key", "Keyboard key or mouse input (`Key_*`)."),
+];
+
+/// Flag families, which prefix does not end with `Flags`: type name, prefix
+/// and doc.
+const EXTRA_FLAG_FAMILIES: &[(&str, &str, &str)] = &[(
+    "Mods",
+    "Mod",
+    "Combination of key modifiers (`Mod_*`).",
+)];
+
+/// Enum of all constants with the `family` prefix, with conversion to the
+/// runtime value.
+pub fn enum_family(family: &str, doc: &str, constants: &[String]) -> TokenStream {
+    let prefix = format!("{}_", family);
+    let members: Vec<&String> = constants
+        .iter()
+        .filter(|name| name.starts_with(&prefix))
+        .collect();
+    let enum_name = family.parse::<TokenStream>().expect("Can no parse");
+    let variants = members.iter().map(|name| {
+        variant_name(name.trim_start_matches(&prefix))
+            .parse::<TokenStream>()
+            .expect("Can no parse")
+    });
+    let variants1 = variants.clone();
+    let variants2 = variants.clone();
+    let variants3 = variants.clone();
+    let const_names = members
+        .iter()
+        .map(|name| name.parse::<TokenStream>().expect("Can no parse"));
+    let names = members.iter().map(|name| name.trim_start_matches(&prefix));
+    quote!(
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum #enum_name {
+            #(#variants,)*
+        }
+        impl #enum_name {
+            /// All the members, in the order of declaration.
+            pub const ALL: &'static [Self] = &[#(Self::#variants1,)*];
+
+            /// Name of the constant without the family prefix.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #(Self::#variants2 => #names,)*
+                }
+            }
+
+            /// Value of the constant in the loaded ReaImGui, if provided.
+            pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+                match self {
+                    #(Self::#variants3 => imgui.#const_names,)*
+                }
+            }
+
+            /// Value of the constant in the loaded ReaImGui.
+            ///
+            /// Panics if the installed ReaImGui does not provide it.
+            pub fn to_raw(self, imgui: &ImGui) -> i32 {
+                match self.try_to_raw(imgui) {
+                    Some(value) => value,
+                    None => panic!(
+                        "Attempt to use a constant that has not been loaded: {}{}",
+                        #prefix,
+                        self.name()
+                    ),
+                }
+            }
+
+            /// Member with the runtime value, if any.
+            pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|member| member.try_to_raw(imgui) == Some(raw))
+            }
+        }
+        impl std::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+    )
+}
+
+/// Flags type for every `*Flags_` family of constants and for
+/// [`EXTRA_FLAG_FAMILIES`].
+pub fn flag_families(constants: &[String]) -> Vec<TokenStream> {
+    let mut families: Vec<&str> = constants
+        .iter()
+        .filter_map(|name| name.split_once('_').map(|(family, _)| family))
+        .filter(|family| family.ends_with("Flags"))
+        .collect();
+    families.sort();
+    families.dedup();
+    families
+        .into_iter()
+        .map(|family| {
+            let doc = format!("Combination of `{}_*`.", family);
+            flag_family(family, family, &doc, constants)
+        })
+        .chain(
+            EXTRA_FLAG_FAMILIES
+                .iter()
+                .map(|(name, family, doc)| flag_family(name, family, doc, constants)),
+        )
+        .collect()
+}
+
+/// Flag constants, that are combinations of other members of their family:
+/// constant and the members it is made of. They are generated as the union
+/// of the members, so that `contains`, `remove` and `!` agree with the
+/// runtime values.
+const COMPOSITE_FLAGS: &[(&str, &[&str])] = &[
+    (
+        "DragDropFlags_AcceptPeekOnly",
+        &["AcceptBeforeDelivery", "AcceptNoDrawDefaultRect"],
+    ),
+    (
+        "DrawFlags_RoundCornersAll",
+        &[
+            "RoundCornersTopLeft",
+            "RoundCornersTopRight",
+            "RoundCornersBottomLeft",
+            "RoundCornersBottomRight",
+        ],
+    ),
+    (
+        "DrawFlags_RoundCornersBottom",
+        &["RoundCornersBottomLeft", "RoundCornersBottomRight"],
+    ),
+    (
+        "DrawFlags_RoundCornersLeft",
+        &["RoundCornersTopLeft", "RoundCornersBottomLeft"],
+    ),
+    (
+        "DrawFlags_RoundCornersRight",
+        &["RoundCornersTopRight", "RoundCornersBottomRight"],
+    ),
+    (
+        "DrawFlags_RoundCornersTop",
+        &["RoundCornersTopLeft", "RoundCornersTopRight"],
+    ),
+    (
+        "FocusedFlags_RootAndChildWindows",
+        &["RootWindow", "ChildWindows"],
+    ),
+    (
+        "HoveredFlags_RectOnly",
+        &[
+            "AllowWhenBlockedByPopup",
+            "AllowWhenBlockedByActiveItem",
+            "AllowWhenOverlapped",
+        ],
+    ),
+    (
+        "HoveredFlags_RootAndChildWindows",
+        &["RootWindow", "ChildWindows"],
+    ),
+    ("PopupFlags_AnyPopup", &["AnyPopupId", "AnyPopupLevel"]),
+    (
+        "TableFlags_Borders",
+        &[
+            "BordersInnerH",
+            "BordersOuterH",
+            "BordersInnerV",
+            "BordersOuterV",
+        ],
+    ),
+    ("TableFlags_BordersH", &["BordersInnerH", "BordersOuterH"]),
+    (
+        "TableFlags_BordersInner",
+        &["BordersInnerV", "BordersInnerH"],
+    ),
+    (
+        "TableFlags_BordersOuter",
+        &["BordersOuterV", "BordersOuterH"],
+    ),
+    ("TableFlags_BordersV", &["BordersInnerV", "BordersOuterV"]),
+    (
+        "TreeNodeFlags_CollapsingHeader",
+        &["Framed", "NoTreePushOnOpen", "NoAutoOpenOnLog"],
+    ),
+    (
+        "WindowFlags_NoDecoration",
+        &["NoTitleBar", "NoResize", "NoScrollbar", "NoCollapse"],
+    ),
+    (
+        "WindowFlags_NoInputs",
+        &["NoMouseInputs", "NoNavInputs", "NoNavFocus"],
+    ),
+    ("WindowFlags_NoNav", &["NoNavInputs", "NoNavFocus"]),
+];
+
+/// Flag constants, that are values of a multi-bit field rather than single
+/// bits: family and the members of the field. A runtime value is decoded
+/// to the member, which value matches the field exactly.
+const FLAG_FIELDS: &[(&str, &[&str])] = &[
+    (
+        "PopupFlags",
+        &["MouseButtonLeft", "MouseButtonRight", "MouseButtonMiddle"],
+    ),
+    (
+        "TableFlags",
+        &[
+            "SizingFixedFit",
+            "SizingFixedSame",
+            "SizingStretchProp",
+            "SizingStretchSame",
+        ],
+    ),
+];
+
+/// Flags type `name` of the constants with the `family` prefix.
+/// `*_None` is the empty set, so it is not a member.
+fn flag_family(name: &str, family: &str, doc: &str, constants: &[String]) -> TokenStream {
+    let prefix = format!("{}_", family);
+    let flag_ident = |member: &str| {
+        screaming_snake_case(member.trim_start_matches(&prefix))
+            .parse::<TokenStream>()
+            .expect("Can no parse")
+    };
+    let const_ident = |member: &str| member.parse::<TokenStream>().expect("Can no parse");
+    let composites: Vec<(&String, &[&str])> = constants
+        .iter()
+        .filter(|constant| constant.starts_with(&prefix))
+        .filter_map(|constant| {
+            COMPOSITE_FLAGS
+                .iter()
+                .find(|(composite, _)| composite == constant)
+                .map(|(_, parts)| (constant, *parts))
+        })
+        .collect();
+    let field: &[&str] = FLAG_FIELDS
+        .iter()
+        .find(|(field_family, _)| *field_family == family)
+        .map_or(&[], |(_, members)| *members);
+    let in_field = |member: &str| field.contains(&member.trim_start_matches(&prefix));
+    let members: Vec<&String> = constants
+        .iter()
+        .filter(|name| name.starts_with(&prefix) && !name.ends_with("_None"))
+        .filter(|name| !composites.iter().any(|(composite, _)| composite == name))
+        .collect();
+    for (composite, parts) in &composites {
+        for part in parts.iter() {
+            let part = format!("{}{}", prefix, part);
+            if !members.contains(&&part) || in_field(&part) {
+                panic!(
+                    "{} is made of {}, that is not a single flag",
+                    composite, part
+                );
+            }
+        }
+    }
+    let (field_members, members): (Vec<&String>, Vec<&String>) =
+        members.into_iter().partition(|member| in_field(member));
+    let name = name.parse::<TokenStream>().expect("Can no parse");
+    let flags = members.iter().map(|member| flag_ident(member));
+    let const_names = members.iter().map(|member| const_ident(member));
+    let fields = match field_members.is_empty() {
+        true => quote!(),
+        false => {
+            let flags = field_members.iter().map(|member| flag_ident(member));
+            let const_names = field_members.iter().map(|member| const_ident(member));
+            quote!([#(#flags = #const_names,)*])
+        }
+    };
+    let composite_flags = composites
+        .iter()
+        .map(|(composite, _)| flag_ident(composite));
+    let composite_consts = composites
+        .iter()
+        .map(|(composite, _)| const_ident(composite));
+    let composite_parts = composites.iter().map(|(_, parts)| {
+        let parts = parts.iter().map(|part| flag_ident(part));
+        quote!(#(#parts)|*)
+    });
+    quote!(
+        crate::flags::flags! {
+            #[doc = #doc]
+            #name {
+                #(#flags = #const_names,)*
+            }
+            fields {
+                #fields
+            }
+            composites {
+                #(#composite_flags = #composite_consts (#composite_parts),)*
+            }
+        }
+    )
+}
+
 pub fn build_bindings(headers: Headers) -> String {
     let class_defs = headers
         .class_defs
-        .into_iter()
+        .iter()
         .map(|s| s.parse::<TokenStream>().expect("Can no parse"));
     let names = headers
         .func_defs
@@ -263,7 +602,10 @@ pub fn build_bindings(headers: Headers) -> String {
     //     .iter()
     //     .map(|fd| fd.returns.parse::<TokenStream>().expect("Can no parse"));
     let declarations = headers.func_defs.iter().map(|fd| func_extern(fd));
-    let methods = headers.func_defs.iter().map(|fd| func_method(fd));
+    let methods = headers
+        .func_defs
+        .iter()
+        .map(|fd| func_method(fd, &headers.class_defs));
     // let total_fn_ptr_count = names.len() as u32;
     let const_init_names = headers.constants.iter().map(|name| {
         let name = "ImGui_".to_string() + name;
@@ -274,6 +616,14 @@ pub fn build_bindings(headers: Headers) -> String {
         .iter()
         .map(|name| name.parse::<TokenStream>().expect("Can no parse"));
     let const_names1 = const_names.clone();
+    let const_names2 = const_names.clone();
+    let const_strings = headers.constants.iter().map(|name| name.as_str());
+    let names4 = names.clone();
+    let name_strings = headers.func_defs.iter().map(|fd| fd.name.as_str());
+    let enums = ENUM_FAMILIES
+        .iter()
+        .map(|(family, doc)| enum_family(family, doc, &headers.constants));
+    let flags = flag_families(&headers.constants);
 
     let bindings = quote!(
         //! This file is automatically generated by executing `cargo build --features generate`.
@@ -285,15 +635,27 @@ pub fn build_bindings(headers: Headers) -> String {
         use std::ffi::c_void;
         use rea_rs_low::PluginContext;
         use std::fmt;
+        use std::marker::PhantomData;
+        use std::thread::{self, ThreadId};
+        use crate::error::LoadError;
 
         #(
+            /// Raw ReaImGui handle. Being a pointer, it can not leave the main thread.
             pub type #class_defs = *mut c_void;
         )*
 
+        /// Loaded ReaImGui API.
+        ///
+        /// ReaImGui can be called only from the REAPER main thread, so the
+        /// struct is neither `Send` nor `Sync`. In debug builds every call also
+        /// panics if it is made from a thread other than the one that performed
+        /// [`ImGui::load`].
         #[derive(Clone)]
         pub struct ImGui{
             pointers: FunctionPointers,
             plugin_context: Option<PluginContext>,
+            main_thread: ThreadId,
+            _not_send: PhantomData<*const ()>,
             #(pub #const_names1: Option<i32>,)*
         }
         impl std::fmt::Debug for ImGui {
@@ -327,6 +689,8 @@ pub fn build_bindings(headers: Headers) -> String {
                 Self {
                     pointers,
                     plugin_context: Some(plugin_context),
+                    main_thread: thread::current().id(),
+                    _not_send: PhantomData,
                     #(
                         #const_names: unsafe{
                             match plugin_context.GetFunc(c_str_macro::c_str!(#const_init_names).as_ptr()).is_null(){
@@ -338,6 +702,106 @@ pub fn build_bindings(headers: Headers) -> String {
                 }
             }
 
+            /// Loads the API like [`ImGui::load`], but fails if any function or
+            /// constant could not be resolved.
+            ///
+            /// Use it when it is better to refuse running with an outdated
+            /// ReaImGui than to panic in the middle of a session.
+            pub fn load_strict(plugin_context: PluginContext) -> Result<Self, LoadError> {
+                Self::load_strict_allowing(plugin_context, &[])
+            }
+
+            /// Same as [`ImGui::load_strict`], but ignores missing items whose
+            /// names are in `allowed`.
+            ///
+            /// Names can be given with or without the `ImGui_` prefix.
+            pub fn load_strict_allowing(
+                plugin_context: PluginContext,
+                allowed: &[&str],
+            ) -> Result<Self, LoadError> {
+                let imgui = Self::load(plugin_context);
+                let is_allowed = |name: &&str| {
+                    allowed
+                        .iter()
+                        .any(|a| a.trim_start_matches("ImGui_") == *name)
+                };
+                let mut functions = imgui.missing_functions();
+                functions.retain(|name| !is_allowed(name));
+                let mut constants = imgui.missing_constants();
+                constants.retain(|name| !is_allowed(name));
+                match functions.is_empty() && constants.is_empty() {
+                    true => Ok(imgui),
+                    false => Err(LoadError {
+                        functions,
+                        constants,
+                    }),
+                }
+            }
+
+            /// Id of the thread that loaded the API. All calls have to be made from it.
+            pub fn main_thread(&self) -> ThreadId {
+                self.main_thread
+            }
+
+            /// Panics if called from a thread other than the one that loaded the API.
+            pub fn assert_main_thread(&self, function: &str) {
+                if thread::current().id() != self.main_thread {
+                    panic!(
+                        "ImGui::{} called from {:?}, but ReaImGui can be used only from \
+                        the thread that called ImGui::load ({:?})",
+                        function,
+                        thread::current().id(),
+                        self.main_thread
+                    );
+                }
+            }
+
+            /// Panics if a handle is destroyed or of a wrong type.
+            ///
+            /// Called before every function with `debug-validate` feature.
+            #[cfg(feature = "debug-validate")]
+            fn validate_handle(
+                &self,
+                pointer: *mut c_void,
+                type_name: &std::ffi::CStr,
+                allow_null: bool,
+                function: &str,
+                argument: &str,
+            ) {
+                if allow_null && pointer.is_null() {
+                    return;
+                }
+                if !unsafe { self.ValidatePtr(pointer, type_name.as_ptr()) } {
+                    panic!(
+                        "ImGui::{}: argument `{}` is not a valid {:?} ({:?}). \
+                        It may have been destroyed or garbage-collected.",
+                        function, argument, type_name, pointer
+                    );
+                }
+            }
+
+            /// Names of functions that are not provided by the installed ReaImGui.
+            pub fn missing_functions(&self) -> Vec<&'static str> {
+                let mut missing = Vec::new();
+                #(
+                    if self.pointers.#names4.is_none() {
+                        missing.push(#name_strings);
+                    }
+                )*
+                missing
+            }
+
+            /// Names of constants that are not provided by the installed ReaImGui.
+            pub fn missing_constants(&self) -> Vec<&'static str> {
+                let mut missing = Vec::new();
+                #(
+                    if self.#const_names2.is_none() {
+                        missing.push(#const_strings);
+                    }
+                )*
+                missing
+            }
+
             #(
                 #methods
             )*
@@ -367,15 +831,32 @@ pub fn build_bindings(headers: Headers) -> String {
             f: fn() -> i32,
         }
 
+        #(#enums)*
+
+        #(#flags)*
+
     );
     bindings.to_token_stream().to_string()
 }
 
+/// ReaImGui release, which header the bindings are generated from.
+const REAIMGUI_VERSION: &str = "0.8.1";
+
 fn main() {
-    let mut res = reqwest::blocking::get(
-        "https://github.com/cfillion/reaimgui/releases/latest/download/reaper_imgui_functions.h",
-    )
-    .expect("Can not get release");
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "generate")]
+    generate();
+}
+
+/// Download the header of the pinned release and regenerate
+/// `src/bindings.rs`.
+#[cfg(feature = "generate")]
+fn generate() {
+    let url = format!(
+        "https://github.com/cfillion/reaimgui/releases/download/v{}/reaper_imgui_functions.h",
+        REAIMGUI_VERSION
+    );
+    let mut res = reqwest::blocking::get(url).expect("Can not get release");
     let mut body = String::new();
     res.read_to_string(&mut body)
         .expect("Can not read to string");
@@ -0,0 +1,64 @@
+//! Names of the generated items. Shared by `build.rs` and the unit tests of
+//! the library, since the tests of a build script are not run.
+
+/// Constant suffix as an identifier: `0` -> `Alpha0`.
+pub fn variant_name(name: &str) -> String {
+    match name.starts_with(|c: char| c.is_ascii_digit()) {
+        true => format!("Alpha{}", name),
+        false => name.to_string(),
+    }
+}
+
+/// `NoTitleBar` -> `NO_TITLE_BAR`, `DisplayHSV` -> `DISPLAY_HSV`.
+pub fn screaming_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+    for (idx, c) in chars.iter().enumerate() {
+        if idx > 0 && c.is_uppercase() {
+            let prev = chars[idx - 1];
+            let next_lower = chars.get(idx + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_uppercase());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screaming_snake_case_words() {
+        assert_eq!(screaming_snake_case("NoTitleBar"), "NO_TITLE_BAR");
+        assert_eq!(screaming_snake_case("Borders"), "BORDERS");
+        assert_eq!(screaming_snake_case("BordersInnerV"), "BORDERS_INNER_V");
+        assert_eq!(screaming_snake_case("Shift"), "SHIFT");
+    }
+
+    #[test]
+    fn screaming_snake_case_acronyms() {
+        assert_eq!(screaming_snake_case("DisplayHSV"), "DISPLAY_HSV");
+        assert_eq!(screaming_snake_case("InputRGB"), "INPUT_RGB");
+        assert_eq!(
+            screaming_snake_case("SourceAllowNullID"),
+            "SOURCE_ALLOW_NULL_ID"
+        );
+        assert_eq!(screaming_snake_case("NoPushId"), "NO_PUSH_ID");
+        assert_eq!(screaming_snake_case("DisplayHex"), "DISPLAY_HEX");
+    }
+
+    #[test]
+    fn screaming_snake_case_digits() {
+        assert_eq!(screaming_snake_case("Uint8"), "UINT8");
+        assert_eq!(screaming_snake_case("Uint8Value"), "UINT8_VALUE");
+    }
+
+    #[test]
+    fn variant_names() {
+        assert_eq!(variant_name("0"), "Alpha0");
+        assert_eq!(variant_name("F12"), "F12");
+    }
+}
@@ -4,23 +4,44 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
+use crate::error::LoadError;
 use rea_rs_low::PluginContext;
 use std::ffi::c_void;
 use std::fmt;
+use std::marker::PhantomData;
+use std::thread::{self, ThreadId};
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type Context = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type DrawList = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type DrawListSplitter = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type Font = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type Image = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type ImageSet = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type ListClipper = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type Resource = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type TextFilter = *mut c_void;
+#[doc = r" Raw ReaImGui handle. Being a pointer, it can not leave the main thread."]
 pub type Viewport = *mut c_void;
+#[doc = r" Loaded ReaImGui API."]
+#[doc = r""]
+#[doc = r" ReaImGui can be called only from the REAPER main thread, so the"]
+#[doc = r" struct is neither `Send` nor `Sync`. In debug builds every call also"]
+#[doc = r" panics if it is made from a thread other than the one that performed"]
+#[doc = r" [`ImGui::load`]."]
 #[derive(Clone)]
 pub struct ImGui {
     pointers: FunctionPointers,
     plugin_context: Option<PluginContext>,
+    main_thread: ThreadId,
+    _not_send: PhantomData<*const ()>,
     pub Dir_Down: Option<i32>,
     pub Dir_Left: Option<i32>,
     pub Dir_None: Option<i32>,
@@ -1665,6 +1686,10 @@ impl ImGui {
                         plugin_context
                             .GetFunc(c_str_macro::c_str!(stringify!(ImGui_ProgressBar)).as_ptr()),
                     ),
+                    ValidatePtr: std::mem::transmute(
+                        plugin_context
+                            .GetFunc(c_str_macro::c_str!(stringify!(ImGui_ValidatePtr)).as_ptr()),
+                    ),
                     SetClipboardText: std::mem::transmute(
                         plugin_context.GetFunc(
                             c_str_macro::c_str!(stringify!(ImGui_SetClipboardText)).as_ptr(),
@@ -2816,6 +2841,9 @@ impl ImGui {
         if pointers.ProgressBar.is_some() {
             loaded_count += 1;
         }
+        if pointers.ValidatePtr.is_some() {
+            loaded_count += 1;
+        }
         if pointers.SetClipboardText.is_some() {
             loaded_count += 1;
         }
@@ -3039,6 +3067,8 @@ impl ImGui {
         Self {
             pointers,
             plugin_context: Some(plugin_context),
+            main_thread: thread::current().id(),
+            _not_send: PhantomData,
             Dir_Down: unsafe {
                 match plugin_context
                     .GetFunc(c_str_macro::c_str!("ImGui_Dir_Down").as_ptr())
@@ -10302,276 +10332,3109 @@ impl ImGui {
             },
         }
     }
-    pub unsafe fn ArrowButton(
-        &self,
-        ctx: Context,
-        str_id: *const std::ffi::c_char,
-        dir: std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.ArrowButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ArrowButton)
-            ),
-            Some(f) => f(ctx, str_id, dir),
-        }
+    #[doc = r" Loads the API like [`ImGui::load`], but fails if any function or"]
+    #[doc = r" constant could not be resolved."]
+    #[doc = r""]
+    #[doc = r" Use it when it is better to refuse running with an outdated"]
+    #[doc = r" ReaImGui than to panic in the middle of a session."]
+    pub fn load_strict(plugin_context: PluginContext) -> Result<Self, LoadError> {
+        Self::load_strict_allowing(plugin_context, &[])
     }
-    pub unsafe fn Button(
+    #[doc = r" Same as [`ImGui::load_strict`], but ignores missing items whose"]
+    #[doc = r" names are in `allowed`."]
+    #[doc = r""]
+    #[doc = r" Names can be given with or without the `ImGui_` prefix."]
+    pub fn load_strict_allowing(
+        plugin_context: PluginContext,
+        allowed: &[&str],
+    ) -> Result<Self, LoadError> {
+        let imgui = Self::load(plugin_context);
+        let is_allowed = |name: &&str| {
+            allowed
+                .iter()
+                .any(|a| a.trim_start_matches("ImGui_") == *name)
+        };
+        let mut functions = imgui.missing_functions();
+        functions.retain(|name| !is_allowed(name));
+        let mut constants = imgui.missing_constants();
+        constants.retain(|name| !is_allowed(name));
+        match functions.is_empty() && constants.is_empty() {
+            true => Ok(imgui),
+            false => Err(LoadError {
+                functions,
+                constants,
+            }),
+        }
+    }
+    #[doc = r" Id of the thread that loaded the API. All calls have to be made from it."]
+    pub fn main_thread(&self) -> ThreadId {
+        self.main_thread
+    }
+    #[doc = r" Panics if called from a thread other than the one that loaded the API."]
+    pub fn assert_main_thread(&self, function: &str) {
+        if thread::current().id() != self.main_thread {
+            panic!(
+                "ImGui::{} called from {:?}, but ReaImGui can be used only from \
+                        the thread that called ImGui::load ({:?})",
+                function,
+                thread::current().id(),
+                self.main_thread
+            );
+        }
+    }
+    #[doc = r" Panics if a handle is destroyed or of a wrong type."]
+    #[doc = r""]
+    #[doc = r" Called before every function with `debug-validate` feature."]
+    #[cfg(feature = "debug-validate")]
+    fn validate_handle(
         &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        size_wInOptional: *mut f64,
-        size_hInOptional: *mut f64,
-    ) -> bool {
-        match self.pointers.Button {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Button)
-            ),
-            Some(f) => f(ctx, label, size_wInOptional, size_hInOptional),
+        pointer: *mut c_void,
+        type_name: &std::ffi::CStr,
+        allow_null: bool,
+        function: &str,
+        argument: &str,
+    ) {
+        if allow_null && pointer.is_null() {
+            return;
         }
-    }
-    pub unsafe fn Checkbox(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        vInOut: *mut bool,
-    ) -> bool {
-        match self.pointers.Checkbox {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Checkbox)
-            ),
-            Some(f) => f(ctx, label, vInOut),
+        if !unsafe { self.ValidatePtr(pointer, type_name.as_ptr()) } {
+            panic!(
+                "ImGui::{}: argument `{}` is not a valid {:?} ({:?}). \
+                        It may have been destroyed or garbage-collected.",
+                function, argument, type_name, pointer
+            );
         }
     }
-    pub unsafe fn CheckboxFlags(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        flagsInOut: *mut std::ffi::c_int,
-        flags_value: std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.CheckboxFlags {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(CheckboxFlags)
-            ),
-            Some(f) => f(ctx, label, flagsInOut, flags_value),
+    #[doc = r" Names of functions that are not provided by the installed ReaImGui."]
+    pub fn missing_functions(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if self.pointers.ArrowButton.is_none() {
+            missing.push("ArrowButton");
         }
-    }
-    pub unsafe fn InvisibleButton(
-        &self,
-        ctx: Context,
-        str_id: *const std::ffi::c_char,
-        size_w: f64,
-        size_h: f64,
-        flagsInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.InvisibleButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(InvisibleButton)
-            ),
-            Some(f) => f(ctx, str_id, size_w, size_h, flagsInOptional),
+        if self.pointers.Button.is_none() {
+            missing.push("Button");
         }
-    }
-    pub unsafe fn PopButtonRepeat(&self, ctx: Context) -> () {
-        match self.pointers.PopButtonRepeat {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PopButtonRepeat)
-            ),
-            Some(f) => f(ctx),
+        if self.pointers.Checkbox.is_none() {
+            missing.push("Checkbox");
         }
-    }
-    pub unsafe fn PushButtonRepeat(&self, ctx: Context, repeat: bool) -> () {
-        match self.pointers.PushButtonRepeat {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(PushButtonRepeat)
-            ),
-            Some(f) => f(ctx, repeat),
+        if self.pointers.CheckboxFlags.is_none() {
+            missing.push("CheckboxFlags");
         }
-    }
-    pub unsafe fn RadioButton(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        active: bool,
-    ) -> bool {
-        match self.pointers.RadioButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RadioButton)
-            ),
-            Some(f) => f(ctx, label, active),
+        if self.pointers.InvisibleButton.is_none() {
+            missing.push("InvisibleButton");
         }
-    }
-    pub unsafe fn RadioButtonEx(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        vInOut: *mut std::ffi::c_int,
-        v_button: std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.RadioButtonEx {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(RadioButtonEx)
-            ),
-            Some(f) => f(ctx, label, vInOut, v_button),
+        if self.pointers.PopButtonRepeat.is_none() {
+            missing.push("PopButtonRepeat");
         }
-    }
-    pub unsafe fn SmallButton(&self, ctx: Context, label: *const std::ffi::c_char) -> bool {
-        match self.pointers.SmallButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SmallButton)
-            ),
-            Some(f) => f(ctx, label),
+        if self.pointers.PushButtonRepeat.is_none() {
+            missing.push("PushButtonRepeat");
         }
-    }
-    pub unsafe fn ColorButton(
-        &self,
-        ctx: Context,
-        desc_id: *const std::ffi::c_char,
-        col_rgba: std::ffi::c_int,
-        flagsInOptional: *mut std::ffi::c_int,
-        size_wInOptional: *mut f64,
-        size_hInOptional: *mut f64,
-    ) -> bool {
-        match self.pointers.ColorButton {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorButton)
-            ),
-            Some(f) => f(
-                ctx,
-                desc_id,
-                col_rgba,
-                flagsInOptional,
-                size_wInOptional,
-                size_hInOptional,
-            ),
+        if self.pointers.RadioButton.is_none() {
+            missing.push("RadioButton");
         }
-    }
-    pub unsafe fn ColorEdit3(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        col_rgbInOut: *mut std::ffi::c_int,
-        flagsInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.ColorEdit3 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorEdit3)
-            ),
-            Some(f) => f(ctx, label, col_rgbInOut, flagsInOptional),
+        if self.pointers.RadioButtonEx.is_none() {
+            missing.push("RadioButtonEx");
         }
-    }
-    pub unsafe fn ColorEdit4(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        col_rgbaInOut: *mut std::ffi::c_int,
-        flagsInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.ColorEdit4 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorEdit4)
-            ),
-            Some(f) => f(ctx, label, col_rgbaInOut, flagsInOptional),
+        if self.pointers.SmallButton.is_none() {
+            missing.push("SmallButton");
         }
-    }
-    pub unsafe fn ColorPicker3(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        col_rgbInOut: *mut std::ffi::c_int,
-        flagsInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.ColorPicker3 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorPicker3)
-            ),
-            Some(f) => f(ctx, label, col_rgbInOut, flagsInOptional),
+        if self.pointers.ColorButton.is_none() {
+            missing.push("ColorButton");
         }
-    }
-    pub unsafe fn ColorPicker4(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        col_rgbaInOut: *mut std::ffi::c_int,
-        flagsInOptional: *mut std::ffi::c_int,
-        ref_colInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.ColorPicker4 {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(ColorPicker4)
-            ),
-            Some(f) => f(
-                ctx,
-                label,
-                col_rgbaInOut,
-                flagsInOptional,
-                ref_colInOptional,
-            ),
+        if self.pointers.ColorEdit3.is_none() {
+            missing.push("ColorEdit3");
         }
-    }
-    pub unsafe fn SetColorEditOptions(&self, ctx: Context, flags: std::ffi::c_int) -> () {
-        match self.pointers.SetColorEditOptions {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(SetColorEditOptions)
-            ),
-            Some(f) => f(ctx, flags),
+        if self.pointers.ColorEdit4.is_none() {
+            missing.push("ColorEdit4");
         }
-    }
-    pub unsafe fn BeginCombo(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        preview_value: *const std::ffi::c_char,
-        flagsInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.BeginCombo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(BeginCombo)
-            ),
-            Some(f) => f(ctx, label, preview_value, flagsInOptional),
+        if self.pointers.ColorPicker3.is_none() {
+            missing.push("ColorPicker3");
         }
-    }
-    pub unsafe fn Combo(
-        &self,
-        ctx: Context,
-        label: *const std::ffi::c_char,
-        current_itemInOut: *mut std::ffi::c_int,
-        items: *const std::ffi::c_char,
-        items_sz: std::ffi::c_int,
-        popup_max_height_in_itemsInOptional: *mut std::ffi::c_int,
-    ) -> bool {
-        match self.pointers.Combo {
-            None => panic!(
-                "Attempt to use a function that has not been loaded: {}",
-                stringify!(Combo)
-            ),
-            Some(f) => f(
-                ctx,
-                label,
-                current_itemInOut,
-                items,
-                items_sz,
-                popup_max_height_in_itemsInOptional,
-            ),
+        if self.pointers.ColorPicker4.is_none() {
+            missing.push("ColorPicker4");
         }
-    }
+        if self.pointers.SetColorEditOptions.is_none() {
+            missing.push("SetColorEditOptions");
+        }
+        if self.pointers.BeginCombo.is_none() {
+            missing.push("BeginCombo");
+        }
+        if self.pointers.Combo.is_none() {
+            missing.push("Combo");
+        }
+        if self.pointers.EndCombo.is_none() {
+            missing.push("EndCombo");
+        }
+        if self.pointers.BeginListBox.is_none() {
+            missing.push("BeginListBox");
+        }
+        if self.pointers.EndListBox.is_none() {
+            missing.push("EndListBox");
+        }
+        if self.pointers.ListBox.is_none() {
+            missing.push("ListBox");
+        }
+        if self.pointers.Selectable.is_none() {
+            missing.push("Selectable");
+        }
+        if self.pointers.Attach.is_none() {
+            missing.push("Attach");
+        }
+        if self.pointers.CreateContext.is_none() {
+            missing.push("CreateContext");
+        }
+        if self.pointers.DestroyContext.is_none() {
+            missing.push("DestroyContext");
+        }
+        if self.pointers.Detach.is_none() {
+            missing.push("Detach");
+        }
+        if self.pointers.GetDeltaTime.is_none() {
+            missing.push("GetDeltaTime");
+        }
+        if self.pointers.GetFrameCount.is_none() {
+            missing.push("GetFrameCount");
+        }
+        if self.pointers.GetFramerate.is_none() {
+            missing.push("GetFramerate");
+        }
+        if self.pointers.GetTime.is_none() {
+            missing.push("GetTime");
+        }
+        if self.pointers.GetConfigVar.is_none() {
+            missing.push("GetConfigVar");
+        }
+        if self.pointers.SetConfigVar.is_none() {
+            missing.push("SetConfigVar");
+        }
+        if self.pointers.AcceptDragDropPayload.is_none() {
+            missing.push("AcceptDragDropPayload");
+        }
+        if self.pointers.AcceptDragDropPayloadFiles.is_none() {
+            missing.push("AcceptDragDropPayloadFiles");
+        }
+        if self.pointers.AcceptDragDropPayloadRGB.is_none() {
+            missing.push("AcceptDragDropPayloadRGB");
+        }
+        if self.pointers.AcceptDragDropPayloadRGBA.is_none() {
+            missing.push("AcceptDragDropPayloadRGBA");
+        }
+        if self.pointers.BeginDragDropSource.is_none() {
+            missing.push("BeginDragDropSource");
+        }
+        if self.pointers.BeginDragDropTarget.is_none() {
+            missing.push("BeginDragDropTarget");
+        }
+        if self.pointers.EndDragDropSource.is_none() {
+            missing.push("EndDragDropSource");
+        }
+        if self.pointers.EndDragDropTarget.is_none() {
+            missing.push("EndDragDropTarget");
+        }
+        if self.pointers.GetDragDropPayload.is_none() {
+            missing.push("GetDragDropPayload");
+        }
+        if self.pointers.GetDragDropPayloadFile.is_none() {
+            missing.push("GetDragDropPayloadFile");
+        }
+        if self.pointers.SetDragDropPayload.is_none() {
+            missing.push("SetDragDropPayload");
+        }
+        if self.pointers.DragDouble.is_none() {
+            missing.push("DragDouble");
+        }
+        if self.pointers.DragDouble2.is_none() {
+            missing.push("DragDouble2");
+        }
+        if self.pointers.DragDouble3.is_none() {
+            missing.push("DragDouble3");
+        }
+        if self.pointers.DragDouble4.is_none() {
+            missing.push("DragDouble4");
+        }
+        if self.pointers.DragFloatRange2.is_none() {
+            missing.push("DragFloatRange2");
+        }
+        if self.pointers.DragInt.is_none() {
+            missing.push("DragInt");
+        }
+        if self.pointers.DragInt2.is_none() {
+            missing.push("DragInt2");
+        }
+        if self.pointers.DragInt3.is_none() {
+            missing.push("DragInt3");
+        }
+        if self.pointers.DragInt4.is_none() {
+            missing.push("DragInt4");
+        }
+        if self.pointers.DragIntRange2.is_none() {
+            missing.push("DragIntRange2");
+        }
+        if self.pointers.SliderAngle.is_none() {
+            missing.push("SliderAngle");
+        }
+        if self.pointers.SliderDouble.is_none() {
+            missing.push("SliderDouble");
+        }
+        if self.pointers.SliderDouble2.is_none() {
+            missing.push("SliderDouble2");
+        }
+        if self.pointers.SliderDouble3.is_none() {
+            missing.push("SliderDouble3");
+        }
+        if self.pointers.SliderDouble4.is_none() {
+            missing.push("SliderDouble4");
+        }
+        if self.pointers.SliderInt.is_none() {
+            missing.push("SliderInt");
+        }
+        if self.pointers.SliderInt2.is_none() {
+            missing.push("SliderInt2");
+        }
+        if self.pointers.SliderInt3.is_none() {
+            missing.push("SliderInt3");
+        }
+        if self.pointers.SliderInt4.is_none() {
+            missing.push("SliderInt4");
+        }
+        if self.pointers.VSliderDouble.is_none() {
+            missing.push("VSliderDouble");
+        }
+        if self.pointers.VSliderInt.is_none() {
+            missing.push("VSliderInt");
+        }
+        if self.pointers.DrawList_PopClipRect.is_none() {
+            missing.push("DrawList_PopClipRect");
+        }
+        if self.pointers.DrawList_PushClipRect.is_none() {
+            missing.push("DrawList_PushClipRect");
+        }
+        if self.pointers.DrawList_PushClipRectFullScreen.is_none() {
+            missing.push("DrawList_PushClipRectFullScreen");
+        }
+        if self.pointers.GetBackgroundDrawList.is_none() {
+            missing.push("GetBackgroundDrawList");
+        }
+        if self.pointers.GetForegroundDrawList.is_none() {
+            missing.push("GetForegroundDrawList");
+        }
+        if self.pointers.GetWindowDrawList.is_none() {
+            missing.push("GetWindowDrawList");
+        }
+        if self.pointers.DrawList_AddBezierCubic.is_none() {
+            missing.push("DrawList_AddBezierCubic");
+        }
+        if self.pointers.DrawList_AddBezierQuadratic.is_none() {
+            missing.push("DrawList_AddBezierQuadratic");
+        }
+        if self.pointers.DrawList_AddCircle.is_none() {
+            missing.push("DrawList_AddCircle");
+        }
+        if self.pointers.DrawList_AddCircleFilled.is_none() {
+            missing.push("DrawList_AddCircleFilled");
+        }
+        if self.pointers.DrawList_AddImage.is_none() {
+            missing.push("DrawList_AddImage");
+        }
+        if self.pointers.DrawList_AddImageQuad.is_none() {
+            missing.push("DrawList_AddImageQuad");
+        }
+        if self.pointers.DrawList_AddImageRounded.is_none() {
+            missing.push("DrawList_AddImageRounded");
+        }
+        if self.pointers.DrawList_AddLine.is_none() {
+            missing.push("DrawList_AddLine");
+        }
+        if self.pointers.DrawList_AddNgon.is_none() {
+            missing.push("DrawList_AddNgon");
+        }
+        if self.pointers.DrawList_AddNgonFilled.is_none() {
+            missing.push("DrawList_AddNgonFilled");
+        }
+        if self.pointers.DrawList_AddQuad.is_none() {
+            missing.push("DrawList_AddQuad");
+        }
+        if self.pointers.DrawList_AddQuadFilled.is_none() {
+            missing.push("DrawList_AddQuadFilled");
+        }
+        if self.pointers.DrawList_AddRect.is_none() {
+            missing.push("DrawList_AddRect");
+        }
+        if self.pointers.DrawList_AddRectFilled.is_none() {
+            missing.push("DrawList_AddRectFilled");
+        }
+        if self.pointers.DrawList_AddRectFilledMultiColor.is_none() {
+            missing.push("DrawList_AddRectFilledMultiColor");
+        }
+        if self.pointers.DrawList_AddText.is_none() {
+            missing.push("DrawList_AddText");
+        }
+        if self.pointers.DrawList_AddTextEx.is_none() {
+            missing.push("DrawList_AddTextEx");
+        }
+        if self.pointers.DrawList_AddTriangle.is_none() {
+            missing.push("DrawList_AddTriangle");
+        }
+        if self.pointers.DrawList_AddTriangleFilled.is_none() {
+            missing.push("DrawList_AddTriangleFilled");
+        }
+        if self.pointers.CreateDrawListSplitter.is_none() {
+            missing.push("CreateDrawListSplitter");
+        }
+        if self.pointers.DrawListSplitter_Clear.is_none() {
+            missing.push("DrawListSplitter_Clear");
+        }
+        if self.pointers.DrawListSplitter_Merge.is_none() {
+            missing.push("DrawListSplitter_Merge");
+        }
+        if self.pointers.DrawListSplitter_SetCurrentChannel.is_none() {
+            missing.push("DrawListSplitter_SetCurrentChannel");
+        }
+        if self.pointers.DrawListSplitter_Split.is_none() {
+            missing.push("DrawListSplitter_Split");
+        }
+        if self.pointers.DrawList_PathArcTo.is_none() {
+            missing.push("DrawList_PathArcTo");
+        }
+        if self.pointers.DrawList_PathArcToFast.is_none() {
+            missing.push("DrawList_PathArcToFast");
+        }
+        if self.pointers.DrawList_PathBezierCubicCurveTo.is_none() {
+            missing.push("DrawList_PathBezierCubicCurveTo");
+        }
+        if self.pointers.DrawList_PathBezierQuadraticCurveTo.is_none() {
+            missing.push("DrawList_PathBezierQuadraticCurveTo");
+        }
+        if self.pointers.DrawList_PathClear.is_none() {
+            missing.push("DrawList_PathClear");
+        }
+        if self.pointers.DrawList_PathFillConvex.is_none() {
+            missing.push("DrawList_PathFillConvex");
+        }
+        if self.pointers.DrawList_PathLineTo.is_none() {
+            missing.push("DrawList_PathLineTo");
+        }
+        if self.pointers.DrawList_PathRect.is_none() {
+            missing.push("DrawList_PathRect");
+        }
+        if self.pointers.DrawList_PathStroke.is_none() {
+            missing.push("DrawList_PathStroke");
+        }
+        if self.pointers.CreateFont.is_none() {
+            missing.push("CreateFont");
+        }
+        if self.pointers.GetFont.is_none() {
+            missing.push("GetFont");
+        }
+        if self.pointers.GetFontSize.is_none() {
+            missing.push("GetFontSize");
+        }
+        if self.pointers.PopFont.is_none() {
+            missing.push("PopFont");
+        }
+        if self.pointers.PushFont.is_none() {
+            missing.push("PushFont");
+        }
+        if self.pointers.CreateImage.is_none() {
+            missing.push("CreateImage");
+        }
+        if self.pointers.CreateImageFromMem.is_none() {
+            missing.push("CreateImageFromMem");
+        }
+        if self.pointers.Image.is_none() {
+            missing.push("Image");
+        }
+        if self.pointers.ImageButton.is_none() {
+            missing.push("ImageButton");
+        }
+        if self.pointers.Image_GetSize.is_none() {
+            missing.push("Image_GetSize");
+        }
+        if self.pointers.ImageSet_Add.is_none() {
+            missing.push("ImageSet_Add");
+        }
+        if self.pointers.BeginDisabled.is_none() {
+            missing.push("BeginDisabled");
+        }
+        if self.pointers.EndDisabled.is_none() {
+            missing.push("EndDisabled");
+        }
+        if self.pointers.SetItemAllowOverlap.is_none() {
+            missing.push("SetItemAllowOverlap");
+        }
+        if self.pointers.CalcItemWidth.is_none() {
+            missing.push("CalcItemWidth");
+        }
+        if self.pointers.GetItemRectMax.is_none() {
+            missing.push("GetItemRectMax");
+        }
+        if self.pointers.GetItemRectMin.is_none() {
+            missing.push("GetItemRectMin");
+        }
+        if self.pointers.GetItemRectSize.is_none() {
+            missing.push("GetItemRectSize");
+        }
+        if self.pointers.PopItemWidth.is_none() {
+            missing.push("PopItemWidth");
+        }
+        if self.pointers.PushItemWidth.is_none() {
+            missing.push("PushItemWidth");
+        }
+        if self.pointers.SetNextItemWidth.is_none() {
+            missing.push("SetNextItemWidth");
+        }
+        if self.pointers.PopAllowKeyboardFocus.is_none() {
+            missing.push("PopAllowKeyboardFocus");
+        }
+        if self.pointers.PushAllowKeyboardFocus.is_none() {
+            missing.push("PushAllowKeyboardFocus");
+        }
+        if self.pointers.SetItemDefaultFocus.is_none() {
+            missing.push("SetItemDefaultFocus");
+        }
+        if self.pointers.SetKeyboardFocusHere.is_none() {
+            missing.push("SetKeyboardFocusHere");
+        }
+        if self.pointers.IsAnyItemActive.is_none() {
+            missing.push("IsAnyItemActive");
+        }
+        if self.pointers.IsAnyItemFocused.is_none() {
+            missing.push("IsAnyItemFocused");
+        }
+        if self.pointers.IsAnyItemHovered.is_none() {
+            missing.push("IsAnyItemHovered");
+        }
+        if self.pointers.IsItemActivated.is_none() {
+            missing.push("IsItemActivated");
+        }
+        if self.pointers.IsItemActive.is_none() {
+            missing.push("IsItemActive");
+        }
+        if self.pointers.IsItemClicked.is_none() {
+            missing.push("IsItemClicked");
+        }
+        if self.pointers.IsItemDeactivated.is_none() {
+            missing.push("IsItemDeactivated");
+        }
+        if self.pointers.IsItemDeactivatedAfterEdit.is_none() {
+            missing.push("IsItemDeactivatedAfterEdit");
+        }
+        if self.pointers.IsItemEdited.is_none() {
+            missing.push("IsItemEdited");
+        }
+        if self.pointers.IsItemFocused.is_none() {
+            missing.push("IsItemFocused");
+        }
+        if self.pointers.IsItemHovered.is_none() {
+            missing.push("IsItemHovered");
+        }
+        if self.pointers.IsItemVisible.is_none() {
+            missing.push("IsItemVisible");
+        }
+        if self.pointers.GetInputQueueCharacter.is_none() {
+            missing.push("GetInputQueueCharacter");
+        }
+        if self.pointers.GetKeyDownDuration.is_none() {
+            missing.push("GetKeyDownDuration");
+        }
+        if self.pointers.GetKeyMods.is_none() {
+            missing.push("GetKeyMods");
+        }
+        if self.pointers.GetKeyPressedAmount.is_none() {
+            missing.push("GetKeyPressedAmount");
+        }
+        if self.pointers.IsKeyDown.is_none() {
+            missing.push("IsKeyDown");
+        }
+        if self.pointers.IsKeyPressed.is_none() {
+            missing.push("IsKeyPressed");
+        }
+        if self.pointers.IsKeyReleased.is_none() {
+            missing.push("IsKeyReleased");
+        }
+        if self.pointers.SetNextFrameWantCaptureKeyboard.is_none() {
+            missing.push("SetNextFrameWantCaptureKeyboard");
+        }
+        if self.pointers.GetMouseClickedCount.is_none() {
+            missing.push("GetMouseClickedCount");
+        }
+        if self.pointers.GetMouseClickedPos.is_none() {
+            missing.push("GetMouseClickedPos");
+        }
+        if self.pointers.GetMouseCursor.is_none() {
+            missing.push("GetMouseCursor");
+        }
+        if self.pointers.GetMouseDelta.is_none() {
+            missing.push("GetMouseDelta");
+        }
+        if self.pointers.GetMouseDownDuration.is_none() {
+            missing.push("GetMouseDownDuration");
+        }
+        if self.pointers.GetMouseDragDelta.is_none() {
+            missing.push("GetMouseDragDelta");
+        }
+        if self.pointers.GetMousePos.is_none() {
+            missing.push("GetMousePos");
+        }
+        if self.pointers.GetMousePosOnOpeningCurrentPopup.is_none() {
+            missing.push("GetMousePosOnOpeningCurrentPopup");
+        }
+        if self.pointers.GetMouseWheel.is_none() {
+            missing.push("GetMouseWheel");
+        }
+        if self.pointers.IsAnyMouseDown.is_none() {
+            missing.push("IsAnyMouseDown");
+        }
+        if self.pointers.IsMouseClicked.is_none() {
+            missing.push("IsMouseClicked");
+        }
+        if self.pointers.IsMouseDoubleClicked.is_none() {
+            missing.push("IsMouseDoubleClicked");
+        }
+        if self.pointers.IsMouseDown.is_none() {
+            missing.push("IsMouseDown");
+        }
+        if self.pointers.IsMouseDragging.is_none() {
+            missing.push("IsMouseDragging");
+        }
+        if self.pointers.IsMouseHoveringRect.is_none() {
+            missing.push("IsMouseHoveringRect");
+        }
+        if self.pointers.IsMousePosValid.is_none() {
+            missing.push("IsMousePosValid");
+        }
+        if self.pointers.IsMouseReleased.is_none() {
+            missing.push("IsMouseReleased");
+        }
+        if self.pointers.ResetMouseDragDelta.is_none() {
+            missing.push("ResetMouseDragDelta");
+        }
+        if self.pointers.SetMouseCursor.is_none() {
+            missing.push("SetMouseCursor");
+        }
+        if self.pointers.Separator.is_none() {
+            missing.push("Separator");
+        }
+        if self.pointers.IsRectVisible.is_none() {
+            missing.push("IsRectVisible");
+        }
+        if self.pointers.IsRectVisibleEx.is_none() {
+            missing.push("IsRectVisibleEx");
+        }
+        if self.pointers.PopClipRect.is_none() {
+            missing.push("PopClipRect");
+        }
+        if self.pointers.PushClipRect.is_none() {
+            missing.push("PushClipRect");
+        }
+        if self.pointers.BeginGroup.is_none() {
+            missing.push("BeginGroup");
+        }
+        if self.pointers.Dummy.is_none() {
+            missing.push("Dummy");
+        }
+        if self.pointers.EndGroup.is_none() {
+            missing.push("EndGroup");
+        }
+        if self.pointers.GetCursorPos.is_none() {
+            missing.push("GetCursorPos");
+        }
+        if self.pointers.GetCursorPosX.is_none() {
+            missing.push("GetCursorPosX");
+        }
+        if self.pointers.GetCursorPosY.is_none() {
+            missing.push("GetCursorPosY");
+        }
+        if self.pointers.GetCursorScreenPos.is_none() {
+            missing.push("GetCursorScreenPos");
+        }
+        if self.pointers.GetCursorStartPos.is_none() {
+            missing.push("GetCursorStartPos");
+        }
+        if self.pointers.Indent.is_none() {
+            missing.push("Indent");
+        }
+        if self.pointers.NewLine.is_none() {
+            missing.push("NewLine");
+        }
+        if self.pointers.SameLine.is_none() {
+            missing.push("SameLine");
+        }
+        if self.pointers.SetCursorPos.is_none() {
+            missing.push("SetCursorPos");
+        }
+        if self.pointers.SetCursorPosX.is_none() {
+            missing.push("SetCursorPosX");
+        }
+        if self.pointers.SetCursorPosY.is_none() {
+            missing.push("SetCursorPosY");
+        }
+        if self.pointers.SetCursorScreenPos.is_none() {
+            missing.push("SetCursorScreenPos");
+        }
+        if self.pointers.Spacing.is_none() {
+            missing.push("Spacing");
+        }
+        if self.pointers.Unindent.is_none() {
+            missing.push("Unindent");
+        }
+        if self.pointers.CreateListClipper.is_none() {
+            missing.push("CreateListClipper");
+        }
+        if self.pointers.ListClipper_Begin.is_none() {
+            missing.push("ListClipper_Begin");
+        }
+        if self.pointers.ListClipper_End.is_none() {
+            missing.push("ListClipper_End");
+        }
+        if self
+            .pointers
+            .ListClipper_ForceDisplayRangeByIndices
+            .is_none()
+        {
+            missing.push("ListClipper_ForceDisplayRangeByIndices");
+        }
+        if self.pointers.ListClipper_GetDisplayRange.is_none() {
+            missing.push("ListClipper_GetDisplayRange");
+        }
+        if self.pointers.ListClipper_Step.is_none() {
+            missing.push("ListClipper_Step");
+        }
+        if self.pointers.BeginMenu.is_none() {
+            missing.push("BeginMenu");
+        }
+        if self.pointers.BeginMenuBar.is_none() {
+            missing.push("BeginMenuBar");
+        }
+        if self.pointers.EndMenu.is_none() {
+            missing.push("EndMenu");
+        }
+        if self.pointers.EndMenuBar.is_none() {
+            missing.push("EndMenuBar");
+        }
+        if self.pointers.MenuItem.is_none() {
+            missing.push("MenuItem");
+        }
+        if self.pointers.BeginPopup.is_none() {
+            missing.push("BeginPopup");
+        }
+        if self.pointers.BeginPopupModal.is_none() {
+            missing.push("BeginPopupModal");
+        }
+        if self.pointers.CloseCurrentPopup.is_none() {
+            missing.push("CloseCurrentPopup");
+        }
+        if self.pointers.EndPopup.is_none() {
+            missing.push("EndPopup");
+        }
+        if self.pointers.IsPopupOpen.is_none() {
+            missing.push("IsPopupOpen");
+        }
+        if self.pointers.OpenPopup.is_none() {
+            missing.push("OpenPopup");
+        }
+        if self.pointers.OpenPopupOnItemClick.is_none() {
+            missing.push("OpenPopupOnItemClick");
+        }
+        if self.pointers.BeginPopupContextItem.is_none() {
+            missing.push("BeginPopupContextItem");
+        }
+        if self.pointers.BeginPopupContextWindow.is_none() {
+            missing.push("BeginPopupContextWindow");
+        }
+        if self.pointers.BeginTooltip.is_none() {
+            missing.push("BeginTooltip");
+        }
+        if self.pointers.EndTooltip.is_none() {
+            missing.push("EndTooltip");
+        }
+        if self.pointers.SetTooltip.is_none() {
+            missing.push("SetTooltip");
+        }
+        if self.pointers.GetColor.is_none() {
+            missing.push("GetColor");
+        }
+        if self.pointers.GetColorEx.is_none() {
+            missing.push("GetColorEx");
+        }
+        if self.pointers.GetStyleColor.is_none() {
+            missing.push("GetStyleColor");
+        }
+        if self.pointers.PopStyleColor.is_none() {
+            missing.push("PopStyleColor");
+        }
+        if self.pointers.PushStyleColor.is_none() {
+            missing.push("PushStyleColor");
+        }
+        if self.pointers.GetStyleVar.is_none() {
+            missing.push("GetStyleVar");
+        }
+        if self.pointers.PopStyleVar.is_none() {
+            missing.push("PopStyleVar");
+        }
+        if self.pointers.PushStyleVar.is_none() {
+            missing.push("PushStyleVar");
+        }
+        if self.pointers.BeginTabBar.is_none() {
+            missing.push("BeginTabBar");
+        }
+        if self.pointers.EndTabBar.is_none() {
+            missing.push("EndTabBar");
+        }
+        if self.pointers.BeginTabItem.is_none() {
+            missing.push("BeginTabItem");
+        }
+        if self.pointers.EndTabItem.is_none() {
+            missing.push("EndTabItem");
+        }
+        if self.pointers.SetTabItemClosed.is_none() {
+            missing.push("SetTabItemClosed");
+        }
+        if self.pointers.TabItemButton.is_none() {
+            missing.push("TabItemButton");
+        }
+        if self.pointers.BeginTable.is_none() {
+            missing.push("BeginTable");
+        }
+        if self.pointers.EndTable.is_none() {
+            missing.push("EndTable");
+        }
+        if self.pointers.TableGetColumnCount.is_none() {
+            missing.push("TableGetColumnCount");
+        }
+        if self.pointers.TableGetColumnIndex.is_none() {
+            missing.push("TableGetColumnIndex");
+        }
+        if self.pointers.TableGetRowIndex.is_none() {
+            missing.push("TableGetRowIndex");
+        }
+        if self.pointers.TableNextColumn.is_none() {
+            missing.push("TableNextColumn");
+        }
+        if self.pointers.TableNextRow.is_none() {
+            missing.push("TableNextRow");
+        }
+        if self.pointers.TableSetColumnIndex.is_none() {
+            missing.push("TableSetColumnIndex");
+        }
+        if self.pointers.TableSetBgColor.is_none() {
+            missing.push("TableSetBgColor");
+        }
+        if self.pointers.TableGetColumnFlags.is_none() {
+            missing.push("TableGetColumnFlags");
+        }
+        if self.pointers.TableHeader.is_none() {
+            missing.push("TableHeader");
+        }
+        if self.pointers.TableHeadersRow.is_none() {
+            missing.push("TableHeadersRow");
+        }
+        if self.pointers.TableSetColumnEnabled.is_none() {
+            missing.push("TableSetColumnEnabled");
+        }
+        if self.pointers.TableSetupColumn.is_none() {
+            missing.push("TableSetupColumn");
+        }
+        if self.pointers.TableSetupScrollFreeze.is_none() {
+            missing.push("TableSetupScrollFreeze");
+        }
+        if self.pointers.TableGetColumnSortSpecs.is_none() {
+            missing.push("TableGetColumnSortSpecs");
+        }
+        if self.pointers.TableNeedSort.is_none() {
+            missing.push("TableNeedSort");
+        }
+        if self.pointers.AlignTextToFramePadding.is_none() {
+            missing.push("AlignTextToFramePadding");
+        }
+        if self.pointers.Bullet.is_none() {
+            missing.push("Bullet");
+        }
+        if self.pointers.BulletText.is_none() {
+            missing.push("BulletText");
+        }
+        if self.pointers.CalcTextSize.is_none() {
+            missing.push("CalcTextSize");
+        }
+        if self.pointers.DebugTextEncoding.is_none() {
+            missing.push("DebugTextEncoding");
+        }
+        if self.pointers.GetFrameHeight.is_none() {
+            missing.push("GetFrameHeight");
+        }
+        if self.pointers.GetFrameHeightWithSpacing.is_none() {
+            missing.push("GetFrameHeightWithSpacing");
+        }
+        if self.pointers.GetTextLineHeight.is_none() {
+            missing.push("GetTextLineHeight");
+        }
+        if self.pointers.GetTextLineHeightWithSpacing.is_none() {
+            missing.push("GetTextLineHeightWithSpacing");
+        }
+        if self.pointers.LabelText.is_none() {
+            missing.push("LabelText");
+        }
+        if self.pointers.PopTextWrapPos.is_none() {
+            missing.push("PopTextWrapPos");
+        }
+        if self.pointers.PushTextWrapPos.is_none() {
+            missing.push("PushTextWrapPos");
+        }
+        if self.pointers.Text.is_none() {
+            missing.push("Text");
+        }
+        if self.pointers.TextColored.is_none() {
+            missing.push("TextColored");
+        }
+        if self.pointers.TextDisabled.is_none() {
+            missing.push("TextDisabled");
+        }
+        if self.pointers.TextWrapped.is_none() {
+            missing.push("TextWrapped");
+        }
+        if self.pointers.InputDouble.is_none() {
+            missing.push("InputDouble");
+        }
+        if self.pointers.InputDouble2.is_none() {
+            missing.push("InputDouble2");
+        }
+        if self.pointers.InputDouble3.is_none() {
+            missing.push("InputDouble3");
+        }
+        if self.pointers.InputDouble4.is_none() {
+            missing.push("InputDouble4");
+        }
+        if self.pointers.InputInt.is_none() {
+            missing.push("InputInt");
+        }
+        if self.pointers.InputInt2.is_none() {
+            missing.push("InputInt2");
+        }
+        if self.pointers.InputInt3.is_none() {
+            missing.push("InputInt3");
+        }
+        if self.pointers.InputInt4.is_none() {
+            missing.push("InputInt4");
+        }
+        if self.pointers.InputText.is_none() {
+            missing.push("InputText");
+        }
+        if self.pointers.InputTextMultiline.is_none() {
+            missing.push("InputTextMultiline");
+        }
+        if self.pointers.InputTextWithHint.is_none() {
+            missing.push("InputTextWithHint");
+        }
+        if self.pointers.CreateTextFilter.is_none() {
+            missing.push("CreateTextFilter");
+        }
+        if self.pointers.TextFilter_Clear.is_none() {
+            missing.push("TextFilter_Clear");
+        }
+        if self.pointers.TextFilter_Draw.is_none() {
+            missing.push("TextFilter_Draw");
+        }
+        if self.pointers.TextFilter_IsActive.is_none() {
+            missing.push("TextFilter_IsActive");
+        }
+        if self.pointers.TextFilter_PassFilter.is_none() {
+            missing.push("TextFilter_PassFilter");
+        }
+        if self.pointers.TextFilter_Set.is_none() {
+            missing.push("TextFilter_Set");
+        }
+        if self.pointers.CollapsingHeader.is_none() {
+            missing.push("CollapsingHeader");
+        }
+        if self.pointers.GetTreeNodeToLabelSpacing.is_none() {
+            missing.push("GetTreeNodeToLabelSpacing");
+        }
+        if self.pointers.IsItemToggledOpen.is_none() {
+            missing.push("IsItemToggledOpen");
+        }
+        if self.pointers.SetNextItemOpen.is_none() {
+            missing.push("SetNextItemOpen");
+        }
+        if self.pointers.TreeNode.is_none() {
+            missing.push("TreeNode");
+        }
+        if self.pointers.TreeNodeEx.is_none() {
+            missing.push("TreeNodeEx");
+        }
+        if self.pointers.TreePop.is_none() {
+            missing.push("TreePop");
+        }
+        if self.pointers.TreePush.is_none() {
+            missing.push("TreePush");
+        }
+        if self.pointers.GetVersion.is_none() {
+            missing.push("GetVersion");
+        }
+        if self.pointers.NumericLimits_Float.is_none() {
+            missing.push("NumericLimits_Float");
+        }
+        if self.pointers.PointConvertNative.is_none() {
+            missing.push("PointConvertNative");
+        }
+        if self.pointers.ProgressBar.is_none() {
+            missing.push("ProgressBar");
+        }
+        if self.pointers.ValidatePtr.is_none() {
+            missing.push("ValidatePtr");
+        }
+        if self.pointers.SetClipboardText.is_none() {
+            missing.push("SetClipboardText");
+        }
+        if self.pointers.ColorConvertDouble4ToU32.is_none() {
+            missing.push("ColorConvertDouble4ToU32");
+        }
+        if self.pointers.ColorConvertHSVtoRGB.is_none() {
+            missing.push("ColorConvertHSVtoRGB");
+        }
+        if self.pointers.ColorConvertNative.is_none() {
+            missing.push("ColorConvertNative");
+        }
+        if self.pointers.ColorConvertRGBtoHSV.is_none() {
+            missing.push("ColorConvertRGBtoHSV");
+        }
+        if self.pointers.ColorConvertU32ToDouble4.is_none() {
+            missing.push("ColorConvertU32ToDouble4");
+        }
+        if self.pointers.PopID.is_none() {
+            missing.push("PopID");
+        }
+        if self.pointers.PushID.is_none() {
+            missing.push("PushID");
+        }
+        if self.pointers.LogFinish.is_none() {
+            missing.push("LogFinish");
+        }
+        if self.pointers.LogText.is_none() {
+            missing.push("LogText");
+        }
+        if self.pointers.LogToClipboard.is_none() {
+            missing.push("LogToClipboard");
+        }
+        if self.pointers.LogToFile.is_none() {
+            missing.push("LogToFile");
+        }
+        if self.pointers.LogToTTY.is_none() {
+            missing.push("LogToTTY");
+        }
+        if self.pointers.GetMainViewport.is_none() {
+            missing.push("GetMainViewport");
+        }
+        if self.pointers.GetWindowViewport.is_none() {
+            missing.push("GetWindowViewport");
+        }
+        if self.pointers.Viewport_GetCenter.is_none() {
+            missing.push("Viewport_GetCenter");
+        }
+        if self.pointers.Viewport_GetPos.is_none() {
+            missing.push("Viewport_GetPos");
+        }
+        if self.pointers.Viewport_GetSize.is_none() {
+            missing.push("Viewport_GetSize");
+        }
+        if self.pointers.Viewport_GetWorkCenter.is_none() {
+            missing.push("Viewport_GetWorkCenter");
+        }
+        if self.pointers.Viewport_GetWorkPos.is_none() {
+            missing.push("Viewport_GetWorkPos");
+        }
+        if self.pointers.Viewport_GetWorkSize.is_none() {
+            missing.push("Viewport_GetWorkSize");
+        }
+        if self.pointers.Begin.is_none() {
+            missing.push("Begin");
+        }
+        if self.pointers.End.is_none() {
+            missing.push("End");
+        }
+        if self.pointers.BeginChild.is_none() {
+            missing.push("BeginChild");
+        }
+        if self.pointers.BeginChildFrame.is_none() {
+            missing.push("BeginChildFrame");
+        }
+        if self.pointers.EndChild.is_none() {
+            missing.push("EndChild");
+        }
+        if self.pointers.EndChildFrame.is_none() {
+            missing.push("EndChildFrame");
+        }
+        if self.pointers.GetContentRegionAvail.is_none() {
+            missing.push("GetContentRegionAvail");
+        }
+        if self.pointers.GetContentRegionMax.is_none() {
+            missing.push("GetContentRegionMax");
+        }
+        if self.pointers.GetWindowContentRegionMax.is_none() {
+            missing.push("GetWindowContentRegionMax");
+        }
+        if self.pointers.GetWindowContentRegionMin.is_none() {
+            missing.push("GetWindowContentRegionMin");
+        }
+        if self.pointers.ShowAboutWindow.is_none() {
+            missing.push("ShowAboutWindow");
+        }
+        if self.pointers.ShowDebugLogWindow.is_none() {
+            missing.push("ShowDebugLogWindow");
+        }
+        if self.pointers.ShowMetricsWindow.is_none() {
+            missing.push("ShowMetricsWindow");
+        }
+        if self.pointers.ShowStackToolWindow.is_none() {
+            missing.push("ShowStackToolWindow");
+        }
+        if self.pointers.GetWindowDockID.is_none() {
+            missing.push("GetWindowDockID");
+        }
+        if self.pointers.IsWindowDocked.is_none() {
+            missing.push("IsWindowDocked");
+        }
+        if self.pointers.SetNextWindowDockID.is_none() {
+            missing.push("SetNextWindowDockID");
+        }
+        if self.pointers.GetWindowDpiScale.is_none() {
+            missing.push("GetWindowDpiScale");
+        }
+        if self.pointers.GetWindowHeight.is_none() {
+            missing.push("GetWindowHeight");
+        }
+        if self.pointers.GetWindowPos.is_none() {
+            missing.push("GetWindowPos");
+        }
+        if self.pointers.GetWindowSize.is_none() {
+            missing.push("GetWindowSize");
+        }
+        if self.pointers.GetWindowWidth.is_none() {
+            missing.push("GetWindowWidth");
+        }
+        if self.pointers.IsWindowAppearing.is_none() {
+            missing.push("IsWindowAppearing");
+        }
+        if self.pointers.IsWindowCollapsed.is_none() {
+            missing.push("IsWindowCollapsed");
+        }
+        if self.pointers.IsWindowFocused.is_none() {
+            missing.push("IsWindowFocused");
+        }
+        if self.pointers.IsWindowHovered.is_none() {
+            missing.push("IsWindowHovered");
+        }
+        if self.pointers.SetNextWindowBgAlpha.is_none() {
+            missing.push("SetNextWindowBgAlpha");
+        }
+        if self.pointers.SetNextWindowCollapsed.is_none() {
+            missing.push("SetNextWindowCollapsed");
+        }
+        if self.pointers.SetNextWindowContentSize.is_none() {
+            missing.push("SetNextWindowContentSize");
+        }
+        if self.pointers.SetNextWindowFocus.is_none() {
+            missing.push("SetNextWindowFocus");
+        }
+        if self.pointers.SetNextWindowPos.is_none() {
+            missing.push("SetNextWindowPos");
+        }
+        if self.pointers.SetNextWindowScroll.is_none() {
+            missing.push("SetNextWindowScroll");
+        }
+        if self.pointers.SetNextWindowSize.is_none() {
+            missing.push("SetNextWindowSize");
+        }
+        if self.pointers.SetNextWindowSizeConstraints.is_none() {
+            missing.push("SetNextWindowSizeConstraints");
+        }
+        if self.pointers.SetWindowCollapsed.is_none() {
+            missing.push("SetWindowCollapsed");
+        }
+        if self.pointers.SetWindowCollapsedEx.is_none() {
+            missing.push("SetWindowCollapsedEx");
+        }
+        if self.pointers.SetWindowFocus.is_none() {
+            missing.push("SetWindowFocus");
+        }
+        if self.pointers.SetWindowFocusEx.is_none() {
+            missing.push("SetWindowFocusEx");
+        }
+        if self.pointers.SetWindowPos.is_none() {
+            missing.push("SetWindowPos");
+        }
+        if self.pointers.SetWindowPosEx.is_none() {
+            missing.push("SetWindowPosEx");
+        }
+        if self.pointers.SetWindowSize.is_none() {
+            missing.push("SetWindowSize");
+        }
+        if self.pointers.SetWindowSizeEx.is_none() {
+            missing.push("SetWindowSizeEx");
+        }
+        if self.pointers.GetScrollMaxX.is_none() {
+            missing.push("GetScrollMaxX");
+        }
+        if self.pointers.GetScrollMaxY.is_none() {
+            missing.push("GetScrollMaxY");
+        }
+        if self.pointers.GetScrollX.is_none() {
+            missing.push("GetScrollX");
+        }
+        if self.pointers.GetScrollY.is_none() {
+            missing.push("GetScrollY");
+        }
+        if self.pointers.SetScrollFromPosX.is_none() {
+            missing.push("SetScrollFromPosX");
+        }
+        if self.pointers.SetScrollFromPosY.is_none() {
+            missing.push("SetScrollFromPosY");
+        }
+        if self.pointers.SetScrollHereX.is_none() {
+            missing.push("SetScrollHereX");
+        }
+        if self.pointers.SetScrollHereY.is_none() {
+            missing.push("SetScrollHereY");
+        }
+        if self.pointers.SetScrollX.is_none() {
+            missing.push("SetScrollX");
+        }
+        if self.pointers.SetScrollY.is_none() {
+            missing.push("SetScrollY");
+        }
+        missing
+    }
+    #[doc = r" Names of constants that are not provided by the installed ReaImGui."]
+    pub fn missing_constants(&self) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+        if self.Dir_Down.is_none() {
+            missing.push("Dir_Down");
+        }
+        if self.Dir_Left.is_none() {
+            missing.push("Dir_Left");
+        }
+        if self.Dir_None.is_none() {
+            missing.push("Dir_None");
+        }
+        if self.Dir_Right.is_none() {
+            missing.push("Dir_Right");
+        }
+        if self.Dir_Up.is_none() {
+            missing.push("Dir_Up");
+        }
+        if self.ButtonFlags_MouseButtonLeft.is_none() {
+            missing.push("ButtonFlags_MouseButtonLeft");
+        }
+        if self.ButtonFlags_MouseButtonMiddle.is_none() {
+            missing.push("ButtonFlags_MouseButtonMiddle");
+        }
+        if self.ButtonFlags_MouseButtonRight.is_none() {
+            missing.push("ButtonFlags_MouseButtonRight");
+        }
+        if self.ButtonFlags_None.is_none() {
+            missing.push("ButtonFlags_None");
+        }
+        if self.ColorEditFlags_NoAlpha.is_none() {
+            missing.push("ColorEditFlags_NoAlpha");
+        }
+        if self.ColorEditFlags_NoBorder.is_none() {
+            missing.push("ColorEditFlags_NoBorder");
+        }
+        if self.ColorEditFlags_NoDragDrop.is_none() {
+            missing.push("ColorEditFlags_NoDragDrop");
+        }
+        if self.ColorEditFlags_NoInputs.is_none() {
+            missing.push("ColorEditFlags_NoInputs");
+        }
+        if self.ColorEditFlags_NoLabel.is_none() {
+            missing.push("ColorEditFlags_NoLabel");
+        }
+        if self.ColorEditFlags_NoOptions.is_none() {
+            missing.push("ColorEditFlags_NoOptions");
+        }
+        if self.ColorEditFlags_NoPicker.is_none() {
+            missing.push("ColorEditFlags_NoPicker");
+        }
+        if self.ColorEditFlags_NoSidePreview.is_none() {
+            missing.push("ColorEditFlags_NoSidePreview");
+        }
+        if self.ColorEditFlags_NoSmallPreview.is_none() {
+            missing.push("ColorEditFlags_NoSmallPreview");
+        }
+        if self.ColorEditFlags_NoTooltip.is_none() {
+            missing.push("ColorEditFlags_NoTooltip");
+        }
+        if self.ColorEditFlags_None.is_none() {
+            missing.push("ColorEditFlags_None");
+        }
+        if self.ColorEditFlags_AlphaBar.is_none() {
+            missing.push("ColorEditFlags_AlphaBar");
+        }
+        if self.ColorEditFlags_AlphaPreview.is_none() {
+            missing.push("ColorEditFlags_AlphaPreview");
+        }
+        if self.ColorEditFlags_AlphaPreviewHalf.is_none() {
+            missing.push("ColorEditFlags_AlphaPreviewHalf");
+        }
+        if self.ColorEditFlags_DisplayHSV.is_none() {
+            missing.push("ColorEditFlags_DisplayHSV");
+        }
+        if self.ColorEditFlags_DisplayHex.is_none() {
+            missing.push("ColorEditFlags_DisplayHex");
+        }
+        if self.ColorEditFlags_DisplayRGB.is_none() {
+            missing.push("ColorEditFlags_DisplayRGB");
+        }
+        if self.ColorEditFlags_Float.is_none() {
+            missing.push("ColorEditFlags_Float");
+        }
+        if self.ColorEditFlags_InputHSV.is_none() {
+            missing.push("ColorEditFlags_InputHSV");
+        }
+        if self.ColorEditFlags_InputRGB.is_none() {
+            missing.push("ColorEditFlags_InputRGB");
+        }
+        if self.ColorEditFlags_PickerHueBar.is_none() {
+            missing.push("ColorEditFlags_PickerHueBar");
+        }
+        if self.ColorEditFlags_PickerHueWheel.is_none() {
+            missing.push("ColorEditFlags_PickerHueWheel");
+        }
+        if self.ColorEditFlags_Uint8.is_none() {
+            missing.push("ColorEditFlags_Uint8");
+        }
+        if self.ComboFlags_HeightLarge.is_none() {
+            missing.push("ComboFlags_HeightLarge");
+        }
+        if self.ComboFlags_HeightLargest.is_none() {
+            missing.push("ComboFlags_HeightLargest");
+        }
+        if self.ComboFlags_HeightRegular.is_none() {
+            missing.push("ComboFlags_HeightRegular");
+        }
+        if self.ComboFlags_HeightSmall.is_none() {
+            missing.push("ComboFlags_HeightSmall");
+        }
+        if self.ComboFlags_NoArrowButton.is_none() {
+            missing.push("ComboFlags_NoArrowButton");
+        }
+        if self.ComboFlags_NoPreview.is_none() {
+            missing.push("ComboFlags_NoPreview");
+        }
+        if self.ComboFlags_None.is_none() {
+            missing.push("ComboFlags_None");
+        }
+        if self.ComboFlags_PopupAlignLeft.is_none() {
+            missing.push("ComboFlags_PopupAlignLeft");
+        }
+        if self.SelectableFlags_AllowDoubleClick.is_none() {
+            missing.push("SelectableFlags_AllowDoubleClick");
+        }
+        if self.SelectableFlags_AllowItemOverlap.is_none() {
+            missing.push("SelectableFlags_AllowItemOverlap");
+        }
+        if self.SelectableFlags_Disabled.is_none() {
+            missing.push("SelectableFlags_Disabled");
+        }
+        if self.SelectableFlags_DontClosePopups.is_none() {
+            missing.push("SelectableFlags_DontClosePopups");
+        }
+        if self.SelectableFlags_None.is_none() {
+            missing.push("SelectableFlags_None");
+        }
+        if self.SelectableFlags_SpanAllColumns.is_none() {
+            missing.push("SelectableFlags_SpanAllColumns");
+        }
+        if self.ConfigFlags_DockingEnable.is_none() {
+            missing.push("ConfigFlags_DockingEnable");
+        }
+        if self.ConfigFlags_NavEnableKeyboard.is_none() {
+            missing.push("ConfigFlags_NavEnableKeyboard");
+        }
+        if self.ConfigFlags_NavEnableSetMousePos.is_none() {
+            missing.push("ConfigFlags_NavEnableSetMousePos");
+        }
+        if self.ConfigFlags_NavNoCaptureKeyboard.is_none() {
+            missing.push("ConfigFlags_NavNoCaptureKeyboard");
+        }
+        if self.ConfigFlags_NoMouse.is_none() {
+            missing.push("ConfigFlags_NoMouse");
+        }
+        if self.ConfigFlags_NoMouseCursorChange.is_none() {
+            missing.push("ConfigFlags_NoMouseCursorChange");
+        }
+        if self.ConfigFlags_NoSavedSettings.is_none() {
+            missing.push("ConfigFlags_NoSavedSettings");
+        }
+        if self.ConfigFlags_None.is_none() {
+            missing.push("ConfigFlags_None");
+        }
+        if self.ConfigVar_DockingNoSplit.is_none() {
+            missing.push("ConfigVar_DockingNoSplit");
+        }
+        if self.ConfigVar_DockingTransparentPayload.is_none() {
+            missing.push("ConfigVar_DockingTransparentPayload");
+        }
+        if self.ConfigVar_DockingWithShift.is_none() {
+            missing.push("ConfigVar_DockingWithShift");
+        }
+        if self.ConfigVar_DragClickToInputText.is_none() {
+            missing.push("ConfigVar_DragClickToInputText");
+        }
+        if self.ConfigVar_Flags.is_none() {
+            missing.push("ConfigVar_Flags");
+        }
+        if self.ConfigVar_HoverDelayNormal.is_none() {
+            missing.push("ConfigVar_HoverDelayNormal");
+        }
+        if self.ConfigVar_HoverDelayShort.is_none() {
+            missing.push("ConfigVar_HoverDelayShort");
+        }
+        if self.ConfigVar_InputTextCursorBlink.is_none() {
+            missing.push("ConfigVar_InputTextCursorBlink");
+        }
+        if self.ConfigVar_InputTextEnterKeepActive.is_none() {
+            missing.push("ConfigVar_InputTextEnterKeepActive");
+        }
+        if self.ConfigVar_InputTrickleEventQueue.is_none() {
+            missing.push("ConfigVar_InputTrickleEventQueue");
+        }
+        if self.ConfigVar_KeyRepeatDelay.is_none() {
+            missing.push("ConfigVar_KeyRepeatDelay");
+        }
+        if self.ConfigVar_KeyRepeatRate.is_none() {
+            missing.push("ConfigVar_KeyRepeatRate");
+        }
+        if self.ConfigVar_MacOSXBehaviors.is_none() {
+            missing.push("ConfigVar_MacOSXBehaviors");
+        }
+        if self.ConfigVar_MouseDoubleClickMaxDist.is_none() {
+            missing.push("ConfigVar_MouseDoubleClickMaxDist");
+        }
+        if self.ConfigVar_MouseDoubleClickTime.is_none() {
+            missing.push("ConfigVar_MouseDoubleClickTime");
+        }
+        if self.ConfigVar_MouseDragThreshold.is_none() {
+            missing.push("ConfigVar_MouseDragThreshold");
+        }
+        if self.ConfigVar_ViewportsNoDecoration.is_none() {
+            missing.push("ConfigVar_ViewportsNoDecoration");
+        }
+        if self.ConfigVar_WindowsMoveFromTitleBarOnly.is_none() {
+            missing.push("ConfigVar_WindowsMoveFromTitleBarOnly");
+        }
+        if self.ConfigVar_WindowsResizeFromEdges.is_none() {
+            missing.push("ConfigVar_WindowsResizeFromEdges");
+        }
+        if self.DragDropFlags_None.is_none() {
+            missing.push("DragDropFlags_None");
+        }
+        if self.DragDropFlags_AcceptBeforeDelivery.is_none() {
+            missing.push("DragDropFlags_AcceptBeforeDelivery");
+        }
+        if self.DragDropFlags_AcceptNoDrawDefaultRect.is_none() {
+            missing.push("DragDropFlags_AcceptNoDrawDefaultRect");
+        }
+        if self.DragDropFlags_AcceptNoPreviewTooltip.is_none() {
+            missing.push("DragDropFlags_AcceptNoPreviewTooltip");
+        }
+        if self.DragDropFlags_AcceptPeekOnly.is_none() {
+            missing.push("DragDropFlags_AcceptPeekOnly");
+        }
+        if self.DragDropFlags_SourceAllowNullID.is_none() {
+            missing.push("DragDropFlags_SourceAllowNullID");
+        }
+        if self.DragDropFlags_SourceAutoExpirePayload.is_none() {
+            missing.push("DragDropFlags_SourceAutoExpirePayload");
+        }
+        if self.DragDropFlags_SourceExtern.is_none() {
+            missing.push("DragDropFlags_SourceExtern");
+        }
+        if self.DragDropFlags_SourceNoDisableHover.is_none() {
+            missing.push("DragDropFlags_SourceNoDisableHover");
+        }
+        if self.DragDropFlags_SourceNoHoldToOpenOthers.is_none() {
+            missing.push("DragDropFlags_SourceNoHoldToOpenOthers");
+        }
+        if self.DragDropFlags_SourceNoPreviewTooltip.is_none() {
+            missing.push("DragDropFlags_SourceNoPreviewTooltip");
+        }
+        if self.SliderFlags_AlwaysClamp.is_none() {
+            missing.push("SliderFlags_AlwaysClamp");
+        }
+        if self.SliderFlags_Logarithmic.is_none() {
+            missing.push("SliderFlags_Logarithmic");
+        }
+        if self.SliderFlags_NoInput.is_none() {
+            missing.push("SliderFlags_NoInput");
+        }
+        if self.SliderFlags_NoRoundToFormat.is_none() {
+            missing.push("SliderFlags_NoRoundToFormat");
+        }
+        if self.SliderFlags_None.is_none() {
+            missing.push("SliderFlags_None");
+        }
+        if self.DrawFlags_Closed.is_none() {
+            missing.push("DrawFlags_Closed");
+        }
+        if self.DrawFlags_None.is_none() {
+            missing.push("DrawFlags_None");
+        }
+        if self.DrawFlags_RoundCornersAll.is_none() {
+            missing.push("DrawFlags_RoundCornersAll");
+        }
+        if self.DrawFlags_RoundCornersBottom.is_none() {
+            missing.push("DrawFlags_RoundCornersBottom");
+        }
+        if self.DrawFlags_RoundCornersBottomLeft.is_none() {
+            missing.push("DrawFlags_RoundCornersBottomLeft");
+        }
+        if self.DrawFlags_RoundCornersBottomRight.is_none() {
+            missing.push("DrawFlags_RoundCornersBottomRight");
+        }
+        if self.DrawFlags_RoundCornersLeft.is_none() {
+            missing.push("DrawFlags_RoundCornersLeft");
+        }
+        if self.DrawFlags_RoundCornersNone.is_none() {
+            missing.push("DrawFlags_RoundCornersNone");
+        }
+        if self.DrawFlags_RoundCornersRight.is_none() {
+            missing.push("DrawFlags_RoundCornersRight");
+        }
+        if self.DrawFlags_RoundCornersTop.is_none() {
+            missing.push("DrawFlags_RoundCornersTop");
+        }
+        if self.DrawFlags_RoundCornersTopLeft.is_none() {
+            missing.push("DrawFlags_RoundCornersTopLeft");
+        }
+        if self.DrawFlags_RoundCornersTopRight.is_none() {
+            missing.push("DrawFlags_RoundCornersTopRight");
+        }
+        if self.FontFlags_Bold.is_none() {
+            missing.push("FontFlags_Bold");
+        }
+        if self.FontFlags_Italic.is_none() {
+            missing.push("FontFlags_Italic");
+        }
+        if self.FontFlags_None.is_none() {
+            missing.push("FontFlags_None");
+        }
+        if self.HoveredFlags_AllowWhenBlockedByActiveItem.is_none() {
+            missing.push("HoveredFlags_AllowWhenBlockedByActiveItem");
+        }
+        if self.HoveredFlags_AllowWhenBlockedByPopup.is_none() {
+            missing.push("HoveredFlags_AllowWhenBlockedByPopup");
+        }
+        if self.HoveredFlags_DelayNormal.is_none() {
+            missing.push("HoveredFlags_DelayNormal");
+        }
+        if self.HoveredFlags_DelayShort.is_none() {
+            missing.push("HoveredFlags_DelayShort");
+        }
+        if self.HoveredFlags_NoNavOverride.is_none() {
+            missing.push("HoveredFlags_NoNavOverride");
+        }
+        if self.HoveredFlags_NoSharedDelay.is_none() {
+            missing.push("HoveredFlags_NoSharedDelay");
+        }
+        if self.HoveredFlags_None.is_none() {
+            missing.push("HoveredFlags_None");
+        }
+        if self.HoveredFlags_AllowWhenDisabled.is_none() {
+            missing.push("HoveredFlags_AllowWhenDisabled");
+        }
+        if self.HoveredFlags_AllowWhenOverlapped.is_none() {
+            missing.push("HoveredFlags_AllowWhenOverlapped");
+        }
+        if self.HoveredFlags_RectOnly.is_none() {
+            missing.push("HoveredFlags_RectOnly");
+        }
+        if self.HoveredFlags_AnyWindow.is_none() {
+            missing.push("HoveredFlags_AnyWindow");
+        }
+        if self.HoveredFlags_ChildWindows.is_none() {
+            missing.push("HoveredFlags_ChildWindows");
+        }
+        if self.HoveredFlags_DockHierarchy.is_none() {
+            missing.push("HoveredFlags_DockHierarchy");
+        }
+        if self.HoveredFlags_NoPopupHierarchy.is_none() {
+            missing.push("HoveredFlags_NoPopupHierarchy");
+        }
+        if self.HoveredFlags_RootAndChildWindows.is_none() {
+            missing.push("HoveredFlags_RootAndChildWindows");
+        }
+        if self.HoveredFlags_RootWindow.is_none() {
+            missing.push("HoveredFlags_RootWindow");
+        }
+        if self.Key_0.is_none() {
+            missing.push("Key_0");
+        }
+        if self.Key_1.is_none() {
+            missing.push("Key_1");
+        }
+        if self.Key_2.is_none() {
+            missing.push("Key_2");
+        }
+        if self.Key_3.is_none() {
+            missing.push("Key_3");
+        }
+        if self.Key_4.is_none() {
+            missing.push("Key_4");
+        }
+        if self.Key_5.is_none() {
+            missing.push("Key_5");
+        }
+        if self.Key_6.is_none() {
+            missing.push("Key_6");
+        }
+        if self.Key_7.is_none() {
+            missing.push("Key_7");
+        }
+        if self.Key_8.is_none() {
+            missing.push("Key_8");
+        }
+        if self.Key_9.is_none() {
+            missing.push("Key_9");
+        }
+        if self.Key_A.is_none() {
+            missing.push("Key_A");
+        }
+        if self.Key_Apostrophe.is_none() {
+            missing.push("Key_Apostrophe");
+        }
+        if self.Key_B.is_none() {
+            missing.push("Key_B");
+        }
+        if self.Key_Backslash.is_none() {
+            missing.push("Key_Backslash");
+        }
+        if self.Key_Backspace.is_none() {
+            missing.push("Key_Backspace");
+        }
+        if self.Key_C.is_none() {
+            missing.push("Key_C");
+        }
+        if self.Key_CapsLock.is_none() {
+            missing.push("Key_CapsLock");
+        }
+        if self.Key_Comma.is_none() {
+            missing.push("Key_Comma");
+        }
+        if self.Key_D.is_none() {
+            missing.push("Key_D");
+        }
+        if self.Key_Delete.is_none() {
+            missing.push("Key_Delete");
+        }
+        if self.Key_DownArrow.is_none() {
+            missing.push("Key_DownArrow");
+        }
+        if self.Key_E.is_none() {
+            missing.push("Key_E");
+        }
+        if self.Key_End.is_none() {
+            missing.push("Key_End");
+        }
+        if self.Key_Enter.is_none() {
+            missing.push("Key_Enter");
+        }
+        if self.Key_Equal.is_none() {
+            missing.push("Key_Equal");
+        }
+        if self.Key_Escape.is_none() {
+            missing.push("Key_Escape");
+        }
+        if self.Key_F.is_none() {
+            missing.push("Key_F");
+        }
+        if self.Key_F1.is_none() {
+            missing.push("Key_F1");
+        }
+        if self.Key_F10.is_none() {
+            missing.push("Key_F10");
+        }
+        if self.Key_F11.is_none() {
+            missing.push("Key_F11");
+        }
+        if self.Key_F12.is_none() {
+            missing.push("Key_F12");
+        }
+        if self.Key_F2.is_none() {
+            missing.push("Key_F2");
+        }
+        if self.Key_F3.is_none() {
+            missing.push("Key_F3");
+        }
+        if self.Key_F4.is_none() {
+            missing.push("Key_F4");
+        }
+        if self.Key_F5.is_none() {
+            missing.push("Key_F5");
+        }
+        if self.Key_F6.is_none() {
+            missing.push("Key_F6");
+        }
+        if self.Key_F7.is_none() {
+            missing.push("Key_F7");
+        }
+        if self.Key_F8.is_none() {
+            missing.push("Key_F8");
+        }
+        if self.Key_F9.is_none() {
+            missing.push("Key_F9");
+        }
+        if self.Key_G.is_none() {
+            missing.push("Key_G");
+        }
+        if self.Key_GraveAccent.is_none() {
+            missing.push("Key_GraveAccent");
+        }
+        if self.Key_H.is_none() {
+            missing.push("Key_H");
+        }
+        if self.Key_Home.is_none() {
+            missing.push("Key_Home");
+        }
+        if self.Key_I.is_none() {
+            missing.push("Key_I");
+        }
+        if self.Key_Insert.is_none() {
+            missing.push("Key_Insert");
+        }
+        if self.Key_J.is_none() {
+            missing.push("Key_J");
+        }
+        if self.Key_K.is_none() {
+            missing.push("Key_K");
+        }
+        if self.Key_Keypad0.is_none() {
+            missing.push("Key_Keypad0");
+        }
+        if self.Key_Keypad1.is_none() {
+            missing.push("Key_Keypad1");
+        }
+        if self.Key_Keypad2.is_none() {
+            missing.push("Key_Keypad2");
+        }
+        if self.Key_Keypad3.is_none() {
+            missing.push("Key_Keypad3");
+        }
+        if self.Key_Keypad4.is_none() {
+            missing.push("Key_Keypad4");
+        }
+        if self.Key_Keypad5.is_none() {
+            missing.push("Key_Keypad5");
+        }
+        if self.Key_Keypad6.is_none() {
+            missing.push("Key_Keypad6");
+        }
+        if self.Key_Keypad7.is_none() {
+            missing.push("Key_Keypad7");
+        }
+        if self.Key_Keypad8.is_none() {
+            missing.push("Key_Keypad8");
+        }
+        if self.Key_Keypad9.is_none() {
+            missing.push("Key_Keypad9");
+        }
+        if self.Key_KeypadAdd.is_none() {
+            missing.push("Key_KeypadAdd");
+        }
+        if self.Key_KeypadDecimal.is_none() {
+            missing.push("Key_KeypadDecimal");
+        }
+        if self.Key_KeypadDivide.is_none() {
+            missing.push("Key_KeypadDivide");
+        }
+        if self.Key_KeypadEnter.is_none() {
+            missing.push("Key_KeypadEnter");
+        }
+        if self.Key_KeypadEqual.is_none() {
+            missing.push("Key_KeypadEqual");
+        }
+        if self.Key_KeypadMultiply.is_none() {
+            missing.push("Key_KeypadMultiply");
+        }
+        if self.Key_KeypadSubtract.is_none() {
+            missing.push("Key_KeypadSubtract");
+        }
+        if self.Key_L.is_none() {
+            missing.push("Key_L");
+        }
+        if self.Key_LeftAlt.is_none() {
+            missing.push("Key_LeftAlt");
+        }
+        if self.Key_LeftArrow.is_none() {
+            missing.push("Key_LeftArrow");
+        }
+        if self.Key_LeftBracket.is_none() {
+            missing.push("Key_LeftBracket");
+        }
+        if self.Key_LeftCtrl.is_none() {
+            missing.push("Key_LeftCtrl");
+        }
+        if self.Key_LeftShift.is_none() {
+            missing.push("Key_LeftShift");
+        }
+        if self.Key_LeftSuper.is_none() {
+            missing.push("Key_LeftSuper");
+        }
+        if self.Key_M.is_none() {
+            missing.push("Key_M");
+        }
+        if self.Key_Menu.is_none() {
+            missing.push("Key_Menu");
+        }
+        if self.Key_Minus.is_none() {
+            missing.push("Key_Minus");
+        }
+        if self.Key_N.is_none() {
+            missing.push("Key_N");
+        }
+        if self.Key_NumLock.is_none() {
+            missing.push("Key_NumLock");
+        }
+        if self.Key_O.is_none() {
+            missing.push("Key_O");
+        }
+        if self.Key_P.is_none() {
+            missing.push("Key_P");
+        }
+        if self.Key_PageDown.is_none() {
+            missing.push("Key_PageDown");
+        }
+        if self.Key_PageUp.is_none() {
+            missing.push("Key_PageUp");
+        }
+        if self.Key_Pause.is_none() {
+            missing.push("Key_Pause");
+        }
+        if self.Key_Period.is_none() {
+            missing.push("Key_Period");
+        }
+        if self.Key_PrintScreen.is_none() {
+            missing.push("Key_PrintScreen");
+        }
+        if self.Key_Q.is_none() {
+            missing.push("Key_Q");
+        }
+        if self.Key_R.is_none() {
+            missing.push("Key_R");
+        }
+        if self.Key_RightAlt.is_none() {
+            missing.push("Key_RightAlt");
+        }
+        if self.Key_RightArrow.is_none() {
+            missing.push("Key_RightArrow");
+        }
+        if self.Key_RightBracket.is_none() {
+            missing.push("Key_RightBracket");
+        }
+        if self.Key_RightCtrl.is_none() {
+            missing.push("Key_RightCtrl");
+        }
+        if self.Key_RightShift.is_none() {
+            missing.push("Key_RightShift");
+        }
+        if self.Key_RightSuper.is_none() {
+            missing.push("Key_RightSuper");
+        }
+        if self.Key_S.is_none() {
+            missing.push("Key_S");
+        }
+        if self.Key_ScrollLock.is_none() {
+            missing.push("Key_ScrollLock");
+        }
+        if self.Key_Semicolon.is_none() {
+            missing.push("Key_Semicolon");
+        }
+        if self.Key_Slash.is_none() {
+            missing.push("Key_Slash");
+        }
+        if self.Key_Space.is_none() {
+            missing.push("Key_Space");
+        }
+        if self.Key_T.is_none() {
+            missing.push("Key_T");
+        }
+        if self.Key_Tab.is_none() {
+            missing.push("Key_Tab");
+        }
+        if self.Key_U.is_none() {
+            missing.push("Key_U");
+        }
+        if self.Key_UpArrow.is_none() {
+            missing.push("Key_UpArrow");
+        }
+        if self.Key_V.is_none() {
+            missing.push("Key_V");
+        }
+        if self.Key_W.is_none() {
+            missing.push("Key_W");
+        }
+        if self.Key_X.is_none() {
+            missing.push("Key_X");
+        }
+        if self.Key_Y.is_none() {
+            missing.push("Key_Y");
+        }
+        if self.Key_Z.is_none() {
+            missing.push("Key_Z");
+        }
+        if self.Mod_Alt.is_none() {
+            missing.push("Mod_Alt");
+        }
+        if self.Mod_Ctrl.is_none() {
+            missing.push("Mod_Ctrl");
+        }
+        if self.Mod_None.is_none() {
+            missing.push("Mod_None");
+        }
+        if self.Mod_Shift.is_none() {
+            missing.push("Mod_Shift");
+        }
+        if self.Mod_Shortcut.is_none() {
+            missing.push("Mod_Shortcut");
+        }
+        if self.Mod_Super.is_none() {
+            missing.push("Mod_Super");
+        }
+        if self.Key_MouseLeft.is_none() {
+            missing.push("Key_MouseLeft");
+        }
+        if self.Key_MouseMiddle.is_none() {
+            missing.push("Key_MouseMiddle");
+        }
+        if self.Key_MouseRight.is_none() {
+            missing.push("Key_MouseRight");
+        }
+        if self.Key_MouseWheelX.is_none() {
+            missing.push("Key_MouseWheelX");
+        }
+        if self.Key_MouseWheelY.is_none() {
+            missing.push("Key_MouseWheelY");
+        }
+        if self.Key_MouseX1.is_none() {
+            missing.push("Key_MouseX1");
+        }
+        if self.Key_MouseX2.is_none() {
+            missing.push("Key_MouseX2");
+        }
+        if self.MouseButton_Left.is_none() {
+            missing.push("MouseButton_Left");
+        }
+        if self.MouseButton_Middle.is_none() {
+            missing.push("MouseButton_Middle");
+        }
+        if self.MouseButton_Right.is_none() {
+            missing.push("MouseButton_Right");
+        }
+        if self.MouseCursor_Arrow.is_none() {
+            missing.push("MouseCursor_Arrow");
+        }
+        if self.MouseCursor_Hand.is_none() {
+            missing.push("MouseCursor_Hand");
+        }
+        if self.MouseCursor_NotAllowed.is_none() {
+            missing.push("MouseCursor_NotAllowed");
+        }
+        if self.MouseCursor_ResizeAll.is_none() {
+            missing.push("MouseCursor_ResizeAll");
+        }
+        if self.MouseCursor_ResizeEW.is_none() {
+            missing.push("MouseCursor_ResizeEW");
+        }
+        if self.MouseCursor_ResizeNESW.is_none() {
+            missing.push("MouseCursor_ResizeNESW");
+        }
+        if self.MouseCursor_ResizeNS.is_none() {
+            missing.push("MouseCursor_ResizeNS");
+        }
+        if self.MouseCursor_ResizeNWSE.is_none() {
+            missing.push("MouseCursor_ResizeNWSE");
+        }
+        if self.MouseCursor_TextInput.is_none() {
+            missing.push("MouseCursor_TextInput");
+        }
+        if self.PopupFlags_NoOpenOverExistingPopup.is_none() {
+            missing.push("PopupFlags_NoOpenOverExistingPopup");
+        }
+        if self.PopupFlags_None.is_none() {
+            missing.push("PopupFlags_None");
+        }
+        if self.PopupFlags_MouseButtonLeft.is_none() {
+            missing.push("PopupFlags_MouseButtonLeft");
+        }
+        if self.PopupFlags_MouseButtonMiddle.is_none() {
+            missing.push("PopupFlags_MouseButtonMiddle");
+        }
+        if self.PopupFlags_MouseButtonRight.is_none() {
+            missing.push("PopupFlags_MouseButtonRight");
+        }
+        if self.PopupFlags_NoOpenOverItems.is_none() {
+            missing.push("PopupFlags_NoOpenOverItems");
+        }
+        if self.PopupFlags_AnyPopup.is_none() {
+            missing.push("PopupFlags_AnyPopup");
+        }
+        if self.PopupFlags_AnyPopupId.is_none() {
+            missing.push("PopupFlags_AnyPopupId");
+        }
+        if self.PopupFlags_AnyPopupLevel.is_none() {
+            missing.push("PopupFlags_AnyPopupLevel");
+        }
+        if self.Col_Border.is_none() {
+            missing.push("Col_Border");
+        }
+        if self.Col_BorderShadow.is_none() {
+            missing.push("Col_BorderShadow");
+        }
+        if self.Col_Button.is_none() {
+            missing.push("Col_Button");
+        }
+        if self.Col_ButtonActive.is_none() {
+            missing.push("Col_ButtonActive");
+        }
+        if self.Col_ButtonHovered.is_none() {
+            missing.push("Col_ButtonHovered");
+        }
+        if self.Col_CheckMark.is_none() {
+            missing.push("Col_CheckMark");
+        }
+        if self.Col_ChildBg.is_none() {
+            missing.push("Col_ChildBg");
+        }
+        if self.Col_DockingEmptyBg.is_none() {
+            missing.push("Col_DockingEmptyBg");
+        }
+        if self.Col_DockingPreview.is_none() {
+            missing.push("Col_DockingPreview");
+        }
+        if self.Col_DragDropTarget.is_none() {
+            missing.push("Col_DragDropTarget");
+        }
+        if self.Col_FrameBg.is_none() {
+            missing.push("Col_FrameBg");
+        }
+        if self.Col_FrameBgActive.is_none() {
+            missing.push("Col_FrameBgActive");
+        }
+        if self.Col_FrameBgHovered.is_none() {
+            missing.push("Col_FrameBgHovered");
+        }
+        if self.Col_Header.is_none() {
+            missing.push("Col_Header");
+        }
+        if self.Col_HeaderActive.is_none() {
+            missing.push("Col_HeaderActive");
+        }
+        if self.Col_HeaderHovered.is_none() {
+            missing.push("Col_HeaderHovered");
+        }
+        if self.Col_MenuBarBg.is_none() {
+            missing.push("Col_MenuBarBg");
+        }
+        if self.Col_ModalWindowDimBg.is_none() {
+            missing.push("Col_ModalWindowDimBg");
+        }
+        if self.Col_NavHighlight.is_none() {
+            missing.push("Col_NavHighlight");
+        }
+        if self.Col_NavWindowingDimBg.is_none() {
+            missing.push("Col_NavWindowingDimBg");
+        }
+        if self.Col_NavWindowingHighlight.is_none() {
+            missing.push("Col_NavWindowingHighlight");
+        }
+        if self.Col_PlotHistogram.is_none() {
+            missing.push("Col_PlotHistogram");
+        }
+        if self.Col_PlotHistogramHovered.is_none() {
+            missing.push("Col_PlotHistogramHovered");
+        }
+        if self.Col_PlotLines.is_none() {
+            missing.push("Col_PlotLines");
+        }
+        if self.Col_PlotLinesHovered.is_none() {
+            missing.push("Col_PlotLinesHovered");
+        }
+        if self.Col_PopupBg.is_none() {
+            missing.push("Col_PopupBg");
+        }
+        if self.Col_ResizeGrip.is_none() {
+            missing.push("Col_ResizeGrip");
+        }
+        if self.Col_ResizeGripActive.is_none() {
+            missing.push("Col_ResizeGripActive");
+        }
+        if self.Col_ResizeGripHovered.is_none() {
+            missing.push("Col_ResizeGripHovered");
+        }
+        if self.Col_ScrollbarBg.is_none() {
+            missing.push("Col_ScrollbarBg");
+        }
+        if self.Col_ScrollbarGrab.is_none() {
+            missing.push("Col_ScrollbarGrab");
+        }
+        if self.Col_ScrollbarGrabActive.is_none() {
+            missing.push("Col_ScrollbarGrabActive");
+        }
+        if self.Col_ScrollbarGrabHovered.is_none() {
+            missing.push("Col_ScrollbarGrabHovered");
+        }
+        if self.Col_Separator.is_none() {
+            missing.push("Col_Separator");
+        }
+        if self.Col_SeparatorActive.is_none() {
+            missing.push("Col_SeparatorActive");
+        }
+        if self.Col_SeparatorHovered.is_none() {
+            missing.push("Col_SeparatorHovered");
+        }
+        if self.Col_SliderGrab.is_none() {
+            missing.push("Col_SliderGrab");
+        }
+        if self.Col_SliderGrabActive.is_none() {
+            missing.push("Col_SliderGrabActive");
+        }
+        if self.Col_Tab.is_none() {
+            missing.push("Col_Tab");
+        }
+        if self.Col_TabActive.is_none() {
+            missing.push("Col_TabActive");
+        }
+        if self.Col_TabHovered.is_none() {
+            missing.push("Col_TabHovered");
+        }
+        if self.Col_TabUnfocused.is_none() {
+            missing.push("Col_TabUnfocused");
+        }
+        if self.Col_TabUnfocusedActive.is_none() {
+            missing.push("Col_TabUnfocusedActive");
+        }
+        if self.Col_TableBorderLight.is_none() {
+            missing.push("Col_TableBorderLight");
+        }
+        if self.Col_TableBorderStrong.is_none() {
+            missing.push("Col_TableBorderStrong");
+        }
+        if self.Col_TableHeaderBg.is_none() {
+            missing.push("Col_TableHeaderBg");
+        }
+        if self.Col_TableRowBg.is_none() {
+            missing.push("Col_TableRowBg");
+        }
+        if self.Col_TableRowBgAlt.is_none() {
+            missing.push("Col_TableRowBgAlt");
+        }
+        if self.Col_Text.is_none() {
+            missing.push("Col_Text");
+        }
+        if self.Col_TextDisabled.is_none() {
+            missing.push("Col_TextDisabled");
+        }
+        if self.Col_TextSelectedBg.is_none() {
+            missing.push("Col_TextSelectedBg");
+        }
+        if self.Col_TitleBg.is_none() {
+            missing.push("Col_TitleBg");
+        }
+        if self.Col_TitleBgActive.is_none() {
+            missing.push("Col_TitleBgActive");
+        }
+        if self.Col_TitleBgCollapsed.is_none() {
+            missing.push("Col_TitleBgCollapsed");
+        }
+        if self.Col_WindowBg.is_none() {
+            missing.push("Col_WindowBg");
+        }
+        if self.StyleVar_Alpha.is_none() {
+            missing.push("StyleVar_Alpha");
+        }
+        if self.StyleVar_ButtonTextAlign.is_none() {
+            missing.push("StyleVar_ButtonTextAlign");
+        }
+        if self.StyleVar_CellPadding.is_none() {
+            missing.push("StyleVar_CellPadding");
+        }
+        if self.StyleVar_ChildBorderSize.is_none() {
+            missing.push("StyleVar_ChildBorderSize");
+        }
+        if self.StyleVar_ChildRounding.is_none() {
+            missing.push("StyleVar_ChildRounding");
+        }
+        if self.StyleVar_DisabledAlpha.is_none() {
+            missing.push("StyleVar_DisabledAlpha");
+        }
+        if self.StyleVar_FrameBorderSize.is_none() {
+            missing.push("StyleVar_FrameBorderSize");
+        }
+        if self.StyleVar_FramePadding.is_none() {
+            missing.push("StyleVar_FramePadding");
+        }
+        if self.StyleVar_FrameRounding.is_none() {
+            missing.push("StyleVar_FrameRounding");
+        }
+        if self.StyleVar_GrabMinSize.is_none() {
+            missing.push("StyleVar_GrabMinSize");
+        }
+        if self.StyleVar_GrabRounding.is_none() {
+            missing.push("StyleVar_GrabRounding");
+        }
+        if self.StyleVar_IndentSpacing.is_none() {
+            missing.push("StyleVar_IndentSpacing");
+        }
+        if self.StyleVar_ItemInnerSpacing.is_none() {
+            missing.push("StyleVar_ItemInnerSpacing");
+        }
+        if self.StyleVar_ItemSpacing.is_none() {
+            missing.push("StyleVar_ItemSpacing");
+        }
+        if self.StyleVar_PopupBorderSize.is_none() {
+            missing.push("StyleVar_PopupBorderSize");
+        }
+        if self.StyleVar_PopupRounding.is_none() {
+            missing.push("StyleVar_PopupRounding");
+        }
+        if self.StyleVar_ScrollbarRounding.is_none() {
+            missing.push("StyleVar_ScrollbarRounding");
+        }
+        if self.StyleVar_ScrollbarSize.is_none() {
+            missing.push("StyleVar_ScrollbarSize");
+        }
+        if self.StyleVar_SelectableTextAlign.is_none() {
+            missing.push("StyleVar_SelectableTextAlign");
+        }
+        if self.StyleVar_TabRounding.is_none() {
+            missing.push("StyleVar_TabRounding");
+        }
+        if self.StyleVar_WindowBorderSize.is_none() {
+            missing.push("StyleVar_WindowBorderSize");
+        }
+        if self.StyleVar_WindowMinSize.is_none() {
+            missing.push("StyleVar_WindowMinSize");
+        }
+        if self.StyleVar_WindowPadding.is_none() {
+            missing.push("StyleVar_WindowPadding");
+        }
+        if self.StyleVar_WindowRounding.is_none() {
+            missing.push("StyleVar_WindowRounding");
+        }
+        if self.StyleVar_WindowTitleAlign.is_none() {
+            missing.push("StyleVar_WindowTitleAlign");
+        }
+        if self.TabBarFlags_AutoSelectNewTabs.is_none() {
+            missing.push("TabBarFlags_AutoSelectNewTabs");
+        }
+        if self.TabBarFlags_FittingPolicyResizeDown.is_none() {
+            missing.push("TabBarFlags_FittingPolicyResizeDown");
+        }
+        if self.TabBarFlags_FittingPolicyScroll.is_none() {
+            missing.push("TabBarFlags_FittingPolicyScroll");
+        }
+        if self.TabBarFlags_NoCloseWithMiddleMouseButton.is_none() {
+            missing.push("TabBarFlags_NoCloseWithMiddleMouseButton");
+        }
+        if self.TabBarFlags_NoTabListScrollingButtons.is_none() {
+            missing.push("TabBarFlags_NoTabListScrollingButtons");
+        }
+        if self.TabBarFlags_NoTooltip.is_none() {
+            missing.push("TabBarFlags_NoTooltip");
+        }
+        if self.TabBarFlags_None.is_none() {
+            missing.push("TabBarFlags_None");
+        }
+        if self.TabBarFlags_Reorderable.is_none() {
+            missing.push("TabBarFlags_Reorderable");
+        }
+        if self.TabBarFlags_TabListPopupButton.is_none() {
+            missing.push("TabBarFlags_TabListPopupButton");
+        }
+        if self.TabItemFlags_Leading.is_none() {
+            missing.push("TabItemFlags_Leading");
+        }
+        if self.TabItemFlags_NoCloseWithMiddleMouseButton.is_none() {
+            missing.push("TabItemFlags_NoCloseWithMiddleMouseButton");
+        }
+        if self.TabItemFlags_NoPushId.is_none() {
+            missing.push("TabItemFlags_NoPushId");
+        }
+        if self.TabItemFlags_NoReorder.is_none() {
+            missing.push("TabItemFlags_NoReorder");
+        }
+        if self.TabItemFlags_NoTooltip.is_none() {
+            missing.push("TabItemFlags_NoTooltip");
+        }
+        if self.TabItemFlags_None.is_none() {
+            missing.push("TabItemFlags_None");
+        }
+        if self.TabItemFlags_SetSelected.is_none() {
+            missing.push("TabItemFlags_SetSelected");
+        }
+        if self.TabItemFlags_Trailing.is_none() {
+            missing.push("TabItemFlags_Trailing");
+        }
+        if self.TabItemFlags_UnsavedDocument.is_none() {
+            missing.push("TabItemFlags_UnsavedDocument");
+        }
+        if self.TableRowFlags_Headers.is_none() {
+            missing.push("TableRowFlags_Headers");
+        }
+        if self.TableRowFlags_None.is_none() {
+            missing.push("TableRowFlags_None");
+        }
+        if self.TableBgTarget_CellBg.is_none() {
+            missing.push("TableBgTarget_CellBg");
+        }
+        if self.TableBgTarget_None.is_none() {
+            missing.push("TableBgTarget_None");
+        }
+        if self.TableBgTarget_RowBg0.is_none() {
+            missing.push("TableBgTarget_RowBg0");
+        }
+        if self.TableBgTarget_RowBg1.is_none() {
+            missing.push("TableBgTarget_RowBg1");
+        }
+        if self.TableColumnFlags_None.is_none() {
+            missing.push("TableColumnFlags_None");
+        }
+        if self.TableColumnFlags_DefaultHide.is_none() {
+            missing.push("TableColumnFlags_DefaultHide");
+        }
+        if self.TableColumnFlags_DefaultSort.is_none() {
+            missing.push("TableColumnFlags_DefaultSort");
+        }
+        if self.TableColumnFlags_Disabled.is_none() {
+            missing.push("TableColumnFlags_Disabled");
+        }
+        if self.TableColumnFlags_IndentDisable.is_none() {
+            missing.push("TableColumnFlags_IndentDisable");
+        }
+        if self.TableColumnFlags_IndentEnable.is_none() {
+            missing.push("TableColumnFlags_IndentEnable");
+        }
+        if self.TableColumnFlags_NoClip.is_none() {
+            missing.push("TableColumnFlags_NoClip");
+        }
+        if self.TableColumnFlags_NoHeaderLabel.is_none() {
+            missing.push("TableColumnFlags_NoHeaderLabel");
+        }
+        if self.TableColumnFlags_NoHeaderWidth.is_none() {
+            missing.push("TableColumnFlags_NoHeaderWidth");
+        }
+        if self.TableColumnFlags_NoHide.is_none() {
+            missing.push("TableColumnFlags_NoHide");
+        }
+        if self.TableColumnFlags_NoReorder.is_none() {
+            missing.push("TableColumnFlags_NoReorder");
+        }
+        if self.TableColumnFlags_NoResize.is_none() {
+            missing.push("TableColumnFlags_NoResize");
+        }
+        if self.TableColumnFlags_NoSort.is_none() {
+            missing.push("TableColumnFlags_NoSort");
+        }
+        if self.TableColumnFlags_NoSortAscending.is_none() {
+            missing.push("TableColumnFlags_NoSortAscending");
+        }
+        if self.TableColumnFlags_NoSortDescending.is_none() {
+            missing.push("TableColumnFlags_NoSortDescending");
+        }
+        if self.TableColumnFlags_PreferSortAscending.is_none() {
+            missing.push("TableColumnFlags_PreferSortAscending");
+        }
+        if self.TableColumnFlags_PreferSortDescending.is_none() {
+            missing.push("TableColumnFlags_PreferSortDescending");
+        }
+        if self.TableColumnFlags_WidthFixed.is_none() {
+            missing.push("TableColumnFlags_WidthFixed");
+        }
+        if self.TableColumnFlags_WidthStretch.is_none() {
+            missing.push("TableColumnFlags_WidthStretch");
+        }
+        if self.TableColumnFlags_IsEnabled.is_none() {
+            missing.push("TableColumnFlags_IsEnabled");
+        }
+        if self.TableColumnFlags_IsHovered.is_none() {
+            missing.push("TableColumnFlags_IsHovered");
+        }
+        if self.TableColumnFlags_IsSorted.is_none() {
+            missing.push("TableColumnFlags_IsSorted");
+        }
+        if self.TableColumnFlags_IsVisible.is_none() {
+            missing.push("TableColumnFlags_IsVisible");
+        }
+        if self.SortDirection_Ascending.is_none() {
+            missing.push("SortDirection_Ascending");
+        }
+        if self.SortDirection_Descending.is_none() {
+            missing.push("SortDirection_Descending");
+        }
+        if self.SortDirection_None.is_none() {
+            missing.push("SortDirection_None");
+        }
+        if self.TableFlags_None.is_none() {
+            missing.push("TableFlags_None");
+        }
+        if self.TableFlags_NoClip.is_none() {
+            missing.push("TableFlags_NoClip");
+        }
+        if self.TableFlags_Borders.is_none() {
+            missing.push("TableFlags_Borders");
+        }
+        if self.TableFlags_BordersH.is_none() {
+            missing.push("TableFlags_BordersH");
+        }
+        if self.TableFlags_BordersInner.is_none() {
+            missing.push("TableFlags_BordersInner");
+        }
+        if self.TableFlags_BordersInnerH.is_none() {
+            missing.push("TableFlags_BordersInnerH");
+        }
+        if self.TableFlags_BordersInnerV.is_none() {
+            missing.push("TableFlags_BordersInnerV");
+        }
+        if self.TableFlags_BordersOuter.is_none() {
+            missing.push("TableFlags_BordersOuter");
+        }
+        if self.TableFlags_BordersOuterH.is_none() {
+            missing.push("TableFlags_BordersOuterH");
+        }
+        if self.TableFlags_BordersOuterV.is_none() {
+            missing.push("TableFlags_BordersOuterV");
+        }
+        if self.TableFlags_BordersV.is_none() {
+            missing.push("TableFlags_BordersV");
+        }
+        if self.TableFlags_RowBg.is_none() {
+            missing.push("TableFlags_RowBg");
+        }
+        if self.TableFlags_ContextMenuInBody.is_none() {
+            missing.push("TableFlags_ContextMenuInBody");
+        }
+        if self.TableFlags_Hideable.is_none() {
+            missing.push("TableFlags_Hideable");
+        }
+        if self.TableFlags_NoSavedSettings.is_none() {
+            missing.push("TableFlags_NoSavedSettings");
+        }
+        if self.TableFlags_Reorderable.is_none() {
+            missing.push("TableFlags_Reorderable");
+        }
+        if self.TableFlags_Resizable.is_none() {
+            missing.push("TableFlags_Resizable");
+        }
+        if self.TableFlags_Sortable.is_none() {
+            missing.push("TableFlags_Sortable");
+        }
+        if self.TableFlags_NoPadInnerX.is_none() {
+            missing.push("TableFlags_NoPadInnerX");
+        }
+        if self.TableFlags_NoPadOuterX.is_none() {
+            missing.push("TableFlags_NoPadOuterX");
+        }
+        if self.TableFlags_PadOuterX.is_none() {
+            missing.push("TableFlags_PadOuterX");
+        }
+        if self.TableFlags_ScrollX.is_none() {
+            missing.push("TableFlags_ScrollX");
+        }
+        if self.TableFlags_ScrollY.is_none() {
+            missing.push("TableFlags_ScrollY");
+        }
+        if self.TableFlags_NoHostExtendX.is_none() {
+            missing.push("TableFlags_NoHostExtendX");
+        }
+        if self.TableFlags_NoHostExtendY.is_none() {
+            missing.push("TableFlags_NoHostExtendY");
+        }
+        if self.TableFlags_NoKeepColumnsVisible.is_none() {
+            missing.push("TableFlags_NoKeepColumnsVisible");
+        }
+        if self.TableFlags_PreciseWidths.is_none() {
+            missing.push("TableFlags_PreciseWidths");
+        }
+        if self.TableFlags_SizingFixedFit.is_none() {
+            missing.push("TableFlags_SizingFixedFit");
+        }
+        if self.TableFlags_SizingFixedSame.is_none() {
+            missing.push("TableFlags_SizingFixedSame");
+        }
+        if self.TableFlags_SizingStretchProp.is_none() {
+            missing.push("TableFlags_SizingStretchProp");
+        }
+        if self.TableFlags_SizingStretchSame.is_none() {
+            missing.push("TableFlags_SizingStretchSame");
+        }
+        if self.TableFlags_SortMulti.is_none() {
+            missing.push("TableFlags_SortMulti");
+        }
+        if self.TableFlags_SortTristate.is_none() {
+            missing.push("TableFlags_SortTristate");
+        }
+        if self.InputTextFlags_AllowTabInput.is_none() {
+            missing.push("InputTextFlags_AllowTabInput");
+        }
+        if self.InputTextFlags_AlwaysOverwrite.is_none() {
+            missing.push("InputTextFlags_AlwaysOverwrite");
+        }
+        if self.InputTextFlags_AutoSelectAll.is_none() {
+            missing.push("InputTextFlags_AutoSelectAll");
+        }
+        if self.InputTextFlags_CharsDecimal.is_none() {
+            missing.push("InputTextFlags_CharsDecimal");
+        }
+        if self.InputTextFlags_CharsHexadecimal.is_none() {
+            missing.push("InputTextFlags_CharsHexadecimal");
+        }
+        if self.InputTextFlags_CharsNoBlank.is_none() {
+            missing.push("InputTextFlags_CharsNoBlank");
+        }
+        if self.InputTextFlags_CharsScientific.is_none() {
+            missing.push("InputTextFlags_CharsScientific");
+        }
+        if self.InputTextFlags_CharsUppercase.is_none() {
+            missing.push("InputTextFlags_CharsUppercase");
+        }
+        if self.InputTextFlags_CtrlEnterForNewLine.is_none() {
+            missing.push("InputTextFlags_CtrlEnterForNewLine");
+        }
+        if self.InputTextFlags_EnterReturnsTrue.is_none() {
+            missing.push("InputTextFlags_EnterReturnsTrue");
+        }
+        if self.InputTextFlags_EscapeClearsAll.is_none() {
+            missing.push("InputTextFlags_EscapeClearsAll");
+        }
+        if self.InputTextFlags_NoHorizontalScroll.is_none() {
+            missing.push("InputTextFlags_NoHorizontalScroll");
+        }
+        if self.InputTextFlags_NoUndoRedo.is_none() {
+            missing.push("InputTextFlags_NoUndoRedo");
+        }
+        if self.InputTextFlags_None.is_none() {
+            missing.push("InputTextFlags_None");
+        }
+        if self.InputTextFlags_Password.is_none() {
+            missing.push("InputTextFlags_Password");
+        }
+        if self.InputTextFlags_ReadOnly.is_none() {
+            missing.push("InputTextFlags_ReadOnly");
+        }
+        if self.TreeNodeFlags_AllowItemOverlap.is_none() {
+            missing.push("TreeNodeFlags_AllowItemOverlap");
+        }
+        if self.TreeNodeFlags_Bullet.is_none() {
+            missing.push("TreeNodeFlags_Bullet");
+        }
+        if self.TreeNodeFlags_CollapsingHeader.is_none() {
+            missing.push("TreeNodeFlags_CollapsingHeader");
+        }
+        if self.TreeNodeFlags_DefaultOpen.is_none() {
+            missing.push("TreeNodeFlags_DefaultOpen");
+        }
+        if self.TreeNodeFlags_FramePadding.is_none() {
+            missing.push("TreeNodeFlags_FramePadding");
+        }
+        if self.TreeNodeFlags_Framed.is_none() {
+            missing.push("TreeNodeFlags_Framed");
+        }
+        if self.TreeNodeFlags_Leaf.is_none() {
+            missing.push("TreeNodeFlags_Leaf");
+        }
+        if self.TreeNodeFlags_NoAutoOpenOnLog.is_none() {
+            missing.push("TreeNodeFlags_NoAutoOpenOnLog");
+        }
+        if self.TreeNodeFlags_NoTreePushOnOpen.is_none() {
+            missing.push("TreeNodeFlags_NoTreePushOnOpen");
+        }
+        if self.TreeNodeFlags_None.is_none() {
+            missing.push("TreeNodeFlags_None");
+        }
+        if self.TreeNodeFlags_OpenOnArrow.is_none() {
+            missing.push("TreeNodeFlags_OpenOnArrow");
+        }
+        if self.TreeNodeFlags_OpenOnDoubleClick.is_none() {
+            missing.push("TreeNodeFlags_OpenOnDoubleClick");
+        }
+        if self.TreeNodeFlags_Selected.is_none() {
+            missing.push("TreeNodeFlags_Selected");
+        }
+        if self.TreeNodeFlags_SpanAvailWidth.is_none() {
+            missing.push("TreeNodeFlags_SpanAvailWidth");
+        }
+        if self.TreeNodeFlags_SpanFullWidth.is_none() {
+            missing.push("TreeNodeFlags_SpanFullWidth");
+        }
+        if self.Cond_Always.is_none() {
+            missing.push("Cond_Always");
+        }
+        if self.Cond_Appearing.is_none() {
+            missing.push("Cond_Appearing");
+        }
+        if self.Cond_FirstUseEver.is_none() {
+            missing.push("Cond_FirstUseEver");
+        }
+        if self.Cond_Once.is_none() {
+            missing.push("Cond_Once");
+        }
+        if self.WindowFlags_AlwaysAutoResize.is_none() {
+            missing.push("WindowFlags_AlwaysAutoResize");
+        }
+        if self.WindowFlags_AlwaysHorizontalScrollbar.is_none() {
+            missing.push("WindowFlags_AlwaysHorizontalScrollbar");
+        }
+        if self.WindowFlags_AlwaysUseWindowPadding.is_none() {
+            missing.push("WindowFlags_AlwaysUseWindowPadding");
+        }
+        if self.WindowFlags_AlwaysVerticalScrollbar.is_none() {
+            missing.push("WindowFlags_AlwaysVerticalScrollbar");
+        }
+        if self.WindowFlags_HorizontalScrollbar.is_none() {
+            missing.push("WindowFlags_HorizontalScrollbar");
+        }
+        if self.WindowFlags_MenuBar.is_none() {
+            missing.push("WindowFlags_MenuBar");
+        }
+        if self.WindowFlags_NoBackground.is_none() {
+            missing.push("WindowFlags_NoBackground");
+        }
+        if self.WindowFlags_NoCollapse.is_none() {
+            missing.push("WindowFlags_NoCollapse");
+        }
+        if self.WindowFlags_NoDecoration.is_none() {
+            missing.push("WindowFlags_NoDecoration");
+        }
+        if self.WindowFlags_NoDocking.is_none() {
+            missing.push("WindowFlags_NoDocking");
+        }
+        if self.WindowFlags_NoFocusOnAppearing.is_none() {
+            missing.push("WindowFlags_NoFocusOnAppearing");
+        }
+        if self.WindowFlags_NoInputs.is_none() {
+            missing.push("WindowFlags_NoInputs");
+        }
+        if self.WindowFlags_NoMouseInputs.is_none() {
+            missing.push("WindowFlags_NoMouseInputs");
+        }
+        if self.WindowFlags_NoMove.is_none() {
+            missing.push("WindowFlags_NoMove");
+        }
+        if self.WindowFlags_NoNav.is_none() {
+            missing.push("WindowFlags_NoNav");
+        }
+        if self.WindowFlags_NoNavFocus.is_none() {
+            missing.push("WindowFlags_NoNavFocus");
+        }
+        if self.WindowFlags_NoNavInputs.is_none() {
+            missing.push("WindowFlags_NoNavInputs");
+        }
+        if self.WindowFlags_NoResize.is_none() {
+            missing.push("WindowFlags_NoResize");
+        }
+        if self.WindowFlags_NoSavedSettings.is_none() {
+            missing.push("WindowFlags_NoSavedSettings");
+        }
+        if self.WindowFlags_NoScrollWithMouse.is_none() {
+            missing.push("WindowFlags_NoScrollWithMouse");
+        }
+        if self.WindowFlags_NoScrollbar.is_none() {
+            missing.push("WindowFlags_NoScrollbar");
+        }
+        if self.WindowFlags_NoTitleBar.is_none() {
+            missing.push("WindowFlags_NoTitleBar");
+        }
+        if self.WindowFlags_None.is_none() {
+            missing.push("WindowFlags_None");
+        }
+        if self.WindowFlags_TopMost.is_none() {
+            missing.push("WindowFlags_TopMost");
+        }
+        if self.WindowFlags_UnsavedDocument.is_none() {
+            missing.push("WindowFlags_UnsavedDocument");
+        }
+        if self.FocusedFlags_AnyWindow.is_none() {
+            missing.push("FocusedFlags_AnyWindow");
+        }
+        if self.FocusedFlags_ChildWindows.is_none() {
+            missing.push("FocusedFlags_ChildWindows");
+        }
+        if self.FocusedFlags_DockHierarchy.is_none() {
+            missing.push("FocusedFlags_DockHierarchy");
+        }
+        if self.FocusedFlags_NoPopupHierarchy.is_none() {
+            missing.push("FocusedFlags_NoPopupHierarchy");
+        }
+        if self.FocusedFlags_None.is_none() {
+            missing.push("FocusedFlags_None");
+        }
+        if self.FocusedFlags_RootAndChildWindows.is_none() {
+            missing.push("FocusedFlags_RootAndChildWindows");
+        }
+        if self.FocusedFlags_RootWindow.is_none() {
+            missing.push("FocusedFlags_RootWindow");
+        }
+        missing
+    }
+    pub unsafe fn ArrowButton(
+        &self,
+        ctx: Context,
+        str_id: *const std::ffi::c_char,
+        dir: std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ArrowButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ArrowButton",
+            "ctx",
+        );
+        match self.pointers.ArrowButton {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ArrowButton)
+            ),
+            Some(f) => f(ctx, str_id, dir),
+        }
+    }
+    pub unsafe fn Button(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        size_wInOptional: *mut f64,
+        size_hInOptional: *mut f64,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Button));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Button",
+            "ctx",
+        );
+        match self.pointers.Button {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(Button)
+            ),
+            Some(f) => f(ctx, label, size_wInOptional, size_hInOptional),
+        }
+    }
+    pub unsafe fn Checkbox(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        vInOut: *mut bool,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Checkbox));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Checkbox",
+            "ctx",
+        );
+        match self.pointers.Checkbox {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(Checkbox)
+            ),
+            Some(f) => f(ctx, label, vInOut),
+        }
+    }
+    pub unsafe fn CheckboxFlags(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        flagsInOut: *mut std::ffi::c_int,
+        flags_value: std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CheckboxFlags));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "CheckboxFlags",
+            "ctx",
+        );
+        match self.pointers.CheckboxFlags {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(CheckboxFlags)
+            ),
+            Some(f) => f(ctx, label, flagsInOut, flags_value),
+        }
+    }
+    pub unsafe fn InvisibleButton(
+        &self,
+        ctx: Context,
+        str_id: *const std::ffi::c_char,
+        size_w: f64,
+        size_h: f64,
+        flagsInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InvisibleButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InvisibleButton",
+            "ctx",
+        );
+        match self.pointers.InvisibleButton {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(InvisibleButton)
+            ),
+            Some(f) => f(ctx, str_id, size_w, size_h, flagsInOptional),
+        }
+    }
+    pub unsafe fn PopButtonRepeat(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopButtonRepeat));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopButtonRepeat",
+            "ctx",
+        );
+        match self.pointers.PopButtonRepeat {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(PopButtonRepeat)
+            ),
+            Some(f) => f(ctx),
+        }
+    }
+    pub unsafe fn PushButtonRepeat(&self, ctx: Context, repeat: bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushButtonRepeat));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushButtonRepeat",
+            "ctx",
+        );
+        match self.pointers.PushButtonRepeat {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(PushButtonRepeat)
+            ),
+            Some(f) => f(ctx, repeat),
+        }
+    }
+    pub unsafe fn RadioButton(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        active: bool,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(RadioButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "RadioButton",
+            "ctx",
+        );
+        match self.pointers.RadioButton {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(RadioButton)
+            ),
+            Some(f) => f(ctx, label, active),
+        }
+    }
+    pub unsafe fn RadioButtonEx(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        vInOut: *mut std::ffi::c_int,
+        v_button: std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(RadioButtonEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "RadioButtonEx",
+            "ctx",
+        );
+        match self.pointers.RadioButtonEx {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(RadioButtonEx)
+            ),
+            Some(f) => f(ctx, label, vInOut, v_button),
+        }
+    }
+    pub unsafe fn SmallButton(&self, ctx: Context, label: *const std::ffi::c_char) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SmallButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SmallButton",
+            "ctx",
+        );
+        match self.pointers.SmallButton {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(SmallButton)
+            ),
+            Some(f) => f(ctx, label),
+        }
+    }
+    pub unsafe fn ColorButton(
+        &self,
+        ctx: Context,
+        desc_id: *const std::ffi::c_char,
+        col_rgba: std::ffi::c_int,
+        flagsInOptional: *mut std::ffi::c_int,
+        size_wInOptional: *mut f64,
+        size_hInOptional: *mut f64,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ColorButton",
+            "ctx",
+        );
+        match self.pointers.ColorButton {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ColorButton)
+            ),
+            Some(f) => f(
+                ctx,
+                desc_id,
+                col_rgba,
+                flagsInOptional,
+                size_wInOptional,
+                size_hInOptional,
+            ),
+        }
+    }
+    pub unsafe fn ColorEdit3(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        col_rgbInOut: *mut std::ffi::c_int,
+        flagsInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorEdit3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ColorEdit3",
+            "ctx",
+        );
+        match self.pointers.ColorEdit3 {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ColorEdit3)
+            ),
+            Some(f) => f(ctx, label, col_rgbInOut, flagsInOptional),
+        }
+    }
+    pub unsafe fn ColorEdit4(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        col_rgbaInOut: *mut std::ffi::c_int,
+        flagsInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorEdit4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ColorEdit4",
+            "ctx",
+        );
+        match self.pointers.ColorEdit4 {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ColorEdit4)
+            ),
+            Some(f) => f(ctx, label, col_rgbaInOut, flagsInOptional),
+        }
+    }
+    pub unsafe fn ColorPicker3(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        col_rgbInOut: *mut std::ffi::c_int,
+        flagsInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorPicker3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ColorPicker3",
+            "ctx",
+        );
+        match self.pointers.ColorPicker3 {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ColorPicker3)
+            ),
+            Some(f) => f(ctx, label, col_rgbInOut, flagsInOptional),
+        }
+    }
+    pub unsafe fn ColorPicker4(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        col_rgbaInOut: *mut std::ffi::c_int,
+        flagsInOptional: *mut std::ffi::c_int,
+        ref_colInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorPicker4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ColorPicker4",
+            "ctx",
+        );
+        match self.pointers.ColorPicker4 {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ColorPicker4)
+            ),
+            Some(f) => f(
+                ctx,
+                label,
+                col_rgbaInOut,
+                flagsInOptional,
+                ref_colInOptional,
+            ),
+        }
+    }
+    pub unsafe fn SetColorEditOptions(&self, ctx: Context, flags: std::ffi::c_int) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetColorEditOptions));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetColorEditOptions",
+            "ctx",
+        );
+        match self.pointers.SetColorEditOptions {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(SetColorEditOptions)
+            ),
+            Some(f) => f(ctx, flags),
+        }
+    }
+    pub unsafe fn BeginCombo(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        preview_value: *const std::ffi::c_char,
+        flagsInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginCombo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginCombo",
+            "ctx",
+        );
+        match self.pointers.BeginCombo {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(BeginCombo)
+            ),
+            Some(f) => f(ctx, label, preview_value, flagsInOptional),
+        }
+    }
+    pub unsafe fn Combo(
+        &self,
+        ctx: Context,
+        label: *const std::ffi::c_char,
+        current_itemInOut: *mut std::ffi::c_int,
+        items: *const std::ffi::c_char,
+        items_sz: std::ffi::c_int,
+        popup_max_height_in_itemsInOptional: *mut std::ffi::c_int,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Combo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Combo",
+            "ctx",
+        );
+        match self.pointers.Combo {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(Combo)
+            ),
+            Some(f) => f(
+                ctx,
+                label,
+                current_itemInOut,
+                items,
+                items_sz,
+                popup_max_height_in_itemsInOptional,
+            ),
+        }
+    }
     pub unsafe fn EndCombo(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndCombo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndCombo",
+            "ctx",
+        );
         match self.pointers.EndCombo {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10587,6 +13450,16 @@ impl ImGui {
         size_wInOptional: *mut f64,
         size_hInOptional: *mut f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginListBox));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginListBox",
+            "ctx",
+        );
         match self.pointers.BeginListBox {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10596,6 +13469,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndListBox(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndListBox));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndListBox",
+            "ctx",
+        );
         match self.pointers.EndListBox {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10613,6 +13496,16 @@ impl ImGui {
         items_sz: std::ffi::c_int,
         height_in_itemsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ListBox));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ListBox",
+            "ctx",
+        );
         match self.pointers.ListBox {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10637,6 +13530,16 @@ impl ImGui {
         size_wInOptional: *mut f64,
         size_hInOptional: *mut f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Selectable));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Selectable",
+            "ctx",
+        );
         match self.pointers.Selectable {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10653,6 +13556,24 @@ impl ImGui {
         }
     }
     pub unsafe fn Attach(&self, ctx: Context, obj: Resource) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Attach));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Attach",
+            "ctx",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            obj,
+            c_str_macro::c_str!("ImGui_Resource*"),
+            false,
+            "Attach",
+            "obj",
+        );
         match self.pointers.Attach {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10666,6 +13587,8 @@ impl ImGui {
         label: *const std::ffi::c_char,
         config_flagsInOptional: *mut std::ffi::c_int,
     ) -> Context {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateContext));
         match self.pointers.CreateContext {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10675,6 +13598,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DestroyContext(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DestroyContext));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DestroyContext",
+            "ctx",
+        );
         match self.pointers.DestroyContext {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10684,6 +13617,24 @@ impl ImGui {
         }
     }
     pub unsafe fn Detach(&self, ctx: Context, obj: Resource) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Detach));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Detach",
+            "ctx",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            obj,
+            c_str_macro::c_str!("ImGui_Resource*"),
+            false,
+            "Detach",
+            "obj",
+        );
         match self.pointers.Detach {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10693,6 +13644,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetDeltaTime(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetDeltaTime));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetDeltaTime",
+            "ctx",
+        );
         match self.pointers.GetDeltaTime {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10702,6 +13663,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetFrameCount(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetFrameCount));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetFrameCount",
+            "ctx",
+        );
         match self.pointers.GetFrameCount {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10711,6 +13682,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetFramerate(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetFramerate));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetFramerate",
+            "ctx",
+        );
         match self.pointers.GetFramerate {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10720,6 +13701,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetTime(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetTime));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetTime",
+            "ctx",
+        );
         match self.pointers.GetTime {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10729,6 +13720,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetConfigVar(&self, ctx: Context, var_idx: std::ffi::c_int) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetConfigVar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetConfigVar",
+            "ctx",
+        );
         match self.pointers.GetConfigVar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10738,6 +13739,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetConfigVar(&self, ctx: Context, var_idx: std::ffi::c_int, value: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetConfigVar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetConfigVar",
+            "ctx",
+        );
         match self.pointers.SetConfigVar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10754,6 +13765,16 @@ impl ImGui {
         payloadOutNeedBig_sz: std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(AcceptDragDropPayload));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "AcceptDragDropPayload",
+            "ctx",
+        );
         match self.pointers.AcceptDragDropPayload {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10774,6 +13795,16 @@ impl ImGui {
         countOut: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(AcceptDragDropPayloadFiles));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "AcceptDragDropPayloadFiles",
+            "ctx",
+        );
         match self.pointers.AcceptDragDropPayloadFiles {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10788,6 +13819,16 @@ impl ImGui {
         rgbOut: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(AcceptDragDropPayloadRGB));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "AcceptDragDropPayloadRGB",
+            "ctx",
+        );
         match self.pointers.AcceptDragDropPayloadRGB {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10802,6 +13843,16 @@ impl ImGui {
         rgbaOut: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(AcceptDragDropPayloadRGBA));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "AcceptDragDropPayloadRGBA",
+            "ctx",
+        );
         match self.pointers.AcceptDragDropPayloadRGBA {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10815,6 +13866,16 @@ impl ImGui {
         ctx: Context,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginDragDropSource));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginDragDropSource",
+            "ctx",
+        );
         match self.pointers.BeginDragDropSource {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10824,6 +13885,16 @@ impl ImGui {
         }
     }
     pub unsafe fn BeginDragDropTarget(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginDragDropTarget));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginDragDropTarget",
+            "ctx",
+        );
         match self.pointers.BeginDragDropTarget {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10833,6 +13904,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndDragDropSource(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndDragDropSource));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndDragDropSource",
+            "ctx",
+        );
         match self.pointers.EndDragDropSource {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10842,6 +13923,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndDragDropTarget(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndDragDropTarget));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndDragDropTarget",
+            "ctx",
+        );
         match self.pointers.EndDragDropTarget {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10860,6 +13951,16 @@ impl ImGui {
         is_previewOut: *mut bool,
         is_deliveryOut: *mut bool,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetDragDropPayload));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetDragDropPayload",
+            "ctx",
+        );
         match self.pointers.GetDragDropPayload {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10883,6 +13984,16 @@ impl ImGui {
         filenameOut: *mut std::ffi::c_char,
         filenameOut_sz: std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetDragDropPayloadFile));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetDragDropPayloadFile",
+            "ctx",
+        );
         match self.pointers.GetDragDropPayloadFile {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10898,6 +14009,16 @@ impl ImGui {
         data: *const std::ffi::c_char,
         condInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetDragDropPayload));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetDragDropPayload",
+            "ctx",
+        );
         match self.pointers.SetDragDropPayload {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10917,6 +14038,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragDouble));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragDouble",
+            "ctx",
+        );
         match self.pointers.DragDouble {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10946,6 +14077,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragDouble2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragDouble2",
+            "ctx",
+        );
         match self.pointers.DragDouble2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -10977,6 +14118,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragDouble3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragDouble3",
+            "ctx",
+        );
         match self.pointers.DragDouble3 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11010,6 +14161,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragDouble4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragDouble4",
+            "ctx",
+        );
         match self.pointers.DragDouble4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11043,6 +14204,16 @@ impl ImGui {
         format_maxInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragFloatRange2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragFloatRange2",
+            "ctx",
+        );
         match self.pointers.DragFloatRange2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11073,6 +14244,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragInt));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragInt",
+            "ctx",
+        );
         match self.pointers.DragInt {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11102,6 +14283,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragInt2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragInt2",
+            "ctx",
+        );
         match self.pointers.DragInt2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11133,6 +14324,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragInt3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragInt3",
+            "ctx",
+        );
         match self.pointers.DragInt3 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11166,6 +14367,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragInt4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragInt4",
+            "ctx",
+        );
         match self.pointers.DragInt4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11199,6 +14410,16 @@ impl ImGui {
         format_maxInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DragIntRange2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DragIntRange2",
+            "ctx",
+        );
         match self.pointers.DragIntRange2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11228,6 +14449,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderAngle));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderAngle",
+            "ctx",
+        );
         match self.pointers.SliderAngle {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11254,6 +14485,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderDouble));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderDouble",
+            "ctx",
+        );
         match self.pointers.SliderDouble {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11281,6 +14522,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderDouble2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderDouble2",
+            "ctx",
+        );
         match self.pointers.SliderDouble2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11310,6 +14561,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderDouble3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderDouble3",
+            "ctx",
+        );
         match self.pointers.SliderDouble3 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11341,6 +14602,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderDouble4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderDouble4",
+            "ctx",
+        );
         match self.pointers.SliderDouble4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11370,6 +14641,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderInt));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderInt",
+            "ctx",
+        );
         match self.pointers.SliderInt {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11397,6 +14678,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderInt2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderInt2",
+            "ctx",
+        );
         match self.pointers.SliderInt2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11426,6 +14717,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderInt3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderInt3",
+            "ctx",
+        );
         match self.pointers.SliderInt3 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11457,6 +14758,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SliderInt4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SliderInt4",
+            "ctx",
+        );
         match self.pointers.SliderInt4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11488,6 +14799,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(VSliderDouble));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "VSliderDouble",
+            "ctx",
+        );
         match self.pointers.VSliderDouble {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11518,6 +14839,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(VSliderInt));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "VSliderInt",
+            "ctx",
+        );
         match self.pointers.VSliderInt {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11537,6 +14868,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DrawList_PopClipRect(&self, draw_list: DrawList) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PopClipRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PopClipRect",
+            "draw_list",
+        );
         match self.pointers.DrawList_PopClipRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11554,6 +14895,16 @@ impl ImGui {
         clip_rect_max_y: f64,
         intersect_with_current_clip_rectInOptional: *mut bool,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PushClipRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PushClipRect",
+            "draw_list",
+        );
         match self.pointers.DrawList_PushClipRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11570,6 +14921,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DrawList_PushClipRectFullScreen(&self, draw_list: DrawList) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PushClipRectFullScreen));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PushClipRectFullScreen",
+            "draw_list",
+        );
         match self.pointers.DrawList_PushClipRectFullScreen {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11579,6 +14940,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetBackgroundDrawList(&self, ctx: Context) -> DrawList {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetBackgroundDrawList));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetBackgroundDrawList",
+            "ctx",
+        );
         match self.pointers.GetBackgroundDrawList {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11588,6 +14959,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetForegroundDrawList(&self, ctx: Context) -> DrawList {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetForegroundDrawList));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetForegroundDrawList",
+            "ctx",
+        );
         match self.pointers.GetForegroundDrawList {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11597,6 +14978,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowDrawList(&self, ctx: Context) -> DrawList {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowDrawList));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowDrawList",
+            "ctx",
+        );
         match self.pointers.GetWindowDrawList {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11620,6 +15011,16 @@ impl ImGui {
         thickness: f64,
         num_segmentsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddBezierCubic));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddBezierCubic",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddBezierCubic {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11654,6 +15055,16 @@ impl ImGui {
         thickness: f64,
         num_segmentsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddBezierQuadratic));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddBezierQuadratic",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddBezierQuadratic {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11683,6 +15094,16 @@ impl ImGui {
         num_segmentsInOptional: *mut std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddCircle));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddCircle",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddCircle {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11708,6 +15129,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         num_segmentsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddCircleFilled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddCircleFilled",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddCircleFilled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11737,6 +15168,24 @@ impl ImGui {
         uv_max_yInOptional: *mut f64,
         col_rgbaInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddImage));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddImage",
+            "draw_list",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "DrawList_AddImage",
+            "img",
+        );
         match self.pointers.DrawList_AddImage {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11779,6 +15228,24 @@ impl ImGui {
         uv4_yInOptional: *mut f64,
         col_rgbaInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddImageQuad));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddImageQuad",
+            "draw_list",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "DrawList_AddImageQuad",
+            "img",
+        );
         match self.pointers.DrawList_AddImageQuad {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11823,6 +15290,24 @@ impl ImGui {
         rounding: f64,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddImageRounded));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddImageRounded",
+            "draw_list",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "DrawList_AddImageRounded",
+            "img",
+        );
         match self.pointers.DrawList_AddImageRounded {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11855,6 +15340,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddLine));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddLine",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddLine {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11881,6 +15376,16 @@ impl ImGui {
         num_segments: std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddNgon));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddNgon",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddNgon {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11906,6 +15411,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         num_segments: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddNgonFilled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddNgonFilled",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddNgonFilled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11935,6 +15450,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddQuad));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddQuad",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddQuad {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11968,6 +15493,16 @@ impl ImGui {
         p4_y: f64,
         col_rgba: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddQuadFilled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddQuadFilled",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddQuadFilled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -11990,6 +15525,16 @@ impl ImGui {
         flagsInOptional: *mut std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddRect",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12019,6 +15564,16 @@ impl ImGui {
         roundingInOptional: *mut f64,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddRectFilled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddRectFilled",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddRectFilled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12048,6 +15603,16 @@ impl ImGui {
         col_bot_right: std::ffi::c_int,
         col_bot_left: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddRectFilledMultiColor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddRectFilledMultiColor",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddRectFilledMultiColor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12074,6 +15639,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         text: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddText));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddText",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddText {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12097,6 +15672,24 @@ impl ImGui {
         cpu_fine_clip_rect_wInOptional: *mut f64,
         cpu_fine_clip_rect_hInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddTextEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddTextEx",
+            "draw_list",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            font,
+            c_str_macro::c_str!("ImGui_Font*"),
+            true,
+            "DrawList_AddTextEx",
+            "font",
+        );
         match self.pointers.DrawList_AddTextEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12130,6 +15723,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddTriangle));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddTriangle",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddTriangle {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12159,6 +15762,16 @@ impl ImGui {
         p3_y: f64,
         col_rgba: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_AddTriangleFilled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_AddTriangleFilled",
+            "draw_list",
+        );
         match self.pointers.DrawList_AddTriangleFilled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12168,6 +15781,16 @@ impl ImGui {
         }
     }
     pub unsafe fn CreateDrawListSplitter(&self, draw_list: DrawList) -> DrawListSplitter {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateDrawListSplitter));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "CreateDrawListSplitter",
+            "draw_list",
+        );
         match self.pointers.CreateDrawListSplitter {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12177,6 +15800,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DrawListSplitter_Clear(&self, splitter: DrawListSplitter) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawListSplitter_Clear));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            splitter,
+            c_str_macro::c_str!("ImGui_DrawListSplitter*"),
+            false,
+            "DrawListSplitter_Clear",
+            "splitter",
+        );
         match self.pointers.DrawListSplitter_Clear {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12186,6 +15819,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DrawListSplitter_Merge(&self, splitter: DrawListSplitter) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawListSplitter_Merge));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            splitter,
+            c_str_macro::c_str!("ImGui_DrawListSplitter*"),
+            false,
+            "DrawListSplitter_Merge",
+            "splitter",
+        );
         match self.pointers.DrawListSplitter_Merge {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12199,6 +15842,16 @@ impl ImGui {
         splitter: DrawListSplitter,
         channel_idx: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawListSplitter_SetCurrentChannel));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            splitter,
+            c_str_macro::c_str!("ImGui_DrawListSplitter*"),
+            false,
+            "DrawListSplitter_SetCurrentChannel",
+            "splitter",
+        );
         match self.pointers.DrawListSplitter_SetCurrentChannel {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12212,6 +15865,16 @@ impl ImGui {
         splitter: DrawListSplitter,
         count: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawListSplitter_Split));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            splitter,
+            c_str_macro::c_str!("ImGui_DrawListSplitter*"),
+            false,
+            "DrawListSplitter_Split",
+            "splitter",
+        );
         match self.pointers.DrawListSplitter_Split {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12230,6 +15893,16 @@ impl ImGui {
         a_max: f64,
         num_segmentsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathArcTo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathArcTo",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathArcTo {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12255,6 +15928,16 @@ impl ImGui {
         a_min_of_12: std::ffi::c_int,
         a_max_of_12: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathArcToFast));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathArcToFast",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathArcToFast {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12281,6 +15964,16 @@ impl ImGui {
         p4_y: f64,
         num_segmentsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathBezierCubicCurveTo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathBezierCubicCurveTo",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathBezierCubicCurveTo {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12307,6 +16000,16 @@ impl ImGui {
         p3_y: f64,
         num_segmentsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathBezierQuadraticCurveTo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathBezierQuadraticCurveTo",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathBezierQuadraticCurveTo {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12316,6 +16019,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DrawList_PathClear(&self, draw_list: DrawList) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathClear));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathClear",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathClear {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12329,6 +16042,16 @@ impl ImGui {
         draw_list: DrawList,
         col_rgba: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathFillConvex));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathFillConvex",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathFillConvex {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12338,6 +16061,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DrawList_PathLineTo(&self, draw_list: DrawList, pos_x: f64, pos_y: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathLineTo));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathLineTo",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathLineTo {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12356,6 +16089,16 @@ impl ImGui {
         roundingInOptional: *mut f64,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathRect",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12379,6 +16122,16 @@ impl ImGui {
         flagsInOptional: *mut std::ffi::c_int,
         thicknessInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DrawList_PathStroke));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            draw_list,
+            c_str_macro::c_str!("ImGui_DrawList*"),
+            false,
+            "DrawList_PathStroke",
+            "draw_list",
+        );
         match self.pointers.DrawList_PathStroke {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12393,6 +16146,8 @@ impl ImGui {
         size: std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> Font {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateFont));
         match self.pointers.CreateFont {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12402,6 +16157,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetFont(&self, ctx: Context) -> Font {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetFont));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetFont",
+            "ctx",
+        );
         match self.pointers.GetFont {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12411,6 +16176,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetFontSize(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetFontSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetFontSize",
+            "ctx",
+        );
         match self.pointers.GetFontSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12420,6 +16195,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopFont(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopFont));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopFont",
+            "ctx",
+        );
         match self.pointers.PopFont {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12429,6 +16214,24 @@ impl ImGui {
         }
     }
     pub unsafe fn PushFont(&self, ctx: Context, font: Font) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushFont));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushFont",
+            "ctx",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            font,
+            c_str_macro::c_str!("ImGui_Font*"),
+            true,
+            "PushFont",
+            "font",
+        );
         match self.pointers.PushFont {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12442,6 +16245,8 @@ impl ImGui {
         file: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> Image {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateImage));
         match self.pointers.CreateImage {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12455,6 +16260,8 @@ impl ImGui {
         data: *const std::ffi::c_char,
         data_sz: std::ffi::c_int,
     ) -> Image {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateImageFromMem));
         match self.pointers.CreateImageFromMem {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12476,6 +16283,24 @@ impl ImGui {
         tint_col_rgbaInOptional: *mut std::ffi::c_int,
         border_col_rgbaInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Image));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Image",
+            "ctx",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "Image",
+            "img",
+        );
         match self.pointers.Image {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12509,6 +16334,24 @@ impl ImGui {
         bg_col_rgbaInOptional: *mut std::ffi::c_int,
         tint_col_rgbaInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ImageButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ImageButton",
+            "ctx",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "ImageButton",
+            "img",
+        );
         match self.pointers.ImageButton {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12530,6 +16373,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Image_GetSize(&self, img: Image, wOut: *mut f64, hOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Image_GetSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "Image_GetSize",
+            "img",
+        );
         match self.pointers.Image_GetSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12539,6 +16392,24 @@ impl ImGui {
         }
     }
     pub unsafe fn ImageSet_Add(&self, set: ImageSet, scale: f64, img: Image) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ImageSet_Add));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            set,
+            c_str_macro::c_str!("ImGui_ImageSet*"),
+            false,
+            "ImageSet_Add",
+            "set",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            img,
+            c_str_macro::c_str!("ImGui_Image*"),
+            false,
+            "ImageSet_Add",
+            "img",
+        );
         match self.pointers.ImageSet_Add {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12548,6 +16419,16 @@ impl ImGui {
         }
     }
     pub unsafe fn BeginDisabled(&self, ctx: Context, disabledInOptional: *mut bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginDisabled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginDisabled",
+            "ctx",
+        );
         match self.pointers.BeginDisabled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12557,6 +16438,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndDisabled(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndDisabled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndDisabled",
+            "ctx",
+        );
         match self.pointers.EndDisabled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12566,6 +16457,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetItemAllowOverlap(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetItemAllowOverlap));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetItemAllowOverlap",
+            "ctx",
+        );
         match self.pointers.SetItemAllowOverlap {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12575,6 +16476,16 @@ impl ImGui {
         }
     }
     pub unsafe fn CalcItemWidth(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CalcItemWidth));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "CalcItemWidth",
+            "ctx",
+        );
         match self.pointers.CalcItemWidth {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12584,6 +16495,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetItemRectMax(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetItemRectMax));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetItemRectMax",
+            "ctx",
+        );
         match self.pointers.GetItemRectMax {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12593,6 +16514,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetItemRectMin(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetItemRectMin));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetItemRectMin",
+            "ctx",
+        );
         match self.pointers.GetItemRectMin {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12602,6 +16533,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetItemRectSize(&self, ctx: Context, wOut: *mut f64, hOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetItemRectSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetItemRectSize",
+            "ctx",
+        );
         match self.pointers.GetItemRectSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12611,6 +16552,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopItemWidth(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopItemWidth));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopItemWidth",
+            "ctx",
+        );
         match self.pointers.PopItemWidth {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12620,6 +16571,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PushItemWidth(&self, ctx: Context, item_width: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushItemWidth));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushItemWidth",
+            "ctx",
+        );
         match self.pointers.PushItemWidth {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12629,6 +16590,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetNextItemWidth(&self, ctx: Context, item_width: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextItemWidth));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextItemWidth",
+            "ctx",
+        );
         match self.pointers.SetNextItemWidth {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12638,6 +16609,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopAllowKeyboardFocus(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopAllowKeyboardFocus));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopAllowKeyboardFocus",
+            "ctx",
+        );
         match self.pointers.PopAllowKeyboardFocus {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12647,6 +16628,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PushAllowKeyboardFocus(&self, ctx: Context, allow_keyboard_focus: bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushAllowKeyboardFocus));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushAllowKeyboardFocus",
+            "ctx",
+        );
         match self.pointers.PushAllowKeyboardFocus {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12656,6 +16647,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetItemDefaultFocus(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetItemDefaultFocus));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetItemDefaultFocus",
+            "ctx",
+        );
         match self.pointers.SetItemDefaultFocus {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12669,6 +16670,16 @@ impl ImGui {
         ctx: Context,
         offsetInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetKeyboardFocusHere));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetKeyboardFocusHere",
+            "ctx",
+        );
         match self.pointers.SetKeyboardFocusHere {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12678,6 +16689,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsAnyItemActive(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsAnyItemActive));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsAnyItemActive",
+            "ctx",
+        );
         match self.pointers.IsAnyItemActive {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12687,6 +16708,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsAnyItemFocused(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsAnyItemFocused));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsAnyItemFocused",
+            "ctx",
+        );
         match self.pointers.IsAnyItemFocused {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12696,6 +16727,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsAnyItemHovered(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsAnyItemHovered));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsAnyItemHovered",
+            "ctx",
+        );
         match self.pointers.IsAnyItemHovered {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12705,6 +16746,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemActivated(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemActivated));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemActivated",
+            "ctx",
+        );
         match self.pointers.IsItemActivated {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12714,6 +16765,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemActive(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemActive));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemActive",
+            "ctx",
+        );
         match self.pointers.IsItemActive {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12727,6 +16788,16 @@ impl ImGui {
         ctx: Context,
         mouse_buttonInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemClicked));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemClicked",
+            "ctx",
+        );
         match self.pointers.IsItemClicked {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12736,6 +16807,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemDeactivated(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemDeactivated));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemDeactivated",
+            "ctx",
+        );
         match self.pointers.IsItemDeactivated {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12745,6 +16826,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemDeactivatedAfterEdit(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemDeactivatedAfterEdit));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemDeactivatedAfterEdit",
+            "ctx",
+        );
         match self.pointers.IsItemDeactivatedAfterEdit {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12754,6 +16845,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemEdited(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemEdited));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemEdited",
+            "ctx",
+        );
         match self.pointers.IsItemEdited {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12763,6 +16864,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemFocused(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemFocused));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemFocused",
+            "ctx",
+        );
         match self.pointers.IsItemFocused {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12776,6 +16887,16 @@ impl ImGui {
         ctx: Context,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemHovered));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemHovered",
+            "ctx",
+        );
         match self.pointers.IsItemHovered {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12785,6 +16906,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemVisible(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemVisible));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemVisible",
+            "ctx",
+        );
         match self.pointers.IsItemVisible {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12799,6 +16930,16 @@ impl ImGui {
         idx: std::ffi::c_int,
         unicode_charOut: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetInputQueueCharacter));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetInputQueueCharacter",
+            "ctx",
+        );
         match self.pointers.GetInputQueueCharacter {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12808,6 +16949,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetKeyDownDuration(&self, ctx: Context, key: std::ffi::c_int) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetKeyDownDuration));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetKeyDownDuration",
+            "ctx",
+        );
         match self.pointers.GetKeyDownDuration {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12817,6 +16968,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetKeyMods(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetKeyMods));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetKeyMods",
+            "ctx",
+        );
         match self.pointers.GetKeyMods {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12832,6 +16993,16 @@ impl ImGui {
         repeat_delay: f64,
         rate: f64,
     ) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetKeyPressedAmount));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetKeyPressedAmount",
+            "ctx",
+        );
         match self.pointers.GetKeyPressedAmount {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12841,6 +17012,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsKeyDown(&self, ctx: Context, key: std::ffi::c_int) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsKeyDown));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsKeyDown",
+            "ctx",
+        );
         match self.pointers.IsKeyDown {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12855,6 +17036,16 @@ impl ImGui {
         key: std::ffi::c_int,
         repeatInOptional: *mut bool,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsKeyPressed));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsKeyPressed",
+            "ctx",
+        );
         match self.pointers.IsKeyPressed {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12864,6 +17055,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsKeyReleased(&self, ctx: Context, key: std::ffi::c_int) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsKeyReleased));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsKeyReleased",
+            "ctx",
+        );
         match self.pointers.IsKeyReleased {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12877,6 +17078,16 @@ impl ImGui {
         ctx: Context,
         want_capture_keyboard: bool,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextFrameWantCaptureKeyboard));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextFrameWantCaptureKeyboard",
+            "ctx",
+        );
         match self.pointers.SetNextFrameWantCaptureKeyboard {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12890,6 +17101,16 @@ impl ImGui {
         ctx: Context,
         button: std::ffi::c_int,
     ) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseClickedCount));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseClickedCount",
+            "ctx",
+        );
         match self.pointers.GetMouseClickedCount {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12905,6 +17126,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseClickedPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseClickedPos",
+            "ctx",
+        );
         match self.pointers.GetMouseClickedPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12914,6 +17145,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetMouseCursor(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseCursor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseCursor",
+            "ctx",
+        );
         match self.pointers.GetMouseCursor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12923,6 +17164,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetMouseDelta(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseDelta));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseDelta",
+            "ctx",
+        );
         match self.pointers.GetMouseDelta {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12932,6 +17183,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetMouseDownDuration(&self, ctx: Context, button: std::ffi::c_int) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseDownDuration));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseDownDuration",
+            "ctx",
+        );
         match self.pointers.GetMouseDownDuration {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12948,6 +17209,16 @@ impl ImGui {
         buttonInOptional: *mut std::ffi::c_int,
         lock_thresholdInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseDragDelta));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseDragDelta",
+            "ctx",
+        );
         match self.pointers.GetMouseDragDelta {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12957,6 +17228,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetMousePos(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMousePos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMousePos",
+            "ctx",
+        );
         match self.pointers.GetMousePos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12971,6 +17252,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMousePosOnOpeningCurrentPopup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMousePosOnOpeningCurrentPopup",
+            "ctx",
+        );
         match self.pointers.GetMousePosOnOpeningCurrentPopup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12985,6 +17276,16 @@ impl ImGui {
         verticalOut: *mut f64,
         horizontalOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMouseWheel));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMouseWheel",
+            "ctx",
+        );
         match self.pointers.GetMouseWheel {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -12994,6 +17295,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsAnyMouseDown(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsAnyMouseDown));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsAnyMouseDown",
+            "ctx",
+        );
         match self.pointers.IsAnyMouseDown {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13008,6 +17319,16 @@ impl ImGui {
         button: std::ffi::c_int,
         repeatInOptional: *mut bool,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMouseClicked));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMouseClicked",
+            "ctx",
+        );
         match self.pointers.IsMouseClicked {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13017,6 +17338,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsMouseDoubleClicked(&self, ctx: Context, button: std::ffi::c_int) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMouseDoubleClicked));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMouseDoubleClicked",
+            "ctx",
+        );
         match self.pointers.IsMouseDoubleClicked {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13026,6 +17357,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsMouseDown(&self, ctx: Context, button: std::ffi::c_int) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMouseDown));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMouseDown",
+            "ctx",
+        );
         match self.pointers.IsMouseDown {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13040,6 +17381,16 @@ impl ImGui {
         button: std::ffi::c_int,
         lock_thresholdInOptional: *mut f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMouseDragging));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMouseDragging",
+            "ctx",
+        );
         match self.pointers.IsMouseDragging {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13057,6 +17408,16 @@ impl ImGui {
         r_max_y: f64,
         clipInOptional: *mut bool,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMouseHoveringRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMouseHoveringRect",
+            "ctx",
+        );
         match self.pointers.IsMouseHoveringRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13071,6 +17432,16 @@ impl ImGui {
         mouse_pos_xInOptional: *mut f64,
         mouse_pos_yInOptional: *mut f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMousePosValid));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMousePosValid",
+            "ctx",
+        );
         match self.pointers.IsMousePosValid {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13080,6 +17451,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsMouseReleased(&self, ctx: Context, button: std::ffi::c_int) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsMouseReleased));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsMouseReleased",
+            "ctx",
+        );
         match self.pointers.IsMouseReleased {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13093,6 +17474,16 @@ impl ImGui {
         ctx: Context,
         buttonInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ResetMouseDragDelta));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ResetMouseDragDelta",
+            "ctx",
+        );
         match self.pointers.ResetMouseDragDelta {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13102,6 +17493,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetMouseCursor(&self, ctx: Context, cursor_type_: std::ffi::c_int) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetMouseCursor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetMouseCursor",
+            "ctx",
+        );
         match self.pointers.SetMouseCursor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13111,6 +17512,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Separator(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Separator));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Separator",
+            "ctx",
+        );
         match self.pointers.Separator {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13120,6 +17531,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsRectVisible(&self, ctx: Context, size_w: f64, size_h: f64) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsRectVisible));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsRectVisible",
+            "ctx",
+        );
         match self.pointers.IsRectVisible {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13136,6 +17557,16 @@ impl ImGui {
         rect_max_x: f64,
         rect_max_y: f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsRectVisibleEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsRectVisibleEx",
+            "ctx",
+        );
         match self.pointers.IsRectVisibleEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13145,6 +17576,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopClipRect(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopClipRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopClipRect",
+            "ctx",
+        );
         match self.pointers.PopClipRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13162,6 +17603,16 @@ impl ImGui {
         clip_rect_max_y: f64,
         intersect_with_current_clip_rect: bool,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushClipRect));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushClipRect",
+            "ctx",
+        );
         match self.pointers.PushClipRect {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13178,6 +17629,16 @@ impl ImGui {
         }
     }
     pub unsafe fn BeginGroup(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginGroup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginGroup",
+            "ctx",
+        );
         match self.pointers.BeginGroup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13187,6 +17648,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Dummy(&self, ctx: Context, size_w: f64, size_h: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Dummy));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Dummy",
+            "ctx",
+        );
         match self.pointers.Dummy {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13196,6 +17667,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndGroup(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndGroup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndGroup",
+            "ctx",
+        );
         match self.pointers.EndGroup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13205,6 +17686,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetCursorPos(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetCursorPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetCursorPos",
+            "ctx",
+        );
         match self.pointers.GetCursorPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13214,6 +17705,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetCursorPosX(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetCursorPosX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetCursorPosX",
+            "ctx",
+        );
         match self.pointers.GetCursorPosX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13223,6 +17724,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetCursorPosY(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetCursorPosY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetCursorPosY",
+            "ctx",
+        );
         match self.pointers.GetCursorPosY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13232,6 +17743,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetCursorScreenPos(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetCursorScreenPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetCursorScreenPos",
+            "ctx",
+        );
         match self.pointers.GetCursorScreenPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13241,6 +17762,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetCursorStartPos(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetCursorStartPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetCursorStartPos",
+            "ctx",
+        );
         match self.pointers.GetCursorStartPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13250,6 +17781,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Indent(&self, ctx: Context, indent_wInOptional: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Indent));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Indent",
+            "ctx",
+        );
         match self.pointers.Indent {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13259,6 +17800,16 @@ impl ImGui {
         }
     }
     pub unsafe fn NewLine(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(NewLine));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "NewLine",
+            "ctx",
+        );
         match self.pointers.NewLine {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13273,6 +17824,16 @@ impl ImGui {
         offset_from_start_xInOptional: *mut f64,
         spacingInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SameLine));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SameLine",
+            "ctx",
+        );
         match self.pointers.SameLine {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13282,6 +17843,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetCursorPos(&self, ctx: Context, local_pos_x: f64, local_pos_y: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetCursorPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetCursorPos",
+            "ctx",
+        );
         match self.pointers.SetCursorPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13291,6 +17862,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetCursorPosX(&self, ctx: Context, local_x: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetCursorPosX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetCursorPosX",
+            "ctx",
+        );
         match self.pointers.SetCursorPosX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13300,6 +17881,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetCursorPosY(&self, ctx: Context, local_y: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetCursorPosY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetCursorPosY",
+            "ctx",
+        );
         match self.pointers.SetCursorPosY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13309,6 +17900,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetCursorScreenPos(&self, ctx: Context, pos_x: f64, pos_y: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetCursorScreenPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetCursorScreenPos",
+            "ctx",
+        );
         match self.pointers.SetCursorScreenPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13318,6 +17919,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Spacing(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Spacing));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Spacing",
+            "ctx",
+        );
         match self.pointers.Spacing {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13327,6 +17938,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Unindent(&self, ctx: Context, indent_wInOptional: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Unindent));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Unindent",
+            "ctx",
+        );
         match self.pointers.Unindent {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13336,6 +17957,16 @@ impl ImGui {
         }
     }
     pub unsafe fn CreateListClipper(&self, ctx: Context) -> ListClipper {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateListClipper));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "CreateListClipper",
+            "ctx",
+        );
         match self.pointers.CreateListClipper {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13350,6 +17981,16 @@ impl ImGui {
         items_count: std::ffi::c_int,
         items_heightInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ListClipper_Begin));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            clipper,
+            c_str_macro::c_str!("ImGui_ListClipper*"),
+            false,
+            "ListClipper_Begin",
+            "clipper",
+        );
         match self.pointers.ListClipper_Begin {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13359,6 +18000,16 @@ impl ImGui {
         }
     }
     pub unsafe fn ListClipper_End(&self, clipper: ListClipper) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ListClipper_End));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            clipper,
+            c_str_macro::c_str!("ImGui_ListClipper*"),
+            false,
+            "ListClipper_End",
+            "clipper",
+        );
         match self.pointers.ListClipper_End {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13373,6 +18024,16 @@ impl ImGui {
         item_min: std::ffi::c_int,
         item_max: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ListClipper_ForceDisplayRangeByIndices));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            clipper,
+            c_str_macro::c_str!("ImGui_ListClipper*"),
+            false,
+            "ListClipper_ForceDisplayRangeByIndices",
+            "clipper",
+        );
         match self.pointers.ListClipper_ForceDisplayRangeByIndices {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13387,6 +18048,16 @@ impl ImGui {
         display_startOut: *mut std::ffi::c_int,
         display_endOut: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ListClipper_GetDisplayRange));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            clipper,
+            c_str_macro::c_str!("ImGui_ListClipper*"),
+            false,
+            "ListClipper_GetDisplayRange",
+            "clipper",
+        );
         match self.pointers.ListClipper_GetDisplayRange {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13396,6 +18067,16 @@ impl ImGui {
         }
     }
     pub unsafe fn ListClipper_Step(&self, clipper: ListClipper) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ListClipper_Step));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            clipper,
+            c_str_macro::c_str!("ImGui_ListClipper*"),
+            false,
+            "ListClipper_Step",
+            "clipper",
+        );
         match self.pointers.ListClipper_Step {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13410,6 +18091,16 @@ impl ImGui {
         label: *const std::ffi::c_char,
         enabledInOptional: *mut bool,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginMenu));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginMenu",
+            "ctx",
+        );
         match self.pointers.BeginMenu {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13419,6 +18110,16 @@ impl ImGui {
         }
     }
     pub unsafe fn BeginMenuBar(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginMenuBar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginMenuBar",
+            "ctx",
+        );
         match self.pointers.BeginMenuBar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13428,6 +18129,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndMenu(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndMenu));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndMenu",
+            "ctx",
+        );
         match self.pointers.EndMenu {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13437,6 +18148,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndMenuBar(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndMenuBar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndMenuBar",
+            "ctx",
+        );
         match self.pointers.EndMenuBar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13453,6 +18174,16 @@ impl ImGui {
         p_selectedInOutOptional: *mut bool,
         enabledInOptional: *mut bool,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(MenuItem));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "MenuItem",
+            "ctx",
+        );
         match self.pointers.MenuItem {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13473,6 +18204,16 @@ impl ImGui {
         str_id: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginPopup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginPopup",
+            "ctx",
+        );
         match self.pointers.BeginPopup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13488,6 +18229,16 @@ impl ImGui {
         p_openInOutOptional: *mut bool,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginPopupModal));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginPopupModal",
+            "ctx",
+        );
         match self.pointers.BeginPopupModal {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13497,6 +18248,16 @@ impl ImGui {
         }
     }
     pub unsafe fn CloseCurrentPopup(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CloseCurrentPopup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "CloseCurrentPopup",
+            "ctx",
+        );
         match self.pointers.CloseCurrentPopup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13506,6 +18267,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndPopup(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndPopup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndPopup",
+            "ctx",
+        );
         match self.pointers.EndPopup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13520,6 +18291,16 @@ impl ImGui {
         str_id: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsPopupOpen));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsPopupOpen",
+            "ctx",
+        );
         match self.pointers.IsPopupOpen {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13534,6 +18315,16 @@ impl ImGui {
         str_id: *const std::ffi::c_char,
         popup_flagsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(OpenPopup));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "OpenPopup",
+            "ctx",
+        );
         match self.pointers.OpenPopup {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13548,6 +18339,16 @@ impl ImGui {
         str_idInOptional: *const std::ffi::c_char,
         popup_flagsInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(OpenPopupOnItemClick));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "OpenPopupOnItemClick",
+            "ctx",
+        );
         match self.pointers.OpenPopupOnItemClick {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13562,6 +18363,16 @@ impl ImGui {
         str_idInOptional: *const std::ffi::c_char,
         popup_flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginPopupContextItem));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginPopupContextItem",
+            "ctx",
+        );
         match self.pointers.BeginPopupContextItem {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13576,6 +18387,16 @@ impl ImGui {
         str_idInOptional: *const std::ffi::c_char,
         popup_flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginPopupContextWindow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginPopupContextWindow",
+            "ctx",
+        );
         match self.pointers.BeginPopupContextWindow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13585,6 +18406,16 @@ impl ImGui {
         }
     }
     pub unsafe fn BeginTooltip(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginTooltip));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginTooltip",
+            "ctx",
+        );
         match self.pointers.BeginTooltip {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13594,6 +18425,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndTooltip(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndTooltip));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndTooltip",
+            "ctx",
+        );
         match self.pointers.EndTooltip {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13603,6 +18444,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetTooltip(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetTooltip));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetTooltip",
+            "ctx",
+        );
         match self.pointers.SetTooltip {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13617,6 +18468,16 @@ impl ImGui {
         idx: std::ffi::c_int,
         alpha_mulInOptional: *mut f64,
     ) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetColor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetColor",
+            "ctx",
+        );
         match self.pointers.GetColor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13626,6 +18487,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetColorEx(&self, ctx: Context, col_rgba: std::ffi::c_int) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetColorEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetColorEx",
+            "ctx",
+        );
         match self.pointers.GetColorEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13635,6 +18506,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetStyleColor(&self, ctx: Context, idx: std::ffi::c_int) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetStyleColor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetStyleColor",
+            "ctx",
+        );
         match self.pointers.GetStyleColor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13644,6 +18525,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopStyleColor(&self, ctx: Context, countInOptional: *mut std::ffi::c_int) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopStyleColor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopStyleColor",
+            "ctx",
+        );
         match self.pointers.PopStyleColor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13658,6 +18549,16 @@ impl ImGui {
         idx: std::ffi::c_int,
         col_rgba: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushStyleColor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushStyleColor",
+            "ctx",
+        );
         match self.pointers.PushStyleColor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13673,6 +18574,16 @@ impl ImGui {
         val1Out: *mut f64,
         val2Out: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetStyleVar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetStyleVar",
+            "ctx",
+        );
         match self.pointers.GetStyleVar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13682,6 +18593,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopStyleVar(&self, ctx: Context, countInOptional: *mut std::ffi::c_int) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopStyleVar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopStyleVar",
+            "ctx",
+        );
         match self.pointers.PopStyleVar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13697,6 +18618,16 @@ impl ImGui {
         val1: f64,
         val2InOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushStyleVar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushStyleVar",
+            "ctx",
+        );
         match self.pointers.PushStyleVar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13711,6 +18642,16 @@ impl ImGui {
         str_id: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginTabBar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginTabBar",
+            "ctx",
+        );
         match self.pointers.BeginTabBar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13720,6 +18661,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndTabBar(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndTabBar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndTabBar",
+            "ctx",
+        );
         match self.pointers.EndTabBar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13735,6 +18686,16 @@ impl ImGui {
         p_openInOutOptional: *mut bool,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginTabItem));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginTabItem",
+            "ctx",
+        );
         match self.pointers.BeginTabItem {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13744,6 +18705,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndTabItem(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndTabItem));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndTabItem",
+            "ctx",
+        );
         match self.pointers.EndTabItem {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13757,6 +18728,16 @@ impl ImGui {
         ctx: Context,
         tab_or_docked_window_label: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetTabItemClosed));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetTabItemClosed",
+            "ctx",
+        );
         match self.pointers.SetTabItemClosed {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13771,6 +18752,16 @@ impl ImGui {
         label: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TabItemButton));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TabItemButton",
+            "ctx",
+        );
         match self.pointers.TabItemButton {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13789,6 +18780,16 @@ impl ImGui {
         outer_size_hInOptional: *mut f64,
         inner_widthInOptional: *mut f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginTable));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginTable",
+            "ctx",
+        );
         match self.pointers.BeginTable {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13806,6 +18807,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndTable(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndTable));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndTable",
+            "ctx",
+        );
         match self.pointers.EndTable {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13815,6 +18826,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableGetColumnCount(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableGetColumnCount));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableGetColumnCount",
+            "ctx",
+        );
         match self.pointers.TableGetColumnCount {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13824,6 +18845,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableGetColumnIndex(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableGetColumnIndex));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableGetColumnIndex",
+            "ctx",
+        );
         match self.pointers.TableGetColumnIndex {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13833,6 +18864,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableGetRowIndex(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableGetRowIndex));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableGetRowIndex",
+            "ctx",
+        );
         match self.pointers.TableGetRowIndex {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13842,6 +18883,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableNextColumn(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableNextColumn));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableNextColumn",
+            "ctx",
+        );
         match self.pointers.TableNextColumn {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13856,6 +18907,16 @@ impl ImGui {
         row_flagsInOptional: *mut std::ffi::c_int,
         min_row_heightInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableNextRow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableNextRow",
+            "ctx",
+        );
         match self.pointers.TableNextRow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13865,6 +18926,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableSetColumnIndex(&self, ctx: Context, column_n: std::ffi::c_int) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableSetColumnIndex));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableSetColumnIndex",
+            "ctx",
+        );
         match self.pointers.TableSetColumnIndex {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13880,6 +18951,16 @@ impl ImGui {
         color_rgba: std::ffi::c_int,
         column_nInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableSetBgColor));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableSetBgColor",
+            "ctx",
+        );
         match self.pointers.TableSetBgColor {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13893,6 +18974,16 @@ impl ImGui {
         ctx: Context,
         column_nInOptional: *mut std::ffi::c_int,
     ) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableGetColumnFlags));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableGetColumnFlags",
+            "ctx",
+        );
         match self.pointers.TableGetColumnFlags {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13902,6 +18993,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableHeader(&self, ctx: Context, label: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableHeader));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableHeader",
+            "ctx",
+        );
         match self.pointers.TableHeader {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13911,6 +19012,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableHeadersRow(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableHeadersRow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableHeadersRow",
+            "ctx",
+        );
         match self.pointers.TableHeadersRow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13925,6 +19036,16 @@ impl ImGui {
         column_n: std::ffi::c_int,
         v: bool,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableSetColumnEnabled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableSetColumnEnabled",
+            "ctx",
+        );
         match self.pointers.TableSetColumnEnabled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13941,6 +19062,16 @@ impl ImGui {
         init_width_or_weightInOptional: *mut f64,
         user_idInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableSetupColumn));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableSetupColumn",
+            "ctx",
+        );
         match self.pointers.TableSetupColumn {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13961,6 +19092,16 @@ impl ImGui {
         cols: std::ffi::c_int,
         rows: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableSetupScrollFreeze));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableSetupScrollFreeze",
+            "ctx",
+        );
         match self.pointers.TableSetupScrollFreeze {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13978,6 +19119,16 @@ impl ImGui {
         sort_orderOut: *mut std::ffi::c_int,
         sort_directionOut: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableGetColumnSortSpecs));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableGetColumnSortSpecs",
+            "ctx",
+        );
         match self.pointers.TableGetColumnSortSpecs {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -13994,6 +19145,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TableNeedSort(&self, ctx: Context, has_specsOut: *mut bool) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TableNeedSort));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TableNeedSort",
+            "ctx",
+        );
         match self.pointers.TableNeedSort {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14003,6 +19164,16 @@ impl ImGui {
         }
     }
     pub unsafe fn AlignTextToFramePadding(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(AlignTextToFramePadding));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "AlignTextToFramePadding",
+            "ctx",
+        );
         match self.pointers.AlignTextToFramePadding {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14012,6 +19183,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Bullet(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Bullet));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Bullet",
+            "ctx",
+        );
         match self.pointers.Bullet {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14021,6 +19202,16 @@ impl ImGui {
         }
     }
     pub unsafe fn BulletText(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BulletText));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BulletText",
+            "ctx",
+        );
         match self.pointers.BulletText {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14038,6 +19229,16 @@ impl ImGui {
         hide_text_after_double_hashInOptional: *mut bool,
         wrap_widthInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CalcTextSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "CalcTextSize",
+            "ctx",
+        );
         match self.pointers.CalcTextSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14054,6 +19255,16 @@ impl ImGui {
         }
     }
     pub unsafe fn DebugTextEncoding(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(DebugTextEncoding));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "DebugTextEncoding",
+            "ctx",
+        );
         match self.pointers.DebugTextEncoding {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14063,6 +19274,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetFrameHeight(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetFrameHeight));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetFrameHeight",
+            "ctx",
+        );
         match self.pointers.GetFrameHeight {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14072,6 +19293,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetFrameHeightWithSpacing(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetFrameHeightWithSpacing));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetFrameHeightWithSpacing",
+            "ctx",
+        );
         match self.pointers.GetFrameHeightWithSpacing {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14081,6 +19312,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetTextLineHeight(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetTextLineHeight));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetTextLineHeight",
+            "ctx",
+        );
         match self.pointers.GetTextLineHeight {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14090,6 +19331,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetTextLineHeightWithSpacing(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetTextLineHeightWithSpacing));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetTextLineHeightWithSpacing",
+            "ctx",
+        );
         match self.pointers.GetTextLineHeightWithSpacing {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14104,6 +19355,16 @@ impl ImGui {
         label: *const std::ffi::c_char,
         text: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(LabelText));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "LabelText",
+            "ctx",
+        );
         match self.pointers.LabelText {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14113,6 +19374,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopTextWrapPos(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopTextWrapPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopTextWrapPos",
+            "ctx",
+        );
         match self.pointers.PopTextWrapPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14122,6 +19393,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PushTextWrapPos(&self, ctx: Context, wrap_local_pos_xInOptional: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushTextWrapPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushTextWrapPos",
+            "ctx",
+        );
         match self.pointers.PushTextWrapPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14131,6 +19412,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Text(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Text));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Text",
+            "ctx",
+        );
         match self.pointers.Text {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14145,6 +19436,16 @@ impl ImGui {
         col_rgba: std::ffi::c_int,
         text: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextColored));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TextColored",
+            "ctx",
+        );
         match self.pointers.TextColored {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14154,6 +19455,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TextDisabled(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextDisabled));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TextDisabled",
+            "ctx",
+        );
         match self.pointers.TextDisabled {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14163,6 +19474,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TextWrapped(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextWrapped));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TextWrapped",
+            "ctx",
+        );
         match self.pointers.TextWrapped {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14181,6 +19502,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputDouble));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputDouble",
+            "ctx",
+        );
         match self.pointers.InputDouble {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14206,6 +19537,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputDouble2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputDouble2",
+            "ctx",
+        );
         match self.pointers.InputDouble2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14231,6 +19572,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputDouble3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputDouble3",
+            "ctx",
+        );
         match self.pointers.InputDouble3 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14258,6 +19609,16 @@ impl ImGui {
         formatInOptional: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputDouble4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputDouble4",
+            "ctx",
+        );
         match self.pointers.InputDouble4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14284,6 +19645,16 @@ impl ImGui {
         step_fastInOptional: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputInt));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputInt",
+            "ctx",
+        );
         match self.pointers.InputInt {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14307,6 +19678,16 @@ impl ImGui {
         v2InOut: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputInt2));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputInt2",
+            "ctx",
+        );
         match self.pointers.InputInt2 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14324,6 +19705,16 @@ impl ImGui {
         v3InOut: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputInt3));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputInt3",
+            "ctx",
+        );
         match self.pointers.InputInt3 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14342,6 +19733,16 @@ impl ImGui {
         v4InOut: *mut std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputInt4));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputInt4",
+            "ctx",
+        );
         match self.pointers.InputInt4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14366,6 +19767,16 @@ impl ImGui {
         bufInOutNeedBig_sz: std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputText));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputText",
+            "ctx",
+        );
         match self.pointers.InputText {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14390,6 +19801,16 @@ impl ImGui {
         size_hInOptional: *mut f64,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputTextMultiline));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputTextMultiline",
+            "ctx",
+        );
         match self.pointers.InputTextMultiline {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14415,6 +19836,16 @@ impl ImGui {
         bufInOutNeedBig_sz: std::ffi::c_int,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(InputTextWithHint));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "InputTextWithHint",
+            "ctx",
+        );
         match self.pointers.InputTextWithHint {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14434,6 +19865,8 @@ impl ImGui {
         &self,
         default_filterInOptional: *const std::ffi::c_char,
     ) -> TextFilter {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CreateTextFilter));
         match self.pointers.CreateTextFilter {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14443,6 +19876,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TextFilter_Clear(&self, filter: TextFilter) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextFilter_Clear));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            filter,
+            c_str_macro::c_str!("ImGui_TextFilter*"),
+            false,
+            "TextFilter_Clear",
+            "filter",
+        );
         match self.pointers.TextFilter_Clear {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14458,6 +19901,24 @@ impl ImGui {
         labelInOptional: *const std::ffi::c_char,
         widthInOptional: *mut f64,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextFilter_Draw));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            filter,
+            c_str_macro::c_str!("ImGui_TextFilter*"),
+            false,
+            "TextFilter_Draw",
+            "filter",
+        );
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TextFilter_Draw",
+            "ctx",
+        );
         match self.pointers.TextFilter_Draw {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14467,6 +19928,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TextFilter_IsActive(&self, filter: TextFilter) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextFilter_IsActive));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            filter,
+            c_str_macro::c_str!("ImGui_TextFilter*"),
+            false,
+            "TextFilter_IsActive",
+            "filter",
+        );
         match self.pointers.TextFilter_IsActive {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14480,6 +19951,16 @@ impl ImGui {
         filter: TextFilter,
         text: *const std::ffi::c_char,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextFilter_PassFilter));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            filter,
+            c_str_macro::c_str!("ImGui_TextFilter*"),
+            false,
+            "TextFilter_PassFilter",
+            "filter",
+        );
         match self.pointers.TextFilter_PassFilter {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14493,6 +19974,16 @@ impl ImGui {
         filter: TextFilter,
         filter_text: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TextFilter_Set));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            filter,
+            c_str_macro::c_str!("ImGui_TextFilter*"),
+            false,
+            "TextFilter_Set",
+            "filter",
+        );
         match self.pointers.TextFilter_Set {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14508,6 +19999,16 @@ impl ImGui {
         p_visibleInOut: *mut bool,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(CollapsingHeader));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "CollapsingHeader",
+            "ctx",
+        );
         match self.pointers.CollapsingHeader {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14517,6 +20018,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetTreeNodeToLabelSpacing(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetTreeNodeToLabelSpacing));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetTreeNodeToLabelSpacing",
+            "ctx",
+        );
         match self.pointers.GetTreeNodeToLabelSpacing {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14526,6 +20037,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsItemToggledOpen(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsItemToggledOpen));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsItemToggledOpen",
+            "ctx",
+        );
         match self.pointers.IsItemToggledOpen {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14540,6 +20061,16 @@ impl ImGui {
         is_open: bool,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextItemOpen));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextItemOpen",
+            "ctx",
+        );
         match self.pointers.SetNextItemOpen {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14554,6 +20085,16 @@ impl ImGui {
         label: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TreeNode));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TreeNode",
+            "ctx",
+        );
         match self.pointers.TreeNode {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14569,6 +20110,16 @@ impl ImGui {
         label: *const std::ffi::c_char,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TreeNodeEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TreeNodeEx",
+            "ctx",
+        );
         match self.pointers.TreeNodeEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14578,6 +20129,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TreePop(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TreePop));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TreePop",
+            "ctx",
+        );
         match self.pointers.TreePop {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14587,6 +20148,16 @@ impl ImGui {
         }
     }
     pub unsafe fn TreePush(&self, ctx: Context, str_id: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(TreePush));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "TreePush",
+            "ctx",
+        );
         match self.pointers.TreePush {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14603,6 +20174,8 @@ impl ImGui {
         reaimgui_versionOut: *mut std::ffi::c_char,
         reaimgui_versionOut_sz: std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetVersion));
         match self.pointers.GetVersion {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14618,6 +20191,8 @@ impl ImGui {
         }
     }
     pub unsafe fn NumericLimits_Float(&self, minOut: *mut f64, maxOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(NumericLimits_Float));
         match self.pointers.NumericLimits_Float {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14633,6 +20208,16 @@ impl ImGui {
         yInOut: *mut f64,
         to_nativeInOptional: *mut bool,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PointConvertNative));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PointConvertNative",
+            "ctx",
+        );
         match self.pointers.PointConvertNative {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14649,6 +20234,16 @@ impl ImGui {
         size_arg_hInOptional: *mut f64,
         overlayInOptional: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ProgressBar));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ProgressBar",
+            "ctx",
+        );
         match self.pointers.ProgressBar {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14663,7 +20258,32 @@ impl ImGui {
             ),
         }
     }
+    pub unsafe fn ValidatePtr(
+        &self,
+        pointer: *mut std::ffi::c_void,
+        type_: *const std::ffi::c_char,
+    ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ValidatePtr));
+        match self.pointers.ValidatePtr {
+            None => panic!(
+                "Attempt to use a function that has not been loaded: {}",
+                stringify!(ValidatePtr)
+            ),
+            Some(f) => f(pointer, type_),
+        }
+    }
     pub unsafe fn SetClipboardText(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetClipboardText));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetClipboardText",
+            "ctx",
+        );
         match self.pointers.SetClipboardText {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14679,6 +20299,8 @@ impl ImGui {
         b: f64,
         a: f64,
     ) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorConvertDouble4ToU32));
         match self.pointers.ColorConvertDouble4ToU32 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14696,6 +20318,8 @@ impl ImGui {
         gOut: *mut f64,
         bOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorConvertHSVtoRGB));
         match self.pointers.ColorConvertHSVtoRGB {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14705,6 +20329,8 @@ impl ImGui {
         }
     }
     pub unsafe fn ColorConvertNative(&self, rgb: std::ffi::c_int) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorConvertNative));
         match self.pointers.ColorConvertNative {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14722,6 +20348,8 @@ impl ImGui {
         sOut: *mut f64,
         vOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorConvertRGBtoHSV));
         match self.pointers.ColorConvertRGBtoHSV {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14738,6 +20366,8 @@ impl ImGui {
         bOut: *mut f64,
         aOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ColorConvertU32ToDouble4));
         match self.pointers.ColorConvertU32ToDouble4 {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14747,6 +20377,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PopID(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PopID));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PopID",
+            "ctx",
+        );
         match self.pointers.PopID {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14756,6 +20396,16 @@ impl ImGui {
         }
     }
     pub unsafe fn PushID(&self, ctx: Context, str_id: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(PushID));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "PushID",
+            "ctx",
+        );
         match self.pointers.PushID {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14765,6 +20415,16 @@ impl ImGui {
         }
     }
     pub unsafe fn LogFinish(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(LogFinish));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "LogFinish",
+            "ctx",
+        );
         match self.pointers.LogFinish {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14774,6 +20434,16 @@ impl ImGui {
         }
     }
     pub unsafe fn LogText(&self, ctx: Context, text: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(LogText));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "LogText",
+            "ctx",
+        );
         match self.pointers.LogText {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14787,6 +20457,16 @@ impl ImGui {
         ctx: Context,
         auto_open_depthInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(LogToClipboard));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "LogToClipboard",
+            "ctx",
+        );
         match self.pointers.LogToClipboard {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14801,6 +20481,16 @@ impl ImGui {
         auto_open_depthInOptional: *mut std::ffi::c_int,
         filenameInOptional: *const std::ffi::c_char,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(LogToFile));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "LogToFile",
+            "ctx",
+        );
         match self.pointers.LogToFile {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14814,6 +20504,16 @@ impl ImGui {
         ctx: Context,
         auto_open_depthInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(LogToTTY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "LogToTTY",
+            "ctx",
+        );
         match self.pointers.LogToTTY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14823,6 +20523,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetMainViewport(&self, ctx: Context) -> Viewport {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetMainViewport));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetMainViewport",
+            "ctx",
+        );
         match self.pointers.GetMainViewport {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14832,6 +20542,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowViewport(&self, ctx: Context) -> Viewport {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowViewport));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowViewport",
+            "ctx",
+        );
         match self.pointers.GetWindowViewport {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14846,6 +20566,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Viewport_GetCenter));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            viewport,
+            c_str_macro::c_str!("ImGui_Viewport*"),
+            false,
+            "Viewport_GetCenter",
+            "viewport",
+        );
         match self.pointers.Viewport_GetCenter {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14855,6 +20585,16 @@ impl ImGui {
         }
     }
     pub unsafe fn Viewport_GetPos(&self, viewport: Viewport, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Viewport_GetPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            viewport,
+            c_str_macro::c_str!("ImGui_Viewport*"),
+            false,
+            "Viewport_GetPos",
+            "viewport",
+        );
         match self.pointers.Viewport_GetPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14869,6 +20609,16 @@ impl ImGui {
         wOut: *mut f64,
         hOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Viewport_GetSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            viewport,
+            c_str_macro::c_str!("ImGui_Viewport*"),
+            false,
+            "Viewport_GetSize",
+            "viewport",
+        );
         match self.pointers.Viewport_GetSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14883,6 +20633,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Viewport_GetWorkCenter));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            viewport,
+            c_str_macro::c_str!("ImGui_Viewport*"),
+            false,
+            "Viewport_GetWorkCenter",
+            "viewport",
+        );
         match self.pointers.Viewport_GetWorkCenter {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14897,6 +20657,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Viewport_GetWorkPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            viewport,
+            c_str_macro::c_str!("ImGui_Viewport*"),
+            false,
+            "Viewport_GetWorkPos",
+            "viewport",
+        );
         match self.pointers.Viewport_GetWorkPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14911,6 +20681,16 @@ impl ImGui {
         wOut: *mut f64,
         hOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Viewport_GetWorkSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            viewport,
+            c_str_macro::c_str!("ImGui_Viewport*"),
+            false,
+            "Viewport_GetWorkSize",
+            "viewport",
+        );
         match self.pointers.Viewport_GetWorkSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14926,6 +20706,16 @@ impl ImGui {
         p_openInOutOptional: *mut bool,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(Begin));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "Begin",
+            "ctx",
+        );
         match self.pointers.Begin {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14935,6 +20725,16 @@ impl ImGui {
         }
     }
     pub unsafe fn End(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(End));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "End",
+            "ctx",
+        );
         match self.pointers.End {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14952,6 +20752,16 @@ impl ImGui {
         borderInOptional: *mut bool,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginChild));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginChild",
+            "ctx",
+        );
         match self.pointers.BeginChild {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14975,6 +20785,16 @@ impl ImGui {
         size_h: f64,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(BeginChildFrame));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "BeginChildFrame",
+            "ctx",
+        );
         match self.pointers.BeginChildFrame {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14984,6 +20804,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndChild(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndChild));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndChild",
+            "ctx",
+        );
         match self.pointers.EndChild {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -14993,6 +20823,16 @@ impl ImGui {
         }
     }
     pub unsafe fn EndChildFrame(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(EndChildFrame));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "EndChildFrame",
+            "ctx",
+        );
         match self.pointers.EndChildFrame {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15002,6 +20842,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetContentRegionAvail(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetContentRegionAvail));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetContentRegionAvail",
+            "ctx",
+        );
         match self.pointers.GetContentRegionAvail {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15011,6 +20861,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetContentRegionMax(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetContentRegionMax));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetContentRegionMax",
+            "ctx",
+        );
         match self.pointers.GetContentRegionMax {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15025,6 +20885,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowContentRegionMax));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowContentRegionMax",
+            "ctx",
+        );
         match self.pointers.GetWindowContentRegionMax {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15039,6 +20909,16 @@ impl ImGui {
         xOut: *mut f64,
         yOut: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowContentRegionMin));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowContentRegionMin",
+            "ctx",
+        );
         match self.pointers.GetWindowContentRegionMin {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15048,6 +20928,16 @@ impl ImGui {
         }
     }
     pub unsafe fn ShowAboutWindow(&self, ctx: Context, p_openInOutOptional: *mut bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ShowAboutWindow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ShowAboutWindow",
+            "ctx",
+        );
         match self.pointers.ShowAboutWindow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15057,6 +20947,16 @@ impl ImGui {
         }
     }
     pub unsafe fn ShowDebugLogWindow(&self, ctx: Context, p_openInOutOptional: *mut bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ShowDebugLogWindow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ShowDebugLogWindow",
+            "ctx",
+        );
         match self.pointers.ShowDebugLogWindow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15066,6 +20966,16 @@ impl ImGui {
         }
     }
     pub unsafe fn ShowMetricsWindow(&self, ctx: Context, p_openInOutOptional: *mut bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ShowMetricsWindow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ShowMetricsWindow",
+            "ctx",
+        );
         match self.pointers.ShowMetricsWindow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15075,6 +20985,16 @@ impl ImGui {
         }
     }
     pub unsafe fn ShowStackToolWindow(&self, ctx: Context, p_openInOutOptional: *mut bool) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(ShowStackToolWindow));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "ShowStackToolWindow",
+            "ctx",
+        );
         match self.pointers.ShowStackToolWindow {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15084,6 +21004,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowDockID(&self, ctx: Context) -> std::ffi::c_int {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowDockID));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowDockID",
+            "ctx",
+        );
         match self.pointers.GetWindowDockID {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15093,6 +21023,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsWindowDocked(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsWindowDocked));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsWindowDocked",
+            "ctx",
+        );
         match self.pointers.IsWindowDocked {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15107,6 +21047,16 @@ impl ImGui {
         dock_id: std::ffi::c_int,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowDockID));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowDockID",
+            "ctx",
+        );
         match self.pointers.SetNextWindowDockID {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15116,6 +21066,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowDpiScale(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowDpiScale));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowDpiScale",
+            "ctx",
+        );
         match self.pointers.GetWindowDpiScale {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15125,6 +21085,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowHeight(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowHeight));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowHeight",
+            "ctx",
+        );
         match self.pointers.GetWindowHeight {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15134,6 +21104,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowPos(&self, ctx: Context, xOut: *mut f64, yOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowPos",
+            "ctx",
+        );
         match self.pointers.GetWindowPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15143,6 +21123,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowSize(&self, ctx: Context, wOut: *mut f64, hOut: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowSize",
+            "ctx",
+        );
         match self.pointers.GetWindowSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15152,6 +21142,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetWindowWidth(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetWindowWidth));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetWindowWidth",
+            "ctx",
+        );
         match self.pointers.GetWindowWidth {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15161,6 +21161,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsWindowAppearing(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsWindowAppearing));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsWindowAppearing",
+            "ctx",
+        );
         match self.pointers.IsWindowAppearing {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15170,6 +21180,16 @@ impl ImGui {
         }
     }
     pub unsafe fn IsWindowCollapsed(&self, ctx: Context) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsWindowCollapsed));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsWindowCollapsed",
+            "ctx",
+        );
         match self.pointers.IsWindowCollapsed {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15183,6 +21203,16 @@ impl ImGui {
         ctx: Context,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsWindowFocused));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsWindowFocused",
+            "ctx",
+        );
         match self.pointers.IsWindowFocused {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15196,6 +21226,16 @@ impl ImGui {
         ctx: Context,
         flagsInOptional: *mut std::ffi::c_int,
     ) -> bool {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(IsWindowHovered));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "IsWindowHovered",
+            "ctx",
+        );
         match self.pointers.IsWindowHovered {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15205,6 +21245,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetNextWindowBgAlpha(&self, ctx: Context, alpha: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowBgAlpha));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowBgAlpha",
+            "ctx",
+        );
         match self.pointers.SetNextWindowBgAlpha {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15219,6 +21269,16 @@ impl ImGui {
         collapsed: bool,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowCollapsed));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowCollapsed",
+            "ctx",
+        );
         match self.pointers.SetNextWindowCollapsed {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15228,6 +21288,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetNextWindowContentSize(&self, ctx: Context, size_w: f64, size_h: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowContentSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowContentSize",
+            "ctx",
+        );
         match self.pointers.SetNextWindowContentSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15237,6 +21307,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetNextWindowFocus(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowFocus));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowFocus",
+            "ctx",
+        );
         match self.pointers.SetNextWindowFocus {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15254,6 +21334,16 @@ impl ImGui {
         pivot_xInOptional: *mut f64,
         pivot_yInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowPos",
+            "ctx",
+        );
         match self.pointers.SetNextWindowPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15270,6 +21360,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetNextWindowScroll(&self, ctx: Context, scroll_x: f64, scroll_y: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowScroll));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowScroll",
+            "ctx",
+        );
         match self.pointers.SetNextWindowScroll {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15285,6 +21385,16 @@ impl ImGui {
         size_h: f64,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowSize",
+            "ctx",
+        );
         match self.pointers.SetNextWindowSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15301,6 +21411,16 @@ impl ImGui {
         size_max_w: f64,
         size_max_h: f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetNextWindowSizeConstraints));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetNextWindowSizeConstraints",
+            "ctx",
+        );
         match self.pointers.SetNextWindowSizeConstraints {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15315,6 +21435,16 @@ impl ImGui {
         collapsed: bool,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowCollapsed));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowCollapsed",
+            "ctx",
+        );
         match self.pointers.SetWindowCollapsed {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15330,6 +21460,16 @@ impl ImGui {
         collapsed: bool,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowCollapsedEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowCollapsedEx",
+            "ctx",
+        );
         match self.pointers.SetWindowCollapsedEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15339,6 +21479,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetWindowFocus(&self, ctx: Context) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowFocus));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowFocus",
+            "ctx",
+        );
         match self.pointers.SetWindowFocus {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15348,6 +21498,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetWindowFocusEx(&self, ctx: Context, name: *const std::ffi::c_char) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowFocusEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowFocusEx",
+            "ctx",
+        );
         match self.pointers.SetWindowFocusEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15363,6 +21523,16 @@ impl ImGui {
         pos_y: f64,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowPos));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowPos",
+            "ctx",
+        );
         match self.pointers.SetWindowPos {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15379,6 +21549,16 @@ impl ImGui {
         pos_y: f64,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowPosEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowPosEx",
+            "ctx",
+        );
         match self.pointers.SetWindowPosEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15394,6 +21574,16 @@ impl ImGui {
         size_h: f64,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowSize));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowSize",
+            "ctx",
+        );
         match self.pointers.SetWindowSize {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15410,6 +21600,16 @@ impl ImGui {
         size_h: f64,
         condInOptional: *mut std::ffi::c_int,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetWindowSizeEx));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetWindowSizeEx",
+            "ctx",
+        );
         match self.pointers.SetWindowSizeEx {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15419,6 +21619,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetScrollMaxX(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetScrollMaxX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetScrollMaxX",
+            "ctx",
+        );
         match self.pointers.GetScrollMaxX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15428,6 +21638,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetScrollMaxY(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetScrollMaxY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetScrollMaxY",
+            "ctx",
+        );
         match self.pointers.GetScrollMaxY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15437,6 +21657,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetScrollX(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetScrollX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetScrollX",
+            "ctx",
+        );
         match self.pointers.GetScrollX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15446,6 +21676,16 @@ impl ImGui {
         }
     }
     pub unsafe fn GetScrollY(&self, ctx: Context) -> f64 {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(GetScrollY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "GetScrollY",
+            "ctx",
+        );
         match self.pointers.GetScrollY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15460,6 +21700,16 @@ impl ImGui {
         local_x: f64,
         center_x_ratioInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetScrollFromPosX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetScrollFromPosX",
+            "ctx",
+        );
         match self.pointers.SetScrollFromPosX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15474,6 +21724,16 @@ impl ImGui {
         local_y: f64,
         center_y_ratioInOptional: *mut f64,
     ) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetScrollFromPosY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetScrollFromPosY",
+            "ctx",
+        );
         match self.pointers.SetScrollFromPosY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15483,6 +21743,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetScrollHereX(&self, ctx: Context, center_x_ratioInOptional: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetScrollHereX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetScrollHereX",
+            "ctx",
+        );
         match self.pointers.SetScrollHereX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15492,6 +21762,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetScrollHereY(&self, ctx: Context, center_y_ratioInOptional: *mut f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetScrollHereY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetScrollHereY",
+            "ctx",
+        );
         match self.pointers.SetScrollHereY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15501,6 +21781,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetScrollX(&self, ctx: Context, scroll_x: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetScrollX));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetScrollX",
+            "ctx",
+        );
         match self.pointers.SetScrollX {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -15510,6 +21800,16 @@ impl ImGui {
         }
     }
     pub unsafe fn SetScrollY(&self, ctx: Context, scroll_y: f64) -> () {
+        #[cfg(debug_assertions)]
+        self.assert_main_thread(stringify!(SetScrollY));
+        #[cfg(feature = "debug-validate")]
+        self.validate_handle(
+            ctx,
+            c_str_macro::c_str!("ImGui_Context*"),
+            false,
+            "SetScrollY",
+            "ctx",
+        );
         match self.pointers.SetScrollY {
             None => panic!(
                 "Attempt to use a function that has not been loaded: {}",
@@ -16660,6 +22960,8 @@ struct FunctionPointers {
     ProgressBar: Option<
         unsafe extern "C" fn(Context, f64, *mut f64, *mut f64, *const std::ffi::c_char) -> (),
     >,
+    ValidatePtr:
+        Option<unsafe extern "C" fn(*mut std::ffi::c_void, *const std::ffi::c_char) -> bool>,
     SetClipboardText: Option<unsafe extern "C" fn(Context, *const std::ffi::c_char) -> ()>,
     ColorConvertDouble4ToU32: Option<unsafe extern "C" fn(f64, f64, f64, f64) -> std::ffi::c_int>,
     ColorConvertHSVtoRGB:
@@ -16793,3 +23095,1281 @@ impl std::fmt::Debug for FunctionPointers {
 struct ConstLoader {
     f: fn() -> i32,
 }
+#[doc = "Color of a style element (`Col_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Col {
+    Border,
+    BorderShadow,
+    Button,
+    ButtonActive,
+    ButtonHovered,
+    CheckMark,
+    ChildBg,
+    DockingEmptyBg,
+    DockingPreview,
+    DragDropTarget,
+    FrameBg,
+    FrameBgActive,
+    FrameBgHovered,
+    Header,
+    HeaderActive,
+    HeaderHovered,
+    MenuBarBg,
+    ModalWindowDimBg,
+    NavHighlight,
+    NavWindowingDimBg,
+    NavWindowingHighlight,
+    PlotHistogram,
+    PlotHistogramHovered,
+    PlotLines,
+    PlotLinesHovered,
+    PopupBg,
+    ResizeGrip,
+    ResizeGripActive,
+    ResizeGripHovered,
+    ScrollbarBg,
+    ScrollbarGrab,
+    ScrollbarGrabActive,
+    ScrollbarGrabHovered,
+    Separator,
+    SeparatorActive,
+    SeparatorHovered,
+    SliderGrab,
+    SliderGrabActive,
+    Tab,
+    TabActive,
+    TabHovered,
+    TabUnfocused,
+    TabUnfocusedActive,
+    TableBorderLight,
+    TableBorderStrong,
+    TableHeaderBg,
+    TableRowBg,
+    TableRowBgAlt,
+    Text,
+    TextDisabled,
+    TextSelectedBg,
+    TitleBg,
+    TitleBgActive,
+    TitleBgCollapsed,
+    WindowBg,
+}
+impl Col {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[
+        Self::Border,
+        Self::BorderShadow,
+        Self::Button,
+        Self::ButtonActive,
+        Self::ButtonHovered,
+        Self::CheckMark,
+        Self::ChildBg,
+        Self::DockingEmptyBg,
+        Self::DockingPreview,
+        Self::DragDropTarget,
+        Self::FrameBg,
+        Self::FrameBgActive,
+        Self::FrameBgHovered,
+        Self::Header,
+        Self::HeaderActive,
+        Self::HeaderHovered,
+        Self::MenuBarBg,
+        Self::ModalWindowDimBg,
+        Self::NavHighlight,
+        Self::NavWindowingDimBg,
+        Self::NavWindowingHighlight,
+        Self::PlotHistogram,
+        Self::PlotHistogramHovered,
+        Self::PlotLines,
+        Self::PlotLinesHovered,
+        Self::PopupBg,
+        Self::ResizeGrip,
+        Self::ResizeGripActive,
+        Self::ResizeGripHovered,
+        Self::ScrollbarBg,
+        Self::ScrollbarGrab,
+        Self::ScrollbarGrabActive,
+        Self::ScrollbarGrabHovered,
+        Self::Separator,
+        Self::SeparatorActive,
+        Self::SeparatorHovered,
+        Self::SliderGrab,
+        Self::SliderGrabActive,
+        Self::Tab,
+        Self::TabActive,
+        Self::TabHovered,
+        Self::TabUnfocused,
+        Self::TabUnfocusedActive,
+        Self::TableBorderLight,
+        Self::TableBorderStrong,
+        Self::TableHeaderBg,
+        Self::TableRowBg,
+        Self::TableRowBgAlt,
+        Self::Text,
+        Self::TextDisabled,
+        Self::TextSelectedBg,
+        Self::TitleBg,
+        Self::TitleBgActive,
+        Self::TitleBgCollapsed,
+        Self::WindowBg,
+    ];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Border => "Border",
+            Self::BorderShadow => "BorderShadow",
+            Self::Button => "Button",
+            Self::ButtonActive => "ButtonActive",
+            Self::ButtonHovered => "ButtonHovered",
+            Self::CheckMark => "CheckMark",
+            Self::ChildBg => "ChildBg",
+            Self::DockingEmptyBg => "DockingEmptyBg",
+            Self::DockingPreview => "DockingPreview",
+            Self::DragDropTarget => "DragDropTarget",
+            Self::FrameBg => "FrameBg",
+            Self::FrameBgActive => "FrameBgActive",
+            Self::FrameBgHovered => "FrameBgHovered",
+            Self::Header => "Header",
+            Self::HeaderActive => "HeaderActive",
+            Self::HeaderHovered => "HeaderHovered",
+            Self::MenuBarBg => "MenuBarBg",
+            Self::ModalWindowDimBg => "ModalWindowDimBg",
+            Self::NavHighlight => "NavHighlight",
+            Self::NavWindowingDimBg => "NavWindowingDimBg",
+            Self::NavWindowingHighlight => "NavWindowingHighlight",
+            Self::PlotHistogram => "PlotHistogram",
+            Self::PlotHistogramHovered => "PlotHistogramHovered",
+            Self::PlotLines => "PlotLines",
+            Self::PlotLinesHovered => "PlotLinesHovered",
+            Self::PopupBg => "PopupBg",
+            Self::ResizeGrip => "ResizeGrip",
+            Self::ResizeGripActive => "ResizeGripActive",
+            Self::ResizeGripHovered => "ResizeGripHovered",
+            Self::ScrollbarBg => "ScrollbarBg",
+            Self::ScrollbarGrab => "ScrollbarGrab",
+            Self::ScrollbarGrabActive => "ScrollbarGrabActive",
+            Self::ScrollbarGrabHovered => "ScrollbarGrabHovered",
+            Self::Separator => "Separator",
+            Self::SeparatorActive => "SeparatorActive",
+            Self::SeparatorHovered => "SeparatorHovered",
+            Self::SliderGrab => "SliderGrab",
+            Self::SliderGrabActive => "SliderGrabActive",
+            Self::Tab => "Tab",
+            Self::TabActive => "TabActive",
+            Self::TabHovered => "TabHovered",
+            Self::TabUnfocused => "TabUnfocused",
+            Self::TabUnfocusedActive => "TabUnfocusedActive",
+            Self::TableBorderLight => "TableBorderLight",
+            Self::TableBorderStrong => "TableBorderStrong",
+            Self::TableHeaderBg => "TableHeaderBg",
+            Self::TableRowBg => "TableRowBg",
+            Self::TableRowBgAlt => "TableRowBgAlt",
+            Self::Text => "Text",
+            Self::TextDisabled => "TextDisabled",
+            Self::TextSelectedBg => "TextSelectedBg",
+            Self::TitleBg => "TitleBg",
+            Self::TitleBgActive => "TitleBgActive",
+            Self::TitleBgCollapsed => "TitleBgCollapsed",
+            Self::WindowBg => "WindowBg",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Border => imgui.Col_Border,
+            Self::BorderShadow => imgui.Col_BorderShadow,
+            Self::Button => imgui.Col_Button,
+            Self::ButtonActive => imgui.Col_ButtonActive,
+            Self::ButtonHovered => imgui.Col_ButtonHovered,
+            Self::CheckMark => imgui.Col_CheckMark,
+            Self::ChildBg => imgui.Col_ChildBg,
+            Self::DockingEmptyBg => imgui.Col_DockingEmptyBg,
+            Self::DockingPreview => imgui.Col_DockingPreview,
+            Self::DragDropTarget => imgui.Col_DragDropTarget,
+            Self::FrameBg => imgui.Col_FrameBg,
+            Self::FrameBgActive => imgui.Col_FrameBgActive,
+            Self::FrameBgHovered => imgui.Col_FrameBgHovered,
+            Self::Header => imgui.Col_Header,
+            Self::HeaderActive => imgui.Col_HeaderActive,
+            Self::HeaderHovered => imgui.Col_HeaderHovered,
+            Self::MenuBarBg => imgui.Col_MenuBarBg,
+            Self::ModalWindowDimBg => imgui.Col_ModalWindowDimBg,
+            Self::NavHighlight => imgui.Col_NavHighlight,
+            Self::NavWindowingDimBg => imgui.Col_NavWindowingDimBg,
+            Self::NavWindowingHighlight => imgui.Col_NavWindowingHighlight,
+            Self::PlotHistogram => imgui.Col_PlotHistogram,
+            Self::PlotHistogramHovered => imgui.Col_PlotHistogramHovered,
+            Self::PlotLines => imgui.Col_PlotLines,
+            Self::PlotLinesHovered => imgui.Col_PlotLinesHovered,
+            Self::PopupBg => imgui.Col_PopupBg,
+            Self::ResizeGrip => imgui.Col_ResizeGrip,
+            Self::ResizeGripActive => imgui.Col_ResizeGripActive,
+            Self::ResizeGripHovered => imgui.Col_ResizeGripHovered,
+            Self::ScrollbarBg => imgui.Col_ScrollbarBg,
+            Self::ScrollbarGrab => imgui.Col_ScrollbarGrab,
+            Self::ScrollbarGrabActive => imgui.Col_ScrollbarGrabActive,
+            Self::ScrollbarGrabHovered => imgui.Col_ScrollbarGrabHovered,
+            Self::Separator => imgui.Col_Separator,
+            Self::SeparatorActive => imgui.Col_SeparatorActive,
+            Self::SeparatorHovered => imgui.Col_SeparatorHovered,
+            Self::SliderGrab => imgui.Col_SliderGrab,
+            Self::SliderGrabActive => imgui.Col_SliderGrabActive,
+            Self::Tab => imgui.Col_Tab,
+            Self::TabActive => imgui.Col_TabActive,
+            Self::TabHovered => imgui.Col_TabHovered,
+            Self::TabUnfocused => imgui.Col_TabUnfocused,
+            Self::TabUnfocusedActive => imgui.Col_TabUnfocusedActive,
+            Self::TableBorderLight => imgui.Col_TableBorderLight,
+            Self::TableBorderStrong => imgui.Col_TableBorderStrong,
+            Self::TableHeaderBg => imgui.Col_TableHeaderBg,
+            Self::TableRowBg => imgui.Col_TableRowBg,
+            Self::TableRowBgAlt => imgui.Col_TableRowBgAlt,
+            Self::Text => imgui.Col_Text,
+            Self::TextDisabled => imgui.Col_TextDisabled,
+            Self::TextSelectedBg => imgui.Col_TextSelectedBg,
+            Self::TitleBg => imgui.Col_TitleBg,
+            Self::TitleBgActive => imgui.Col_TitleBgActive,
+            Self::TitleBgCollapsed => imgui.Col_TitleBgCollapsed,
+            Self::WindowBg => imgui.Col_WindowBg,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "Col_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for Col {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Style variable (`StyleVar_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleVar {
+    Alpha,
+    ButtonTextAlign,
+    CellPadding,
+    ChildBorderSize,
+    ChildRounding,
+    DisabledAlpha,
+    FrameBorderSize,
+    FramePadding,
+    FrameRounding,
+    GrabMinSize,
+    GrabRounding,
+    IndentSpacing,
+    ItemInnerSpacing,
+    ItemSpacing,
+    PopupBorderSize,
+    PopupRounding,
+    ScrollbarRounding,
+    ScrollbarSize,
+    SelectableTextAlign,
+    TabRounding,
+    WindowBorderSize,
+    WindowMinSize,
+    WindowPadding,
+    WindowRounding,
+    WindowTitleAlign,
+}
+impl StyleVar {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[
+        Self::Alpha,
+        Self::ButtonTextAlign,
+        Self::CellPadding,
+        Self::ChildBorderSize,
+        Self::ChildRounding,
+        Self::DisabledAlpha,
+        Self::FrameBorderSize,
+        Self::FramePadding,
+        Self::FrameRounding,
+        Self::GrabMinSize,
+        Self::GrabRounding,
+        Self::IndentSpacing,
+        Self::ItemInnerSpacing,
+        Self::ItemSpacing,
+        Self::PopupBorderSize,
+        Self::PopupRounding,
+        Self::ScrollbarRounding,
+        Self::ScrollbarSize,
+        Self::SelectableTextAlign,
+        Self::TabRounding,
+        Self::WindowBorderSize,
+        Self::WindowMinSize,
+        Self::WindowPadding,
+        Self::WindowRounding,
+        Self::WindowTitleAlign,
+    ];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Alpha => "Alpha",
+            Self::ButtonTextAlign => "ButtonTextAlign",
+            Self::CellPadding => "CellPadding",
+            Self::ChildBorderSize => "ChildBorderSize",
+            Self::ChildRounding => "ChildRounding",
+            Self::DisabledAlpha => "DisabledAlpha",
+            Self::FrameBorderSize => "FrameBorderSize",
+            Self::FramePadding => "FramePadding",
+            Self::FrameRounding => "FrameRounding",
+            Self::GrabMinSize => "GrabMinSize",
+            Self::GrabRounding => "GrabRounding",
+            Self::IndentSpacing => "IndentSpacing",
+            Self::ItemInnerSpacing => "ItemInnerSpacing",
+            Self::ItemSpacing => "ItemSpacing",
+            Self::PopupBorderSize => "PopupBorderSize",
+            Self::PopupRounding => "PopupRounding",
+            Self::ScrollbarRounding => "ScrollbarRounding",
+            Self::ScrollbarSize => "ScrollbarSize",
+            Self::SelectableTextAlign => "SelectableTextAlign",
+            Self::TabRounding => "TabRounding",
+            Self::WindowBorderSize => "WindowBorderSize",
+            Self::WindowMinSize => "WindowMinSize",
+            Self::WindowPadding => "WindowPadding",
+            Self::WindowRounding => "WindowRounding",
+            Self::WindowTitleAlign => "WindowTitleAlign",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Alpha => imgui.StyleVar_Alpha,
+            Self::ButtonTextAlign => imgui.StyleVar_ButtonTextAlign,
+            Self::CellPadding => imgui.StyleVar_CellPadding,
+            Self::ChildBorderSize => imgui.StyleVar_ChildBorderSize,
+            Self::ChildRounding => imgui.StyleVar_ChildRounding,
+            Self::DisabledAlpha => imgui.StyleVar_DisabledAlpha,
+            Self::FrameBorderSize => imgui.StyleVar_FrameBorderSize,
+            Self::FramePadding => imgui.StyleVar_FramePadding,
+            Self::FrameRounding => imgui.StyleVar_FrameRounding,
+            Self::GrabMinSize => imgui.StyleVar_GrabMinSize,
+            Self::GrabRounding => imgui.StyleVar_GrabRounding,
+            Self::IndentSpacing => imgui.StyleVar_IndentSpacing,
+            Self::ItemInnerSpacing => imgui.StyleVar_ItemInnerSpacing,
+            Self::ItemSpacing => imgui.StyleVar_ItemSpacing,
+            Self::PopupBorderSize => imgui.StyleVar_PopupBorderSize,
+            Self::PopupRounding => imgui.StyleVar_PopupRounding,
+            Self::ScrollbarRounding => imgui.StyleVar_ScrollbarRounding,
+            Self::ScrollbarSize => imgui.StyleVar_ScrollbarSize,
+            Self::SelectableTextAlign => imgui.StyleVar_SelectableTextAlign,
+            Self::TabRounding => imgui.StyleVar_TabRounding,
+            Self::WindowBorderSize => imgui.StyleVar_WindowBorderSize,
+            Self::WindowMinSize => imgui.StyleVar_WindowMinSize,
+            Self::WindowPadding => imgui.StyleVar_WindowPadding,
+            Self::WindowRounding => imgui.StyleVar_WindowRounding,
+            Self::WindowTitleAlign => imgui.StyleVar_WindowTitleAlign,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "StyleVar_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for StyleVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Direction (`Dir_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    Down,
+    Left,
+    None,
+    Right,
+    Up,
+}
+impl Dir {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[Self::Down, Self::Left, Self::None, Self::Right, Self::Up];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Down => "Down",
+            Self::Left => "Left",
+            Self::None => "None",
+            Self::Right => "Right",
+            Self::Up => "Up",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Down => imgui.Dir_Down,
+            Self::Left => imgui.Dir_Left,
+            Self::None => imgui.Dir_None,
+            Self::Right => imgui.Dir_Right,
+            Self::Up => imgui.Dir_Up,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "Dir_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for Dir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Condition for the `SetNext*` functions (`Cond_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cond {
+    Always,
+    Appearing,
+    FirstUseEver,
+    Once,
+}
+impl Cond {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[
+        Self::Always,
+        Self::Appearing,
+        Self::FirstUseEver,
+        Self::Once,
+    ];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Always => "Always",
+            Self::Appearing => "Appearing",
+            Self::FirstUseEver => "FirstUseEver",
+            Self::Once => "Once",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Always => imgui.Cond_Always,
+            Self::Appearing => imgui.Cond_Appearing,
+            Self::FirstUseEver => imgui.Cond_FirstUseEver,
+            Self::Once => imgui.Cond_Once,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "Cond_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for Cond {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Mouse cursor shape (`MouseCursor_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseCursor {
+    Arrow,
+    Hand,
+    NotAllowed,
+    ResizeAll,
+    ResizeEW,
+    ResizeNESW,
+    ResizeNS,
+    ResizeNWSE,
+    TextInput,
+}
+impl MouseCursor {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[
+        Self::Arrow,
+        Self::Hand,
+        Self::NotAllowed,
+        Self::ResizeAll,
+        Self::ResizeEW,
+        Self::ResizeNESW,
+        Self::ResizeNS,
+        Self::ResizeNWSE,
+        Self::TextInput,
+    ];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Arrow => "Arrow",
+            Self::Hand => "Hand",
+            Self::NotAllowed => "NotAllowed",
+            Self::ResizeAll => "ResizeAll",
+            Self::ResizeEW => "ResizeEW",
+            Self::ResizeNESW => "ResizeNESW",
+            Self::ResizeNS => "ResizeNS",
+            Self::ResizeNWSE => "ResizeNWSE",
+            Self::TextInput => "TextInput",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Arrow => imgui.MouseCursor_Arrow,
+            Self::Hand => imgui.MouseCursor_Hand,
+            Self::NotAllowed => imgui.MouseCursor_NotAllowed,
+            Self::ResizeAll => imgui.MouseCursor_ResizeAll,
+            Self::ResizeEW => imgui.MouseCursor_ResizeEW,
+            Self::ResizeNESW => imgui.MouseCursor_ResizeNESW,
+            Self::ResizeNS => imgui.MouseCursor_ResizeNS,
+            Self::ResizeNWSE => imgui.MouseCursor_ResizeNWSE,
+            Self::TextInput => imgui.MouseCursor_TextInput,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "MouseCursor_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for MouseCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Mouse button (`MouseButton_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+}
+impl MouseButton {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[Self::Left, Self::Middle, Self::Right];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Left => "Left",
+            Self::Middle => "Middle",
+            Self::Right => "Right",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Left => imgui.MouseButton_Left,
+            Self::Middle => imgui.MouseButton_Middle,
+            Self::Right => imgui.MouseButton_Right,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "MouseButton_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for MouseButton {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Table background to color (`TableBgTarget_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableBgTarget {
+    CellBg,
+    None,
+    RowBg0,
+    RowBg1,
+}
+impl TableBgTarget {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[Self::CellBg, Self::None, Self::RowBg0, Self::RowBg1];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CellBg => "CellBg",
+            Self::None => "None",
+            Self::RowBg0 => "RowBg0",
+            Self::RowBg1 => "RowBg1",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::CellBg => imgui.TableBgTarget_CellBg,
+            Self::None => imgui.TableBgTarget_None,
+            Self::RowBg0 => imgui.TableBgTarget_RowBg0,
+            Self::RowBg1 => imgui.TableBgTarget_RowBg1,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "TableBgTarget_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for TableBgTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Table sort direction (`SortDirection_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+    None,
+}
+impl SortDirection {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[Self::Ascending, Self::Descending, Self::None];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Ascending => "Ascending",
+            Self::Descending => "Descending",
+            Self::None => "None",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Ascending => imgui.SortDirection_Ascending,
+            Self::Descending => imgui.SortDirection_Descending,
+            Self::None => imgui.SortDirection_None,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "SortDirection_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+#[doc = "Keyboard key or mouse input (`Key_*`)."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Alpha0,
+    Alpha1,
+    Alpha2,
+    Alpha3,
+    Alpha4,
+    Alpha5,
+    Alpha6,
+    Alpha7,
+    Alpha8,
+    Alpha9,
+    A,
+    Apostrophe,
+    B,
+    Backslash,
+    Backspace,
+    C,
+    CapsLock,
+    Comma,
+    D,
+    Delete,
+    DownArrow,
+    E,
+    End,
+    Enter,
+    Equal,
+    Escape,
+    F,
+    F1,
+    F10,
+    F11,
+    F12,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    G,
+    GraveAccent,
+    H,
+    Home,
+    I,
+    Insert,
+    J,
+    K,
+    Keypad0,
+    Keypad1,
+    Keypad2,
+    Keypad3,
+    Keypad4,
+    Keypad5,
+    Keypad6,
+    Keypad7,
+    Keypad8,
+    Keypad9,
+    KeypadAdd,
+    KeypadDecimal,
+    KeypadDivide,
+    KeypadEnter,
+    KeypadEqual,
+    KeypadMultiply,
+    KeypadSubtract,
+    L,
+    LeftAlt,
+    LeftArrow,
+    LeftBracket,
+    LeftCtrl,
+    LeftShift,
+    LeftSuper,
+    M,
+    Menu,
+    Minus,
+    N,
+    NumLock,
+    O,
+    P,
+    PageDown,
+    PageUp,
+    Pause,
+    Period,
+    PrintScreen,
+    Q,
+    R,
+    RightAlt,
+    RightArrow,
+    RightBracket,
+    RightCtrl,
+    RightShift,
+    RightSuper,
+    S,
+    ScrollLock,
+    Semicolon,
+    Slash,
+    Space,
+    T,
+    Tab,
+    U,
+    UpArrow,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    MouseLeft,
+    MouseMiddle,
+    MouseRight,
+    MouseWheelX,
+    MouseWheelY,
+    MouseX1,
+    MouseX2,
+}
+impl Key {
+    #[doc = r" All the members, in the order of declaration."]
+    pub const ALL: &'static [Self] = &[
+        Self::Alpha0,
+        Self::Alpha1,
+        Self::Alpha2,
+        Self::Alpha3,
+        Self::Alpha4,
+        Self::Alpha5,
+        Self::Alpha6,
+        Self::Alpha7,
+        Self::Alpha8,
+        Self::Alpha9,
+        Self::A,
+        Self::Apostrophe,
+        Self::B,
+        Self::Backslash,
+        Self::Backspace,
+        Self::C,
+        Self::CapsLock,
+        Self::Comma,
+        Self::D,
+        Self::Delete,
+        Self::DownArrow,
+        Self::E,
+        Self::End,
+        Self::Enter,
+        Self::Equal,
+        Self::Escape,
+        Self::F,
+        Self::F1,
+        Self::F10,
+        Self::F11,
+        Self::F12,
+        Self::F2,
+        Self::F3,
+        Self::F4,
+        Self::F5,
+        Self::F6,
+        Self::F7,
+        Self::F8,
+        Self::F9,
+        Self::G,
+        Self::GraveAccent,
+        Self::H,
+        Self::Home,
+        Self::I,
+        Self::Insert,
+        Self::J,
+        Self::K,
+        Self::Keypad0,
+        Self::Keypad1,
+        Self::Keypad2,
+        Self::Keypad3,
+        Self::Keypad4,
+        Self::Keypad5,
+        Self::Keypad6,
+        Self::Keypad7,
+        Self::Keypad8,
+        Self::Keypad9,
+        Self::KeypadAdd,
+        Self::KeypadDecimal,
+        Self::KeypadDivide,
+        Self::KeypadEnter,
+        Self::KeypadEqual,
+        Self::KeypadMultiply,
+        Self::KeypadSubtract,
+        Self::L,
+        Self::LeftAlt,
+        Self::LeftArrow,
+        Self::LeftBracket,
+        Self::LeftCtrl,
+        Self::LeftShift,
+        Self::LeftSuper,
+        Self::M,
+        Self::Menu,
+        Self::Minus,
+        Self::N,
+        Self::NumLock,
+        Self::O,
+        Self::P,
+        Self::PageDown,
+        Self::PageUp,
+        Self::Pause,
+        Self::Period,
+        Self::PrintScreen,
+        Self::Q,
+        Self::R,
+        Self::RightAlt,
+        Self::RightArrow,
+        Self::RightBracket,
+        Self::RightCtrl,
+        Self::RightShift,
+        Self::RightSuper,
+        Self::S,
+        Self::ScrollLock,
+        Self::Semicolon,
+        Self::Slash,
+        Self::Space,
+        Self::T,
+        Self::Tab,
+        Self::U,
+        Self::UpArrow,
+        Self::V,
+        Self::W,
+        Self::X,
+        Self::Y,
+        Self::Z,
+        Self::MouseLeft,
+        Self::MouseMiddle,
+        Self::MouseRight,
+        Self::MouseWheelX,
+        Self::MouseWheelY,
+        Self::MouseX1,
+        Self::MouseX2,
+    ];
+    #[doc = r" Name of the constant without the family prefix."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Alpha0 => "0",
+            Self::Alpha1 => "1",
+            Self::Alpha2 => "2",
+            Self::Alpha3 => "3",
+            Self::Alpha4 => "4",
+            Self::Alpha5 => "5",
+            Self::Alpha6 => "6",
+            Self::Alpha7 => "7",
+            Self::Alpha8 => "8",
+            Self::Alpha9 => "9",
+            Self::A => "A",
+            Self::Apostrophe => "Apostrophe",
+            Self::B => "B",
+            Self::Backslash => "Backslash",
+            Self::Backspace => "Backspace",
+            Self::C => "C",
+            Self::CapsLock => "CapsLock",
+            Self::Comma => "Comma",
+            Self::D => "D",
+            Self::Delete => "Delete",
+            Self::DownArrow => "DownArrow",
+            Self::E => "E",
+            Self::End => "End",
+            Self::Enter => "Enter",
+            Self::Equal => "Equal",
+            Self::Escape => "Escape",
+            Self::F => "F",
+            Self::F1 => "F1",
+            Self::F10 => "F10",
+            Self::F11 => "F11",
+            Self::F12 => "F12",
+            Self::F2 => "F2",
+            Self::F3 => "F3",
+            Self::F4 => "F4",
+            Self::F5 => "F5",
+            Self::F6 => "F6",
+            Self::F7 => "F7",
+            Self::F8 => "F8",
+            Self::F9 => "F9",
+            Self::G => "G",
+            Self::GraveAccent => "GraveAccent",
+            Self::H => "H",
+            Self::Home => "Home",
+            Self::I => "I",
+            Self::Insert => "Insert",
+            Self::J => "J",
+            Self::K => "K",
+            Self::Keypad0 => "Keypad0",
+            Self::Keypad1 => "Keypad1",
+            Self::Keypad2 => "Keypad2",
+            Self::Keypad3 => "Keypad3",
+            Self::Keypad4 => "Keypad4",
+            Self::Keypad5 => "Keypad5",
+            Self::Keypad6 => "Keypad6",
+            Self::Keypad7 => "Keypad7",
+            Self::Keypad8 => "Keypad8",
+            Self::Keypad9 => "Keypad9",
+            Self::KeypadAdd => "KeypadAdd",
+            Self::KeypadDecimal => "KeypadDecimal",
+            Self::KeypadDivide => "KeypadDivide",
+            Self::KeypadEnter => "KeypadEnter",
+            Self::KeypadEqual => "KeypadEqual",
+            Self::KeypadMultiply => "KeypadMultiply",
+            Self::KeypadSubtract => "KeypadSubtract",
+            Self::L => "L",
+            Self::LeftAlt => "LeftAlt",
+            Self::LeftArrow => "LeftArrow",
+            Self::LeftBracket => "LeftBracket",
+            Self::LeftCtrl => "LeftCtrl",
+            Self::LeftShift => "LeftShift",
+            Self::LeftSuper => "LeftSuper",
+            Self::M => "M",
+            Self::Menu => "Menu",
+            Self::Minus => "Minus",
+            Self::N => "N",
+            Self::NumLock => "NumLock",
+            Self::O => "O",
+            Self::P => "P",
+            Self::PageDown => "PageDown",
+            Self::PageUp => "PageUp",
+            Self::Pause => "Pause",
+            Self::Period => "Period",
+            Self::PrintScreen => "PrintScreen",
+            Self::Q => "Q",
+            Self::R => "R",
+            Self::RightAlt => "RightAlt",
+            Self::RightArrow => "RightArrow",
+            Self::RightBracket => "RightBracket",
+            Self::RightCtrl => "RightCtrl",
+            Self::RightShift => "RightShift",
+            Self::RightSuper => "RightSuper",
+            Self::S => "S",
+            Self::ScrollLock => "ScrollLock",
+            Self::Semicolon => "Semicolon",
+            Self::Slash => "Slash",
+            Self::Space => "Space",
+            Self::T => "T",
+            Self::Tab => "Tab",
+            Self::U => "U",
+            Self::UpArrow => "UpArrow",
+            Self::V => "V",
+            Self::W => "W",
+            Self::X => "X",
+            Self::Y => "Y",
+            Self::Z => "Z",
+            Self::MouseLeft => "MouseLeft",
+            Self::MouseMiddle => "MouseMiddle",
+            Self::MouseRight => "MouseRight",
+            Self::MouseWheelX => "MouseWheelX",
+            Self::MouseWheelY => "MouseWheelY",
+            Self::MouseX1 => "MouseX1",
+            Self::MouseX2 => "MouseX2",
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui, if provided."]
+    pub fn try_to_raw(self, imgui: &ImGui) -> Option<i32> {
+        match self {
+            Self::Alpha0 => imgui.Key_0,
+            Self::Alpha1 => imgui.Key_1,
+            Self::Alpha2 => imgui.Key_2,
+            Self::Alpha3 => imgui.Key_3,
+            Self::Alpha4 => imgui.Key_4,
+            Self::Alpha5 => imgui.Key_5,
+            Self::Alpha6 => imgui.Key_6,
+            Self::Alpha7 => imgui.Key_7,
+            Self::Alpha8 => imgui.Key_8,
+            Self::Alpha9 => imgui.Key_9,
+            Self::A => imgui.Key_A,
+            Self::Apostrophe => imgui.Key_Apostrophe,
+            Self::B => imgui.Key_B,
+            Self::Backslash => imgui.Key_Backslash,
+            Self::Backspace => imgui.Key_Backspace,
+            Self::C => imgui.Key_C,
+            Self::CapsLock => imgui.Key_CapsLock,
+            Self::Comma => imgui.Key_Comma,
+            Self::D => imgui.Key_D,
+            Self::Delete => imgui.Key_Delete,
+            Self::DownArrow => imgui.Key_DownArrow,
+            Self::E => imgui.Key_E,
+            Self::End => imgui.Key_End,
+            Self::Enter => imgui.Key_Enter,
+            Self::Equal => imgui.Key_Equal,
+            Self::Escape => imgui.Key_Escape,
+            Self::F => imgui.Key_F,
+            Self::F1 => imgui.Key_F1,
+            Self::F10 => imgui.Key_F10,
+            Self::F11 => imgui.Key_F11,
+            Self::F12 => imgui.Key_F12,
+            Self::F2 => imgui.Key_F2,
+            Self::F3 => imgui.Key_F3,
+            Self::F4 => imgui.Key_F4,
+            Self::F5 => imgui.Key_F5,
+            Self::F6 => imgui.Key_F6,
+            Self::F7 => imgui.Key_F7,
+            Self::F8 => imgui.Key_F8,
+            Self::F9 => imgui.Key_F9,
+            Self::G => imgui.Key_G,
+            Self::GraveAccent => imgui.Key_GraveAccent,
+            Self::H => imgui.Key_H,
+            Self::Home => imgui.Key_Home,
+            Self::I => imgui.Key_I,
+            Self::Insert => imgui.Key_Insert,
+            Self::J => imgui.Key_J,
+            Self::K => imgui.Key_K,
+            Self::Keypad0 => imgui.Key_Keypad0,
+            Self::Keypad1 => imgui.Key_Keypad1,
+            Self::Keypad2 => imgui.Key_Keypad2,
+            Self::Keypad3 => imgui.Key_Keypad3,
+            Self::Keypad4 => imgui.Key_Keypad4,
+            Self::Keypad5 => imgui.Key_Keypad5,
+            Self::Keypad6 => imgui.Key_Keypad6,
+            Self::Keypad7 => imgui.Key_Keypad7,
+            Self::Keypad8 => imgui.Key_Keypad8,
+            Self::Keypad9 => imgui.Key_Keypad9,
+            Self::KeypadAdd => imgui.Key_KeypadAdd,
+            Self::KeypadDecimal => imgui.Key_KeypadDecimal,
+            Self::KeypadDivide => imgui.Key_KeypadDivide,
+            Self::KeypadEnter => imgui.Key_KeypadEnter,
+            Self::KeypadEqual => imgui.Key_KeypadEqual,
+            Self::KeypadMultiply => imgui.Key_KeypadMultiply,
+            Self::KeypadSubtract => imgui.Key_KeypadSubtract,
+            Self::L => imgui.Key_L,
+            Self::LeftAlt => imgui.Key_LeftAlt,
+            Self::LeftArrow => imgui.Key_LeftArrow,
+            Self::LeftBracket => imgui.Key_LeftBracket,
+            Self::LeftCtrl => imgui.Key_LeftCtrl,
+            Self::LeftShift => imgui.Key_LeftShift,
+            Self::LeftSuper => imgui.Key_LeftSuper,
+            Self::M => imgui.Key_M,
+            Self::Menu => imgui.Key_Menu,
+            Self::Minus => imgui.Key_Minus,
+            Self::N => imgui.Key_N,
+            Self::NumLock => imgui.Key_NumLock,
+            Self::O => imgui.Key_O,
+            Self::P => imgui.Key_P,
+            Self::PageDown => imgui.Key_PageDown,
+            Self::PageUp => imgui.Key_PageUp,
+            Self::Pause => imgui.Key_Pause,
+            Self::Period => imgui.Key_Period,
+            Self::PrintScreen => imgui.Key_PrintScreen,
+            Self::Q => imgui.Key_Q,
+            Self::R => imgui.Key_R,
+            Self::RightAlt => imgui.Key_RightAlt,
+            Self::RightArrow => imgui.Key_RightArrow,
+            Self::RightBracket => imgui.Key_RightBracket,
+            Self::RightCtrl => imgui.Key_RightCtrl,
+            Self::RightShift => imgui.Key_RightShift,
+            Self::RightSuper => imgui.Key_RightSuper,
+            Self::S => imgui.Key_S,
+            Self::ScrollLock => imgui.Key_ScrollLock,
+            Self::Semicolon => imgui.Key_Semicolon,
+            Self::Slash => imgui.Key_Slash,
+            Self::Space => imgui.Key_Space,
+            Self::T => imgui.Key_T,
+            Self::Tab => imgui.Key_Tab,
+            Self::U => imgui.Key_U,
+            Self::UpArrow => imgui.Key_UpArrow,
+            Self::V => imgui.Key_V,
+            Self::W => imgui.Key_W,
+            Self::X => imgui.Key_X,
+            Self::Y => imgui.Key_Y,
+            Self::Z => imgui.Key_Z,
+            Self::MouseLeft => imgui.Key_MouseLeft,
+            Self::MouseMiddle => imgui.Key_MouseMiddle,
+            Self::MouseRight => imgui.Key_MouseRight,
+            Self::MouseWheelX => imgui.Key_MouseWheelX,
+            Self::MouseWheelY => imgui.Key_MouseWheelY,
+            Self::MouseX1 => imgui.Key_MouseX1,
+            Self::MouseX2 => imgui.Key_MouseX2,
+        }
+    }
+    #[doc = r" Value of the constant in the loaded ReaImGui."]
+    #[doc = r""]
+    #[doc = r" Panics if the installed ReaImGui does not provide it."]
+    pub fn to_raw(self, imgui: &ImGui) -> i32 {
+        match self.try_to_raw(imgui) {
+            Some(value) => value,
+            None => panic!(
+                "Attempt to use a constant that has not been loaded: {}{}",
+                "Key_",
+                self.name()
+            ),
+        }
+    }
+    #[doc = r" Member with the runtime value, if any."]
+    pub fn from_raw(imgui: &ImGui, raw: i32) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|member| member.try_to_raw(imgui) == Some(raw))
+    }
+}
+impl std::fmt::Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+crate::flags::flags! { # [doc = "Combination of `ButtonFlags_*`."] ButtonFlags { MOUSE_BUTTON_LEFT = ButtonFlags_MouseButtonLeft , MOUSE_BUTTON_MIDDLE = ButtonFlags_MouseButtonMiddle , MOUSE_BUTTON_RIGHT = ButtonFlags_MouseButtonRight , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `ColorEditFlags_*`."] ColorEditFlags { NO_ALPHA = ColorEditFlags_NoAlpha , NO_BORDER = ColorEditFlags_NoBorder , NO_DRAG_DROP = ColorEditFlags_NoDragDrop , NO_INPUTS = ColorEditFlags_NoInputs , NO_LABEL = ColorEditFlags_NoLabel , NO_OPTIONS = ColorEditFlags_NoOptions , NO_PICKER = ColorEditFlags_NoPicker , NO_SIDE_PREVIEW = ColorEditFlags_NoSidePreview , NO_SMALL_PREVIEW = ColorEditFlags_NoSmallPreview , NO_TOOLTIP = ColorEditFlags_NoTooltip , ALPHA_BAR = ColorEditFlags_AlphaBar , ALPHA_PREVIEW = ColorEditFlags_AlphaPreview , ALPHA_PREVIEW_HALF = ColorEditFlags_AlphaPreviewHalf , DISPLAY_HSV = ColorEditFlags_DisplayHSV , DISPLAY_HEX = ColorEditFlags_DisplayHex , DISPLAY_RGB = ColorEditFlags_DisplayRGB , FLOAT = ColorEditFlags_Float , INPUT_HSV = ColorEditFlags_InputHSV , INPUT_RGB = ColorEditFlags_InputRGB , PICKER_HUE_BAR = ColorEditFlags_PickerHueBar , PICKER_HUE_WHEEL = ColorEditFlags_PickerHueWheel , UINT8 = ColorEditFlags_Uint8 , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `ComboFlags_*`."] ComboFlags { HEIGHT_LARGE = ComboFlags_HeightLarge , HEIGHT_LARGEST = ComboFlags_HeightLargest , HEIGHT_REGULAR = ComboFlags_HeightRegular , HEIGHT_SMALL = ComboFlags_HeightSmall , NO_ARROW_BUTTON = ComboFlags_NoArrowButton , NO_PREVIEW = ComboFlags_NoPreview , POPUP_ALIGN_LEFT = ComboFlags_PopupAlignLeft , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `ConfigFlags_*`."] ConfigFlags { DOCKING_ENABLE = ConfigFlags_DockingEnable , NAV_ENABLE_KEYBOARD = ConfigFlags_NavEnableKeyboard , NAV_ENABLE_SET_MOUSE_POS = ConfigFlags_NavEnableSetMousePos , NAV_NO_CAPTURE_KEYBOARD = ConfigFlags_NavNoCaptureKeyboard , NO_MOUSE = ConfigFlags_NoMouse , NO_MOUSE_CURSOR_CHANGE = ConfigFlags_NoMouseCursorChange , NO_SAVED_SETTINGS = ConfigFlags_NoSavedSettings , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `DragDropFlags_*`."] DragDropFlags { ACCEPT_BEFORE_DELIVERY = DragDropFlags_AcceptBeforeDelivery , ACCEPT_NO_DRAW_DEFAULT_RECT = DragDropFlags_AcceptNoDrawDefaultRect , ACCEPT_NO_PREVIEW_TOOLTIP = DragDropFlags_AcceptNoPreviewTooltip , SOURCE_ALLOW_NULL_ID = DragDropFlags_SourceAllowNullID , SOURCE_AUTO_EXPIRE_PAYLOAD = DragDropFlags_SourceAutoExpirePayload , SOURCE_EXTERN = DragDropFlags_SourceExtern , SOURCE_NO_DISABLE_HOVER = DragDropFlags_SourceNoDisableHover , SOURCE_NO_HOLD_TO_OPEN_OTHERS = DragDropFlags_SourceNoHoldToOpenOthers , SOURCE_NO_PREVIEW_TOOLTIP = DragDropFlags_SourceNoPreviewTooltip , } fields { } composites { ACCEPT_PEEK_ONLY = DragDropFlags_AcceptPeekOnly (ACCEPT_BEFORE_DELIVERY | ACCEPT_NO_DRAW_DEFAULT_RECT) , } }
+crate::flags::flags! { # [doc = "Combination of `DrawFlags_*`."] DrawFlags { CLOSED = DrawFlags_Closed , ROUND_CORNERS_BOTTOM_LEFT = DrawFlags_RoundCornersBottomLeft , ROUND_CORNERS_BOTTOM_RIGHT = DrawFlags_RoundCornersBottomRight , ROUND_CORNERS_NONE = DrawFlags_RoundCornersNone , ROUND_CORNERS_TOP_LEFT = DrawFlags_RoundCornersTopLeft , ROUND_CORNERS_TOP_RIGHT = DrawFlags_RoundCornersTopRight , } fields { } composites { ROUND_CORNERS_ALL = DrawFlags_RoundCornersAll (ROUND_CORNERS_TOP_LEFT | ROUND_CORNERS_TOP_RIGHT | ROUND_CORNERS_BOTTOM_LEFT | ROUND_CORNERS_BOTTOM_RIGHT) , ROUND_CORNERS_BOTTOM = DrawFlags_RoundCornersBottom (ROUND_CORNERS_BOTTOM_LEFT | ROUND_CORNERS_BOTTOM_RIGHT) , ROUND_CORNERS_LEFT = DrawFlags_RoundCornersLeft (ROUND_CORNERS_TOP_LEFT | ROUND_CORNERS_BOTTOM_LEFT) , ROUND_CORNERS_RIGHT = DrawFlags_RoundCornersRight (ROUND_CORNERS_TOP_RIGHT | ROUND_CORNERS_BOTTOM_RIGHT) , ROUND_CORNERS_TOP = DrawFlags_RoundCornersTop (ROUND_CORNERS_TOP_LEFT | ROUND_CORNERS_TOP_RIGHT) , } }
+crate::flags::flags! { # [doc = "Combination of `FocusedFlags_*`."] FocusedFlags { ANY_WINDOW = FocusedFlags_AnyWindow , CHILD_WINDOWS = FocusedFlags_ChildWindows , DOCK_HIERARCHY = FocusedFlags_DockHierarchy , NO_POPUP_HIERARCHY = FocusedFlags_NoPopupHierarchy , ROOT_WINDOW = FocusedFlags_RootWindow , } fields { } composites { ROOT_AND_CHILD_WINDOWS = FocusedFlags_RootAndChildWindows (ROOT_WINDOW | CHILD_WINDOWS) , } }
+crate::flags::flags! { # [doc = "Combination of `FontFlags_*`."] FontFlags { BOLD = FontFlags_Bold , ITALIC = FontFlags_Italic , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `HoveredFlags_*`."] HoveredFlags { ALLOW_WHEN_BLOCKED_BY_ACTIVE_ITEM = HoveredFlags_AllowWhenBlockedByActiveItem , ALLOW_WHEN_BLOCKED_BY_POPUP = HoveredFlags_AllowWhenBlockedByPopup , DELAY_NORMAL = HoveredFlags_DelayNormal , DELAY_SHORT = HoveredFlags_DelayShort , NO_NAV_OVERRIDE = HoveredFlags_NoNavOverride , NO_SHARED_DELAY = HoveredFlags_NoSharedDelay , ALLOW_WHEN_DISABLED = HoveredFlags_AllowWhenDisabled , ALLOW_WHEN_OVERLAPPED = HoveredFlags_AllowWhenOverlapped , ANY_WINDOW = HoveredFlags_AnyWindow , CHILD_WINDOWS = HoveredFlags_ChildWindows , DOCK_HIERARCHY = HoveredFlags_DockHierarchy , NO_POPUP_HIERARCHY = HoveredFlags_NoPopupHierarchy , ROOT_WINDOW = HoveredFlags_RootWindow , } fields { } composites { RECT_ONLY = HoveredFlags_RectOnly (ALLOW_WHEN_BLOCKED_BY_POPUP | ALLOW_WHEN_BLOCKED_BY_ACTIVE_ITEM | ALLOW_WHEN_OVERLAPPED) , ROOT_AND_CHILD_WINDOWS = HoveredFlags_RootAndChildWindows (ROOT_WINDOW | CHILD_WINDOWS) , } }
+crate::flags::flags! { # [doc = "Combination of `InputTextFlags_*`."] InputTextFlags { ALLOW_TAB_INPUT = InputTextFlags_AllowTabInput , ALWAYS_OVERWRITE = InputTextFlags_AlwaysOverwrite , AUTO_SELECT_ALL = InputTextFlags_AutoSelectAll , CHARS_DECIMAL = InputTextFlags_CharsDecimal , CHARS_HEXADECIMAL = InputTextFlags_CharsHexadecimal , CHARS_NO_BLANK = InputTextFlags_CharsNoBlank , CHARS_SCIENTIFIC = InputTextFlags_CharsScientific , CHARS_UPPERCASE = InputTextFlags_CharsUppercase , CTRL_ENTER_FOR_NEW_LINE = InputTextFlags_CtrlEnterForNewLine , ENTER_RETURNS_TRUE = InputTextFlags_EnterReturnsTrue , ESCAPE_CLEARS_ALL = InputTextFlags_EscapeClearsAll , NO_HORIZONTAL_SCROLL = InputTextFlags_NoHorizontalScroll , NO_UNDO_REDO = InputTextFlags_NoUndoRedo , PASSWORD = InputTextFlags_Password , READ_ONLY = InputTextFlags_ReadOnly , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `PopupFlags_*`."] PopupFlags { NO_OPEN_OVER_EXISTING_POPUP = PopupFlags_NoOpenOverExistingPopup , NO_OPEN_OVER_ITEMS = PopupFlags_NoOpenOverItems , ANY_POPUP_ID = PopupFlags_AnyPopupId , ANY_POPUP_LEVEL = PopupFlags_AnyPopupLevel , } fields { [MOUSE_BUTTON_LEFT = PopupFlags_MouseButtonLeft , MOUSE_BUTTON_MIDDLE = PopupFlags_MouseButtonMiddle , MOUSE_BUTTON_RIGHT = PopupFlags_MouseButtonRight ,] } composites { ANY_POPUP = PopupFlags_AnyPopup (ANY_POPUP_ID | ANY_POPUP_LEVEL) , } }
+crate::flags::flags! { # [doc = "Combination of `SelectableFlags_*`."] SelectableFlags { ALLOW_DOUBLE_CLICK = SelectableFlags_AllowDoubleClick , ALLOW_ITEM_OVERLAP = SelectableFlags_AllowItemOverlap , DISABLED = SelectableFlags_Disabled , DONT_CLOSE_POPUPS = SelectableFlags_DontClosePopups , SPAN_ALL_COLUMNS = SelectableFlags_SpanAllColumns , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `SliderFlags_*`."] SliderFlags { ALWAYS_CLAMP = SliderFlags_AlwaysClamp , LOGARITHMIC = SliderFlags_Logarithmic , NO_INPUT = SliderFlags_NoInput , NO_ROUND_TO_FORMAT = SliderFlags_NoRoundToFormat , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `TabBarFlags_*`."] TabBarFlags { AUTO_SELECT_NEW_TABS = TabBarFlags_AutoSelectNewTabs , FITTING_POLICY_RESIZE_DOWN = TabBarFlags_FittingPolicyResizeDown , FITTING_POLICY_SCROLL = TabBarFlags_FittingPolicyScroll , NO_CLOSE_WITH_MIDDLE_MOUSE_BUTTON = TabBarFlags_NoCloseWithMiddleMouseButton , NO_TAB_LIST_SCROLLING_BUTTONS = TabBarFlags_NoTabListScrollingButtons , NO_TOOLTIP = TabBarFlags_NoTooltip , REORDERABLE = TabBarFlags_Reorderable , TAB_LIST_POPUP_BUTTON = TabBarFlags_TabListPopupButton , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `TabItemFlags_*`."] TabItemFlags { LEADING = TabItemFlags_Leading , NO_CLOSE_WITH_MIDDLE_MOUSE_BUTTON = TabItemFlags_NoCloseWithMiddleMouseButton , NO_PUSH_ID = TabItemFlags_NoPushId , NO_REORDER = TabItemFlags_NoReorder , NO_TOOLTIP = TabItemFlags_NoTooltip , SET_SELECTED = TabItemFlags_SetSelected , TRAILING = TabItemFlags_Trailing , UNSAVED_DOCUMENT = TabItemFlags_UnsavedDocument , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `TableColumnFlags_*`."] TableColumnFlags { DEFAULT_HIDE = TableColumnFlags_DefaultHide , DEFAULT_SORT = TableColumnFlags_DefaultSort , DISABLED = TableColumnFlags_Disabled , INDENT_DISABLE = TableColumnFlags_IndentDisable , INDENT_ENABLE = TableColumnFlags_IndentEnable , NO_CLIP = TableColumnFlags_NoClip , NO_HEADER_LABEL = TableColumnFlags_NoHeaderLabel , NO_HEADER_WIDTH = TableColumnFlags_NoHeaderWidth , NO_HIDE = TableColumnFlags_NoHide , NO_REORDER = TableColumnFlags_NoReorder , NO_RESIZE = TableColumnFlags_NoResize , NO_SORT = TableColumnFlags_NoSort , NO_SORT_ASCENDING = TableColumnFlags_NoSortAscending , NO_SORT_DESCENDING = TableColumnFlags_NoSortDescending , PREFER_SORT_ASCENDING = TableColumnFlags_PreferSortAscending , PREFER_SORT_DESCENDING = TableColumnFlags_PreferSortDescending , WIDTH_FIXED = TableColumnFlags_WidthFixed , WIDTH_STRETCH = TableColumnFlags_WidthStretch , IS_ENABLED = TableColumnFlags_IsEnabled , IS_HOVERED = TableColumnFlags_IsHovered , IS_SORTED = TableColumnFlags_IsSorted , IS_VISIBLE = TableColumnFlags_IsVisible , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `TableFlags_*`."] TableFlags { NO_CLIP = TableFlags_NoClip , BORDERS_INNER_H = TableFlags_BordersInnerH , BORDERS_INNER_V = TableFlags_BordersInnerV , BORDERS_OUTER_H = TableFlags_BordersOuterH , BORDERS_OUTER_V = TableFlags_BordersOuterV , ROW_BG = TableFlags_RowBg , CONTEXT_MENU_IN_BODY = TableFlags_ContextMenuInBody , HIDEABLE = TableFlags_Hideable , NO_SAVED_SETTINGS = TableFlags_NoSavedSettings , REORDERABLE = TableFlags_Reorderable , RESIZABLE = TableFlags_Resizable , SORTABLE = TableFlags_Sortable , NO_PAD_INNER_X = TableFlags_NoPadInnerX , NO_PAD_OUTER_X = TableFlags_NoPadOuterX , PAD_OUTER_X = TableFlags_PadOuterX , SCROLL_X = TableFlags_ScrollX , SCROLL_Y = TableFlags_ScrollY , NO_HOST_EXTEND_X = TableFlags_NoHostExtendX , NO_HOST_EXTEND_Y = TableFlags_NoHostExtendY , NO_KEEP_COLUMNS_VISIBLE = TableFlags_NoKeepColumnsVisible , PRECISE_WIDTHS = TableFlags_PreciseWidths , SORT_MULTI = TableFlags_SortMulti , SORT_TRISTATE = TableFlags_SortTristate , } fields { [SIZING_FIXED_FIT = TableFlags_SizingFixedFit , SIZING_FIXED_SAME = TableFlags_SizingFixedSame , SIZING_STRETCH_PROP = TableFlags_SizingStretchProp , SIZING_STRETCH_SAME = TableFlags_SizingStretchSame ,] } composites { BORDERS = TableFlags_Borders (BORDERS_INNER_H | BORDERS_OUTER_H | BORDERS_INNER_V | BORDERS_OUTER_V) , BORDERS_H = TableFlags_BordersH (BORDERS_INNER_H | BORDERS_OUTER_H) , BORDERS_INNER = TableFlags_BordersInner (BORDERS_INNER_V | BORDERS_INNER_H) , BORDERS_OUTER = TableFlags_BordersOuter (BORDERS_OUTER_V | BORDERS_OUTER_H) , BORDERS_V = TableFlags_BordersV (BORDERS_INNER_V | BORDERS_OUTER_V) , } }
+crate::flags::flags! { # [doc = "Combination of `TableRowFlags_*`."] TableRowFlags { HEADERS = TableRowFlags_Headers , } fields { } composites { } }
+crate::flags::flags! { # [doc = "Combination of `TreeNodeFlags_*`."] TreeNodeFlags { ALLOW_ITEM_OVERLAP = TreeNodeFlags_AllowItemOverlap , BULLET = TreeNodeFlags_Bullet , DEFAULT_OPEN = TreeNodeFlags_DefaultOpen , FRAME_PADDING = TreeNodeFlags_FramePadding , FRAMED = TreeNodeFlags_Framed , LEAF = TreeNodeFlags_Leaf , NO_AUTO_OPEN_ON_LOG = TreeNodeFlags_NoAutoOpenOnLog , NO_TREE_PUSH_ON_OPEN = TreeNodeFlags_NoTreePushOnOpen , OPEN_ON_ARROW = TreeNodeFlags_OpenOnArrow , OPEN_ON_DOUBLE_CLICK = TreeNodeFlags_OpenOnDoubleClick , SELECTED = TreeNodeFlags_Selected , SPAN_AVAIL_WIDTH = TreeNodeFlags_SpanAvailWidth , SPAN_FULL_WIDTH = TreeNodeFlags_SpanFullWidth , } fields { } composites { COLLAPSING_HEADER = TreeNodeFlags_CollapsingHeader (FRAMED | NO_TREE_PUSH_ON_OPEN | NO_AUTO_OPEN_ON_LOG) , } }
+crate::flags::flags! { # [doc = "Combination of `WindowFlags_*`."] WindowFlags { ALWAYS_AUTO_RESIZE = WindowFlags_AlwaysAutoResize , ALWAYS_HORIZONTAL_SCROLLBAR = WindowFlags_AlwaysHorizontalScrollbar , ALWAYS_USE_WINDOW_PADDING = WindowFlags_AlwaysUseWindowPadding , ALWAYS_VERTICAL_SCROLLBAR = WindowFlags_AlwaysVerticalScrollbar , HORIZONTAL_SCROLLBAR = WindowFlags_HorizontalScrollbar , MENU_BAR = WindowFlags_MenuBar , NO_BACKGROUND = WindowFlags_NoBackground , NO_COLLAPSE = WindowFlags_NoCollapse , NO_DOCKING = WindowFlags_NoDocking , NO_FOCUS_ON_APPEARING = WindowFlags_NoFocusOnAppearing , NO_MOUSE_INPUTS = WindowFlags_NoMouseInputs , NO_MOVE = WindowFlags_NoMove , NO_NAV_FOCUS = WindowFlags_NoNavFocus , NO_NAV_INPUTS = WindowFlags_NoNavInputs , NO_RESIZE = WindowFlags_NoResize , NO_SAVED_SETTINGS = WindowFlags_NoSavedSettings , NO_SCROLL_WITH_MOUSE = WindowFlags_NoScrollWithMouse , NO_SCROLLBAR = WindowFlags_NoScrollbar , NO_TITLE_BAR = WindowFlags_NoTitleBar , TOP_MOST = WindowFlags_TopMost , UNSAVED_DOCUMENT = WindowFlags_UnsavedDocument , } fields { } composites { NO_DECORATION = WindowFlags_NoDecoration (NO_TITLE_BAR | NO_RESIZE | NO_SCROLLBAR | NO_COLLAPSE) , NO_INPUTS = WindowFlags_NoInputs (NO_MOUSE_INPUTS | NO_NAV_INPUTS | NO_NAV_FOCUS) , NO_NAV = WindowFlags_NoNav (NO_NAV_INPUTS | NO_NAV_FOCUS) , } }
+crate::flags::flags! { # [doc = "Combination of key modifiers (`Mod_*`)."] Mods { ALT = Mod_Alt , CTRL = Mod_Ctrl , SHIFT = Mod_Shift , SHORTCUT = Mod_Shortcut , SUPER = Mod_Super , } fields { } composites { } }
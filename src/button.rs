@@ -0,0 +1,253 @@
+//! Buttons: regular, small, arrow, invisible and image ones.
+//!
+//! Every kind is built with [`Button::build`], which returns `true` when the
+//! button is clicked. Options only exist for the kinds supporting them.
+//!
+//! ```no_run
+//! # use reaper_imgui::{ButtonFlags, Dir, Ui};
+//! # fn f(ui: &Ui, value: &mut i32) {
+//! if ui.button("Reset").size((80.0, 0.0)).build() {
+//!     *value = 0;
+//! }
+//! // Reports clicks repeatedly while held.
+//! if ui.arrow_button("##down", Dir::Down).repeat(true).build() {
+//!     *value -= 1;
+//! }
+//! ui.same_line();
+//! if ui.small_button("+1").repeat(true).build() {
+//!     *value += 1;
+//! }
+//! let area = ui
+//!     .invisible_button("canvas", (200.0, 100.0))
+//!     .flags(ButtonFlags::MOUSE_BUTTON_LEFT | ButtonFlags::MOUSE_BUTTON_RIGHT)
+//!     .build();
+//! # }
+//! ```
+
+use std::ffi::c_char;
+
+use crate::{
+    scratch::{opt_ptr, vec2_ptrs, ImStr},
+    ButtonFlags, Color, Dir, Image, Ui, Vec2,
+};
+
+/// Builder of a button, see [`Ui::button`].
+#[must_use = "call .build() to draw the button"]
+pub struct Button<'ui, L, K> {
+    ui: &'ui Ui<'ui>,
+    label: L,
+    kind: K,
+    repeat: bool,
+}
+impl<'ui, L: ImStr, K> Button<'ui, L, K> {
+    /// Report clicks repeatedly while the button is held, see
+    /// [`Ui::push_button_repeat`].
+    pub fn repeat(mut self, repeat: bool) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    fn press(self, f: impl FnOnce(&Ui, *const c_char, K) -> bool) -> bool {
+        let _repeat = self.repeat.then(|| self.ui.push_button_repeat(true));
+        self.ui
+            .with_str(&self.label, |label| f(self.ui, label, self.kind))
+    }
+}
+
+/// Regular button, see [`Ui::button`].
+#[derive(Debug, Clone, Copy)]
+pub struct Regular {
+    size: Option<Vec2>,
+}
+impl<'ui, L: ImStr> Button<'ui, L, Regular> {
+    /// Zero for an axis fits the label, negative aligns the edge to that
+    /// distance from the window edge.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.kind.size = Some(size.into());
+        self
+    }
+
+    pub fn build(self) -> bool {
+        self.press(|ui, label, kind| {
+            let (mut width, mut height) = match kind.size {
+                Some(size) => (Some(size.x), Some(size.y)),
+                None => (None, None),
+            };
+            unsafe {
+                ui.imgui().Button(
+                    ui.raw_context(),
+                    label,
+                    opt_ptr(width.as_mut()),
+                    opt_ptr(height.as_mut()),
+                )
+            }
+        })
+    }
+}
+
+/// Button without frame padding, see [`Ui::small_button`].
+#[derive(Debug, Clone, Copy)]
+pub struct Small;
+impl<'ui, L: ImStr> Button<'ui, L, Small> {
+    pub fn build(self) -> bool {
+        self.press(|ui, label, _| unsafe { ui.imgui().SmallButton(ui.raw_context(), label) })
+    }
+}
+
+/// Square button with an arrow, see [`Ui::arrow_button`].
+#[derive(Debug, Clone, Copy)]
+pub struct Arrow {
+    dir: Dir,
+}
+impl<'ui, L: ImStr> Button<'ui, L, Arrow> {
+    pub fn build(self) -> bool {
+        self.press(|ui, str_id, kind| unsafe {
+            ui.imgui()
+                .ArrowButton(ui.raw_context(), str_id, kind.dir.to_raw(ui.imgui()))
+        })
+    }
+}
+
+/// Button behavior without visuals, see [`Ui::invisible_button`].
+#[derive(Debug, Clone, Copy)]
+pub struct Invisible {
+    size: Vec2,
+    flags: ButtonFlags,
+}
+impl<'ui, L: ImStr> Button<'ui, L, Invisible> {
+    /// Mouse buttons reacted to, the left one by default.
+    pub fn flags(mut self, flags: ButtonFlags) -> Self {
+        self.kind.flags = flags;
+        self
+    }
+
+    pub fn build(self) -> bool {
+        self.press(|ui, str_id, kind| {
+            let mut flags = kind.flags.to_raw(ui.imgui());
+            unsafe {
+                ui.imgui().InvisibleButton(
+                    ui.raw_context(),
+                    str_id,
+                    kind.size.x,
+                    kind.size.y,
+                    &mut flags,
+                )
+            }
+        })
+    }
+}
+
+/// Button showing an image, see [`Ui::image_button`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImageButton {
+    image: Image,
+    size: Vec2,
+    uv: Option<(Vec2, Vec2)>,
+    bg_color: Option<Color>,
+    tint: Option<Color>,
+}
+impl<'ui, L: ImStr> Button<'ui, L, ImageButton> {
+    /// Part of the image in normalized coordinates, `(0, 0)` to `(1, 1)` by
+    /// default.
+    pub fn uv(mut self, uv0: impl Into<Vec2>, uv1: impl Into<Vec2>) -> Self {
+        self.kind.uv = Some((uv0.into(), uv1.into()));
+        self
+    }
+
+    /// Color under the image, transparent by default.
+    pub fn bg_color(mut self, color: impl Into<Color>) -> Self {
+        self.kind.bg_color = Some(color.into());
+        self
+    }
+
+    /// Color multiplied with the image, white by default.
+    pub fn tint(mut self, color: impl Into<Color>) -> Self {
+        self.kind.tint = Some(color.into());
+        self
+    }
+
+    pub fn build(self) -> bool {
+        self.press(|ui, str_id, kind| {
+            let (mut uv0, mut uv1) = match kind.uv {
+                Some((uv0, uv1)) => (Some(uv0), Some(uv1)),
+                None => (None, None),
+            };
+            let (uv0_x, uv0_y) = vec2_ptrs(&mut uv0);
+            let (uv1_x, uv1_y) = vec2_ptrs(&mut uv1);
+            let mut bg_color = kind.bg_color.map(|color| color.to_rgba() as i32);
+            let mut tint = kind.tint.map(|color| color.to_rgba() as i32);
+            unsafe {
+                ui.imgui().ImageButton(
+                    ui.raw_context(),
+                    str_id,
+                    kind.image,
+                    kind.size.x,
+                    kind.size.y,
+                    uv0_x,
+                    uv0_y,
+                    uv1_x,
+                    uv1_y,
+                    opt_ptr(bg_color.as_mut()),
+                    opt_ptr(tint.as_mut()),
+                )
+            }
+        })
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    fn button_of<L: ImStr, K>(&self, label: L, kind: K) -> Button<'_, L, K> {
+        Button {
+            ui: self,
+            label,
+            kind,
+            repeat: false,
+        }
+    }
+
+    /// Button fitting the label.
+    pub fn button<L: ImStr>(&self, label: L) -> Button<'_, L, Regular> {
+        self.button_of(label, Regular { size: None })
+    }
+
+    /// Button without frame padding, to embed within text.
+    pub fn small_button<L: ImStr>(&self, label: L) -> Button<'_, L, Small> {
+        self.button_of(label, Small)
+    }
+
+    /// Square button with an arrow of the direction.
+    pub fn arrow_button<L: ImStr>(&self, str_id: L, dir: Dir) -> Button<'_, L, Arrow> {
+        self.button_of(str_id, Arrow { dir })
+    }
+
+    /// Button behavior without visuals, e.g. for a custom widget drawn over
+    /// it. The size must not be zero.
+    pub fn invisible_button<L: ImStr>(
+        &self,
+        str_id: L,
+        size: impl Into<Vec2>,
+    ) -> Button<'_, L, Invisible> {
+        let kind = Invisible {
+            size: size.into(),
+            flags: ButtonFlags::empty(),
+        };
+        self.button_of(str_id, kind)
+    }
+
+    /// Button showing the image, which must be attached to the context.
+    pub fn image_button<L: ImStr>(
+        &self,
+        str_id: L,
+        image: Image,
+        size: impl Into<Vec2>,
+    ) -> Button<'_, L, ImageButton> {
+        let kind = ImageButton {
+            image,
+            size: size.into(),
+            uv: None,
+            bg_color: None,
+            tint: None,
+        };
+        self.button_of(str_id, kind)
+    }
+}
@@ -0,0 +1,1003 @@
+//! Debug wrapper that checks balancing of Begin/End and Push/Pop pairs.
+//!
+//! ReaImGui aborts the whole context with a generic message when, for
+//! example, `PushStyleColor` is not followed by `PopStyleColor`. [`CheckedImGui`]
+//! mirrors the raw paired functions of [`ImGui`], remembers every opening call
+//! per [`Context`] together with its source location and reports the exact
+//! offender at [`CheckedImGui::end_frame`].
+//!
+//! All other functions are reachable through `Deref<Target = ImGui>`.
+//!
+//! ```no_run
+//! # use reaper_imgui::{CheckedImGui, Context, ImGui};
+//! # use c_str_macro::c_str;
+//! # fn frame(imgui: &CheckedImGui, ctx: Context) {
+//! unsafe {
+//!     if imgui.Begin(ctx, c_str!("window").as_ptr(), std::ptr::null_mut(), std::ptr::null_mut()) {
+//!         imgui.PushID(ctx, c_str!("row").as_ptr());
+//!         imgui.Text(ctx, c_str!("forgot to pop").as_ptr());
+//!     }
+//!     imgui.End(ctx);
+//! }
+//! if let Err(report) = imgui.end_frame(ctx) {
+//!     eprintln!("{}", report);
+//! }
+//! # }
+//! ```
+//!
+//! # Safety
+//!
+//! Wrapped functions have the same contracts as their [`ImGui`] counterparts.
+#![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(clippy::too_many_arguments)]
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    ffi::{c_char, c_int, CStr},
+    fmt,
+    ops::Deref,
+    panic::Location,
+};
+
+use crate::{Col, Context, Font, ImGui, StyleVar};
+
+/// Kind of a stack that must be balanced within a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StackKind {
+    Window,
+    Child,
+    ChildFrame,
+    Group,
+    Table,
+    TabBar,
+    TabItem,
+    Combo,
+    ListBox,
+    Menu,
+    MenuBar,
+    Popup,
+    Tooltip,
+    TreeNode,
+    Disabled,
+    DragDropSource,
+    DragDropTarget,
+    StyleColor,
+    StyleVar,
+    Id,
+    Font,
+    ItemWidth,
+    TextWrapPos,
+    AllowKeyboardFocus,
+    ButtonRepeat,
+    ClipRect,
+}
+impl StackKind {
+    /// Whether the kind is a scope, that can contain other scopes, like a
+    /// window or a table. Other kinds, like style colors, are plain value
+    /// stacks.
+    pub fn is_scope(&self) -> bool {
+        !matches!(
+            self,
+            Self::StyleColor
+                | Self::StyleVar
+                | Self::Id
+                | Self::Font
+                | Self::ItemWidth
+                | Self::TextWrapPos
+                | Self::AllowKeyboardFocus
+                | Self::ButtonRepeat
+                | Self::ClipRect
+        )
+    }
+
+    /// Function that closes the stack entry.
+    pub fn closing_function(&self) -> &'static str {
+        match self {
+            Self::Window => "End",
+            Self::Child => "EndChild",
+            Self::ChildFrame => "EndChildFrame",
+            Self::Group => "EndGroup",
+            Self::Table => "EndTable",
+            Self::TabBar => "EndTabBar",
+            Self::TabItem => "EndTabItem",
+            Self::Combo => "EndCombo",
+            Self::ListBox => "EndListBox",
+            Self::Menu => "EndMenu",
+            Self::MenuBar => "EndMenuBar",
+            Self::Popup => "EndPopup",
+            Self::Tooltip => "EndTooltip",
+            Self::TreeNode => "TreePop",
+            Self::Disabled => "EndDisabled",
+            Self::DragDropSource => "EndDragDropSource",
+            Self::DragDropTarget => "EndDragDropTarget",
+            Self::StyleColor => "PopStyleColor",
+            Self::StyleVar => "PopStyleVar",
+            Self::Id => "PopID",
+            Self::Font => "PopFont",
+            Self::ItemWidth => "PopItemWidth",
+            Self::TextWrapPos => "PopTextWrapPos",
+            Self::AllowKeyboardFocus => "PopAllowKeyboardFocus",
+            Self::ButtonRepeat => "PopButtonRepeat",
+            Self::ClipRect => "PopClipRect",
+        }
+    }
+}
+
+/// Opening call remembered by [`CheckedImGui`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackEntry {
+    pub kind: StackKind,
+    /// Name of the opening function, e.g. `PushStyleColor`.
+    pub function: &'static str,
+    /// Label, id or index given to the opening function, if any.
+    pub label: Option<String>,
+    pub location: &'static Location<'static>,
+}
+impl fmt::Display for StackEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.function)?;
+        if let Some(label) = &self.label {
+            write!(f, "({:?})", label)?;
+        }
+        write!(f, " at {}", self.location)
+    }
+}
+
+/// Single balancing problem found during a frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackError {
+    /// Opening call that was never closed.
+    Unmatched(StackEntry),
+    /// Closing call without a corresponding opening one. It is still
+    /// forwarded to ReaImGui, which reports it on its own.
+    UnexpectedClose {
+        kind: StackKind,
+        location: &'static Location<'static>,
+    },
+}
+impl fmt::Display for StackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unmatched(entry) => write!(
+                f,
+                "{} is not matched by {}",
+                entry,
+                entry.kind.closing_function()
+            ),
+            Self::UnexpectedClose { kind, location } => write!(
+                f,
+                "{} at {} has nothing to close",
+                kind.closing_function(),
+                location
+            ),
+        }
+    }
+}
+
+/// All problems of a single context, returned by [`CheckedImGui::end_frame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackReport {
+    pub context: Context,
+    pub errors: Vec<StackError>,
+}
+impl fmt::Display for StackReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unbalanced ImGui stacks in context {:?}:", self.context)?;
+        for error in self.errors.iter() {
+            write!(f, "\n    {}", error)?;
+        }
+        Ok(())
+    }
+}
+impl Error for StackReport {}
+
+/// Open entries of a single context.
+///
+/// Every kind has its own stack. Scoped kinds additionally nest within each
+/// other: closing a window reports the child windows, tables, etc. left open
+/// inside it. Value stacks, like style colors or IDs, are independent of the
+/// scopes.
+#[derive(Debug, Default)]
+struct ContextStacks {
+    stacks: BTreeMap<StackKind, Vec<StackEntry>>,
+    /// Open scoped kinds, innermost last.
+    scopes: Vec<StackKind>,
+    errors: Vec<StackError>,
+}
+impl ContextStacks {
+    #[track_caller]
+    fn open(&mut self, kind: StackKind, function: &'static str, label: Option<String>) {
+        self.stacks.entry(kind).or_default().push(StackEntry {
+            kind,
+            function,
+            label,
+            location: Location::caller(),
+        });
+        if kind.is_scope() {
+            self.scopes.push(kind);
+        }
+    }
+
+    /// Returns `false` if there is nothing to close.
+    ///
+    /// Scopes opened after the closed one are reported as unmatched.
+    #[track_caller]
+    fn close(&mut self, kind: StackKind) -> bool {
+        let is_open = match kind.is_scope() {
+            true => match self.scopes.iter().rposition(|k| *k == kind) {
+                None => false,
+                Some(idx) => {
+                    for inner in self.scopes.drain(idx + 1..).rev().collect::<Vec<_>>() {
+                        let entry = self.pop(inner);
+                        self.errors.extend(entry.map(StackError::Unmatched));
+                    }
+                    self.scopes.pop();
+                    true
+                }
+            },
+            false => self.stacks.get(&kind).is_some_and(|s| !s.is_empty()),
+        };
+        match is_open {
+            true => {
+                self.pop(kind);
+            }
+            false => self.errors.push(StackError::UnexpectedClose {
+                kind,
+                location: Location::caller(),
+            }),
+        }
+        is_open
+    }
+
+    /// Close `count` entries, one if null. Stops at the first unexpected
+    /// close.
+    #[track_caller]
+    fn close_many(&mut self, kind: StackKind, count: *const c_int) {
+        let count = match count.is_null() {
+            true => 1,
+            false => unsafe { *count },
+        };
+        for _ in 0..count {
+            if !self.close(kind) {
+                break;
+            }
+        }
+    }
+
+    fn pop(&mut self, kind: StackKind) -> Option<StackEntry> {
+        self.stacks.get_mut(&kind).and_then(Vec::pop)
+    }
+
+    /// Problems of the frame, with everything still open.
+    fn finish(self) -> Vec<StackError> {
+        let mut errors = self.errors;
+        let unmatched = self.stacks.into_values().flatten();
+        errors.extend(unmatched.map(StackError::Unmatched));
+        errors
+    }
+}
+
+/// [`ImGui`] wrapper, that tracks paired calls per [`Context`].
+///
+/// Paired functions have the same signatures as in [`ImGui`], so it can be
+/// swapped in during debugging without touching the UI code.
+/// [`CheckedImGui::end_frame`] has to be called at the end of every frame
+/// for every used context.
+#[derive(Debug)]
+pub struct CheckedImGui {
+    imgui: ImGui,
+    stacks: RefCell<HashMap<Context, ContextStacks>>,
+}
+impl Deref for CheckedImGui {
+    type Target = ImGui;
+    fn deref(&self) -> &Self::Target {
+        &self.imgui
+    }
+}
+impl From<ImGui> for CheckedImGui {
+    fn from(imgui: ImGui) -> Self {
+        Self::new(imgui)
+    }
+}
+impl CheckedImGui {
+    pub fn new(imgui: ImGui) -> Self {
+        Self {
+            imgui,
+            stacks: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Get the wrapped API back.
+    pub fn into_inner(self) -> ImGui {
+        self.imgui
+    }
+
+    /// Finish the frame of the context and report everything left open or
+    /// closed without opening.
+    ///
+    /// The state of the context is cleared in any case.
+    pub fn end_frame(&self, ctx: Context) -> Result<(), StackReport> {
+        let stacks = match self.stacks.borrow_mut().remove(&ctx) {
+            None => return Ok(()),
+            Some(s) => s,
+        };
+        let errors = stacks.finish();
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(StackReport {
+                context: ctx,
+                errors,
+            }),
+        }
+    }
+
+    #[track_caller]
+    fn open(&self, ctx: Context, kind: StackKind, function: &'static str, label: Option<String>) {
+        let mut stacks = self.stacks.borrow_mut();
+        stacks.entry(ctx).or_default().open(kind, function, label)
+    }
+
+    /// Only records the problems: the closing call is forwarded in any case.
+    #[track_caller]
+    fn close(&self, ctx: Context, kind: StackKind) {
+        self.stacks.borrow_mut().entry(ctx).or_default().close(kind);
+    }
+
+    #[track_caller]
+    fn close_many(&self, ctx: Context, kind: StackKind, count: *const c_int) {
+        let mut stacks = self.stacks.borrow_mut();
+        stacks.entry(ctx).or_default().close_many(kind, count)
+    }
+
+    fn label(s: *const c_char) -> Option<String> {
+        match s.is_null() {
+            true => None,
+            false => Some(unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()),
+        }
+    }
+
+    #[track_caller]
+    pub unsafe fn Begin(
+        &self,
+        ctx: Context,
+        name: *const c_char,
+        p_openInOutOptional: *mut bool,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        self.open(ctx, StackKind::Window, "Begin", Self::label(name));
+        self.imgui
+            .Begin(ctx, name, p_openInOutOptional, flagsInOptional)
+    }
+    #[track_caller]
+    pub unsafe fn End(&self, ctx: Context) {
+        self.close(ctx, StackKind::Window);
+        self.imgui.End(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginChild(
+        &self,
+        ctx: Context,
+        str_id: *const c_char,
+        size_wInOptional: *mut f64,
+        size_hInOptional: *mut f64,
+        borderInOptional: *mut bool,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        self.open(ctx, StackKind::Child, "BeginChild", Self::label(str_id));
+        self.imgui.BeginChild(
+            ctx,
+            str_id,
+            size_wInOptional,
+            size_hInOptional,
+            borderInOptional,
+            flagsInOptional,
+        )
+    }
+    #[track_caller]
+    pub unsafe fn EndChild(&self, ctx: Context) {
+        self.close(ctx, StackKind::Child);
+        self.imgui.EndChild(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginChildFrame(
+        &self,
+        ctx: Context,
+        str_id: *const c_char,
+        size_w: f64,
+        size_h: f64,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        self.open(
+            ctx,
+            StackKind::ChildFrame,
+            "BeginChildFrame",
+            Self::label(str_id),
+        );
+        self.imgui
+            .BeginChildFrame(ctx, str_id, size_w, size_h, flagsInOptional)
+    }
+    #[track_caller]
+    pub unsafe fn EndChildFrame(&self, ctx: Context) {
+        self.close(ctx, StackKind::ChildFrame);
+        self.imgui.EndChildFrame(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginGroup(&self, ctx: Context) {
+        self.open(ctx, StackKind::Group, "BeginGroup", None);
+        self.imgui.BeginGroup(ctx)
+    }
+    #[track_caller]
+    pub unsafe fn EndGroup(&self, ctx: Context) {
+        self.close(ctx, StackKind::Group);
+        self.imgui.EndGroup(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginTable(
+        &self,
+        ctx: Context,
+        str_id: *const c_char,
+        column: c_int,
+        flagsInOptional: *mut c_int,
+        outer_size_wInOptional: *mut f64,
+        outer_size_hInOptional: *mut f64,
+        inner_widthInOptional: *mut f64,
+    ) -> bool {
+        let result = self.imgui.BeginTable(
+            ctx,
+            str_id,
+            column,
+            flagsInOptional,
+            outer_size_wInOptional,
+            outer_size_hInOptional,
+            inner_widthInOptional,
+        );
+        if result {
+            self.open(ctx, StackKind::Table, "BeginTable", Self::label(str_id));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndTable(&self, ctx: Context) {
+        self.close(ctx, StackKind::Table);
+        self.imgui.EndTable(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginTabBar(
+        &self,
+        ctx: Context,
+        str_id: *const c_char,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self.imgui.BeginTabBar(ctx, str_id, flagsInOptional);
+        if result {
+            self.open(ctx, StackKind::TabBar, "BeginTabBar", Self::label(str_id));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndTabBar(&self, ctx: Context) {
+        self.close(ctx, StackKind::TabBar);
+        self.imgui.EndTabBar(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginTabItem(
+        &self,
+        ctx: Context,
+        label: *const c_char,
+        p_openInOutOptional: *mut bool,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self
+            .imgui
+            .BeginTabItem(ctx, label, p_openInOutOptional, flagsInOptional);
+        if result {
+            self.open(ctx, StackKind::TabItem, "BeginTabItem", Self::label(label));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndTabItem(&self, ctx: Context) {
+        self.close(ctx, StackKind::TabItem);
+        self.imgui.EndTabItem(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginCombo(
+        &self,
+        ctx: Context,
+        label: *const c_char,
+        preview_value: *const c_char,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self
+            .imgui
+            .BeginCombo(ctx, label, preview_value, flagsInOptional);
+        if result {
+            self.open(ctx, StackKind::Combo, "BeginCombo", Self::label(label));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndCombo(&self, ctx: Context) {
+        self.close(ctx, StackKind::Combo);
+        self.imgui.EndCombo(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginListBox(
+        &self,
+        ctx: Context,
+        label: *const c_char,
+        size_wInOptional: *mut f64,
+        size_hInOptional: *mut f64,
+    ) -> bool {
+        let result = self
+            .imgui
+            .BeginListBox(ctx, label, size_wInOptional, size_hInOptional);
+        if result {
+            self.open(ctx, StackKind::ListBox, "BeginListBox", Self::label(label));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndListBox(&self, ctx: Context) {
+        self.close(ctx, StackKind::ListBox);
+        self.imgui.EndListBox(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginMenuBar(&self, ctx: Context) -> bool {
+        let result = self.imgui.BeginMenuBar(ctx);
+        if result {
+            self.open(ctx, StackKind::MenuBar, "BeginMenuBar", None);
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndMenuBar(&self, ctx: Context) {
+        self.close(ctx, StackKind::MenuBar);
+        self.imgui.EndMenuBar(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginMenu(
+        &self,
+        ctx: Context,
+        label: *const c_char,
+        enabledInOptional: *mut bool,
+    ) -> bool {
+        let result = self.imgui.BeginMenu(ctx, label, enabledInOptional);
+        if result {
+            self.open(ctx, StackKind::Menu, "BeginMenu", Self::label(label));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndMenu(&self, ctx: Context) {
+        self.close(ctx, StackKind::Menu);
+        self.imgui.EndMenu(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginPopup(
+        &self,
+        ctx: Context,
+        str_id: *const c_char,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self.imgui.BeginPopup(ctx, str_id, flagsInOptional);
+        if result {
+            self.open(ctx, StackKind::Popup, "BeginPopup", Self::label(str_id));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn BeginPopupModal(
+        &self,
+        ctx: Context,
+        name: *const c_char,
+        p_openInOutOptional: *mut bool,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self
+            .imgui
+            .BeginPopupModal(ctx, name, p_openInOutOptional, flagsInOptional);
+        if result {
+            self.open(ctx, StackKind::Popup, "BeginPopupModal", Self::label(name));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn BeginPopupContextItem(
+        &self,
+        ctx: Context,
+        str_idInOptional: *const c_char,
+        popup_flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self
+            .imgui
+            .BeginPopupContextItem(ctx, str_idInOptional, popup_flagsInOptional);
+        if result {
+            self.open(
+                ctx,
+                StackKind::Popup,
+                "BeginPopupContextItem",
+                Self::label(str_idInOptional),
+            );
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn BeginPopupContextWindow(
+        &self,
+        ctx: Context,
+        str_idInOptional: *const c_char,
+        popup_flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result =
+            self.imgui
+                .BeginPopupContextWindow(ctx, str_idInOptional, popup_flagsInOptional);
+        if result {
+            self.open(
+                ctx,
+                StackKind::Popup,
+                "BeginPopupContextWindow",
+                Self::label(str_idInOptional),
+            );
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndPopup(&self, ctx: Context) {
+        self.close(ctx, StackKind::Popup);
+        self.imgui.EndPopup(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginTooltip(&self, ctx: Context) {
+        self.open(ctx, StackKind::Tooltip, "BeginTooltip", None);
+        self.imgui.BeginTooltip(ctx)
+    }
+    #[track_caller]
+    pub unsafe fn EndTooltip(&self, ctx: Context) {
+        self.close(ctx, StackKind::Tooltip);
+        self.imgui.EndTooltip(ctx)
+    }
+
+    /// Tree node is tracked only if it is opened and pushes to the tree.
+    #[track_caller]
+    pub unsafe fn TreeNode(
+        &self,
+        ctx: Context,
+        label: *const c_char,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self.imgui.TreeNode(ctx, label, flagsInOptional);
+        if result && self.pushes_tree(flagsInOptional) {
+            self.open(ctx, StackKind::TreeNode, "TreeNode", Self::label(label));
+        }
+        result
+    }
+    /// Tree node is tracked only if it is opened and pushes to the tree.
+    #[track_caller]
+    pub unsafe fn TreeNodeEx(
+        &self,
+        ctx: Context,
+        str_id: *const c_char,
+        label: *const c_char,
+        flagsInOptional: *mut c_int,
+    ) -> bool {
+        let result = self.imgui.TreeNodeEx(ctx, str_id, label, flagsInOptional);
+        if result && self.pushes_tree(flagsInOptional) {
+            self.open(ctx, StackKind::TreeNode, "TreeNodeEx", Self::label(str_id));
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn TreePush(&self, ctx: Context, str_id: *const c_char) {
+        self.open(ctx, StackKind::TreeNode, "TreePush", Self::label(str_id));
+        self.imgui.TreePush(ctx, str_id)
+    }
+    #[track_caller]
+    pub unsafe fn TreePop(&self, ctx: Context) {
+        self.close(ctx, StackKind::TreeNode);
+        self.imgui.TreePop(ctx)
+    }
+    unsafe fn pushes_tree(&self, flags: *mut c_int) -> bool {
+        pushes_tree(flags, self.imgui.TreeNodeFlags_NoTreePushOnOpen)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginDisabled(&self, ctx: Context, disabledInOptional: *mut bool) {
+        self.open(ctx, StackKind::Disabled, "BeginDisabled", None);
+        self.imgui.BeginDisabled(ctx, disabledInOptional)
+    }
+    #[track_caller]
+    pub unsafe fn EndDisabled(&self, ctx: Context) {
+        self.close(ctx, StackKind::Disabled);
+        self.imgui.EndDisabled(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginDragDropSource(&self, ctx: Context, flagsInOptional: *mut c_int) -> bool {
+        let result = self.imgui.BeginDragDropSource(ctx, flagsInOptional);
+        if result {
+            self.open(ctx, StackKind::DragDropSource, "BeginDragDropSource", None);
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndDragDropSource(&self, ctx: Context) {
+        self.close(ctx, StackKind::DragDropSource);
+        self.imgui.EndDragDropSource(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn BeginDragDropTarget(&self, ctx: Context) -> bool {
+        let result = self.imgui.BeginDragDropTarget(ctx);
+        if result {
+            self.open(ctx, StackKind::DragDropTarget, "BeginDragDropTarget", None);
+        }
+        result
+    }
+    #[track_caller]
+    pub unsafe fn EndDragDropTarget(&self, ctx: Context) {
+        self.close(ctx, StackKind::DragDropTarget);
+        self.imgui.EndDragDropTarget(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushStyleColor(&self, ctx: Context, idx: c_int, col_rgba: c_int) {
+        self.open(
+            ctx,
+            StackKind::StyleColor,
+            "PushStyleColor",
+            Some(style_label(
+                Col::from_raw(&self.imgui, idx).map(Col::name),
+                idx,
+            )),
+        );
+        self.imgui.PushStyleColor(ctx, idx, col_rgba)
+    }
+    #[track_caller]
+    pub unsafe fn PopStyleColor(&self, ctx: Context, countInOptional: *mut c_int) {
+        self.close_many(ctx, StackKind::StyleColor, countInOptional);
+        self.imgui.PopStyleColor(ctx, countInOptional)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushStyleVar(
+        &self,
+        ctx: Context,
+        var_idx: c_int,
+        val1: f64,
+        val2InOptional: *mut f64,
+    ) {
+        self.open(
+            ctx,
+            StackKind::StyleVar,
+            "PushStyleVar",
+            Some(style_label(
+                StyleVar::from_raw(&self.imgui, var_idx).map(StyleVar::name),
+                var_idx,
+            )),
+        );
+        self.imgui.PushStyleVar(ctx, var_idx, val1, val2InOptional)
+    }
+    #[track_caller]
+    pub unsafe fn PopStyleVar(&self, ctx: Context, countInOptional: *mut c_int) {
+        self.close_many(ctx, StackKind::StyleVar, countInOptional);
+        self.imgui.PopStyleVar(ctx, countInOptional)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushID(&self, ctx: Context, str_id: *const c_char) {
+        self.open(ctx, StackKind::Id, "PushID", Self::label(str_id));
+        self.imgui.PushID(ctx, str_id)
+    }
+    #[track_caller]
+    pub unsafe fn PopID(&self, ctx: Context) {
+        self.close(ctx, StackKind::Id);
+        self.imgui.PopID(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushFont(&self, ctx: Context, font: Font) {
+        self.open(ctx, StackKind::Font, "PushFont", None);
+        self.imgui.PushFont(ctx, font)
+    }
+    #[track_caller]
+    pub unsafe fn PopFont(&self, ctx: Context) {
+        self.close(ctx, StackKind::Font);
+        self.imgui.PopFont(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushItemWidth(&self, ctx: Context, item_width: f64) {
+        self.open(ctx, StackKind::ItemWidth, "PushItemWidth", None);
+        self.imgui.PushItemWidth(ctx, item_width)
+    }
+    #[track_caller]
+    pub unsafe fn PopItemWidth(&self, ctx: Context) {
+        self.close(ctx, StackKind::ItemWidth);
+        self.imgui.PopItemWidth(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushTextWrapPos(&self, ctx: Context, wrap_local_pos_xInOptional: *mut f64) {
+        self.open(ctx, StackKind::TextWrapPos, "PushTextWrapPos", None);
+        self.imgui.PushTextWrapPos(ctx, wrap_local_pos_xInOptional)
+    }
+    #[track_caller]
+    pub unsafe fn PopTextWrapPos(&self, ctx: Context) {
+        self.close(ctx, StackKind::TextWrapPos);
+        self.imgui.PopTextWrapPos(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushAllowKeyboardFocus(&self, ctx: Context, allow_keyboard_focus: bool) {
+        self.open(
+            ctx,
+            StackKind::AllowKeyboardFocus,
+            "PushAllowKeyboardFocus",
+            None,
+        );
+        self.imgui.PushAllowKeyboardFocus(ctx, allow_keyboard_focus)
+    }
+    #[track_caller]
+    pub unsafe fn PopAllowKeyboardFocus(&self, ctx: Context) {
+        self.close(ctx, StackKind::AllowKeyboardFocus);
+        self.imgui.PopAllowKeyboardFocus(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushButtonRepeat(&self, ctx: Context, repeat: bool) {
+        self.open(ctx, StackKind::ButtonRepeat, "PushButtonRepeat", None);
+        self.imgui.PushButtonRepeat(ctx, repeat)
+    }
+    #[track_caller]
+    pub unsafe fn PopButtonRepeat(&self, ctx: Context) {
+        self.close(ctx, StackKind::ButtonRepeat);
+        self.imgui.PopButtonRepeat(ctx)
+    }
+
+    #[track_caller]
+    pub unsafe fn PushClipRect(
+        &self,
+        ctx: Context,
+        clip_rect_min_x: f64,
+        clip_rect_min_y: f64,
+        clip_rect_max_x: f64,
+        clip_rect_max_y: f64,
+        intersect_with_current_clip_rect: bool,
+    ) {
+        self.open(ctx, StackKind::ClipRect, "PushClipRect", None);
+        self.imgui.PushClipRect(
+            ctx,
+            clip_rect_min_x,
+            clip_rect_min_y,
+            clip_rect_max_x,
+            clip_rect_max_y,
+            intersect_with_current_clip_rect,
+        )
+    }
+    #[track_caller]
+    pub unsafe fn PopClipRect(&self, ctx: Context) {
+        self.close(ctx, StackKind::ClipRect);
+        self.imgui.PopClipRect(ctx)
+    }
+}
+
+/// Whether an opened tree node has to be closed with `TreePop`.
+unsafe fn pushes_tree(flags: *const c_int, no_tree_push_on_open: Option<c_int>) -> bool {
+    match (flags.is_null(), no_tree_push_on_open) {
+        (false, Some(no_push)) => *flags & no_push == 0,
+        _ => true,
+    }
+}
+
+/// Name of the pushed style color or variable, or its raw index, if it is
+/// not known.
+fn style_label(name: Option<&str>, idx: c_int) -> String {
+    match name {
+        Some(name) => name.to_string(),
+        None => format!("idx {}", idx),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(errors: &[StackError]) -> Vec<(StackKind, bool)> {
+        errors
+            .iter()
+            .map(|error| match error {
+                StackError::Unmatched(entry) => (entry.kind, false),
+                StackError::UnexpectedClose { kind, .. } => (*kind, true),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn interleaved_kinds_are_independent() {
+        let mut stacks = ContextStacks::default();
+        stacks.open(StackKind::StyleVar, "PushStyleVar", None);
+        stacks.open(StackKind::Window, "Begin", Some("window".into()));
+        assert!(stacks.close(StackKind::StyleVar));
+        assert!(stacks.close(StackKind::Window));
+        assert_eq!(stacks.finish(), vec![]);
+    }
+
+    #[test]
+    fn closing_scope_reports_inner_scopes() {
+        let mut stacks = ContextStacks::default();
+        stacks.open(StackKind::Window, "Begin", None);
+        stacks.open(StackKind::Table, "BeginTable", None);
+        stacks.open(StackKind::Id, "PushID", None);
+        stacks.open(StackKind::Combo, "BeginCombo", None);
+        assert!(stacks.close(StackKind::Window));
+        assert!(stacks.close(StackKind::Id));
+        assert_eq!(
+            kinds(&stacks.finish()),
+            vec![(StackKind::Combo, false), (StackKind::Table, false)]
+        );
+    }
+
+    #[test]
+    fn extra_close() {
+        let mut stacks = ContextStacks::default();
+        stacks.open(StackKind::Id, "PushID", None);
+        assert!(stacks.close(StackKind::Id));
+        assert!(!stacks.close(StackKind::Id));
+        assert!(!stacks.close(StackKind::Window));
+        assert_eq!(
+            kinds(&stacks.finish()),
+            vec![(StackKind::Id, true), (StackKind::Window, true)]
+        );
+    }
+
+    #[test]
+    fn partial_pop_many() {
+        let mut stacks = ContextStacks::default();
+        for _ in 0..3 {
+            stacks.open(StackKind::StyleColor, "PushStyleColor", None);
+        }
+        stacks.close_many(StackKind::StyleColor, &2);
+        assert_eq!(kinds(&stacks.errors), vec![]);
+        stacks.close_many(StackKind::StyleColor, std::ptr::null());
+        stacks.close_many(StackKind::StyleColor, &2);
+        assert_eq!(kinds(&stacks.finish()), vec![(StackKind::StyleColor, true)]);
+    }
+
+    #[test]
+    fn tree_node_without_push() {
+        const NO_TREE_PUSH_ON_OPEN: c_int = 1 << 3;
+        let (flags, no_push) = (1, 1 | NO_TREE_PUSH_ON_OPEN);
+        unsafe {
+            assert!(pushes_tree(std::ptr::null(), Some(NO_TREE_PUSH_ON_OPEN)));
+            assert!(pushes_tree(&flags, Some(NO_TREE_PUSH_ON_OPEN)));
+            assert!(!pushes_tree(&no_push, Some(NO_TREE_PUSH_ON_OPEN)));
+            assert!(pushes_tree(&no_push, None));
+        }
+        let mut stacks = ContextStacks::default();
+        assert!(!stacks.close(StackKind::TreeNode));
+        assert_eq!(kinds(&stacks.finish()), vec![(StackKind::TreeNode, true)]);
+    }
+
+    #[test]
+    fn style_labels() {
+        assert_eq!(style_label(Some(Col::Text.name()), 0), "Text");
+        assert_eq!(style_label(Some(StyleVar::Alpha.name()), 0), "Alpha");
+        assert_eq!(style_label(None, 99), "idx 99");
+    }
+}
@@ -0,0 +1,403 @@
+//! Colors, converted in Rust the same way as the ReaImGui converters.
+//!
+//! [`Color`] is stored packed as `0xRRGGBBAA`, like ReaImGui passes it.
+//! Components are quantized as `ColorConvertDouble4ToU32` does, and HSV is
+//! converted with the same single-precision algorithm as
+//! `ColorConvertRGBtoHSV` and `ColorConvertHSVtoRGB`, so the results match
+//! ReaImGui exactly:
+//!
+//! ```
+//! # use reaper_imgui::Color;
+//! // ColorConvertDouble4ToU32(1, 0.5, 0, 1) == 0xFF8000FF
+//! let orange = Color::from_rgba_f64(1.0, 0.5, 0.0, 1.0);
+//! assert_eq!(orange.to_rgba(), 0xFF8000FF);
+//! // Out of range components saturate.
+//! assert_eq!(Color::from_rgba_f64(2.0, -1.0, 0.0, 1.0).to_rgba(), 0xFF0000FF);
+//! // ColorConvertU32ToDouble4(0xFF8000FF) == 1, 0.50196081, 0, 1
+//! let rgba = orange.to_rgba_f64().map(|c| c as f32);
+//! assert_eq!(rgba, [1.0, 0.50196081, 0.0, 1.0]);
+//! // ColorConvertHSVtoRGB(0.6666667, 1, 1) == 0, 0, 1
+//! assert_eq!(Color::from_hsv(2.0 / 3.0, 1.0, 1.0, 1.0).to_rgba(), 0x0000FFFF);
+//! // ColorConvertRGBtoHSV(1, 0.50196081, 0) == 0.083660133, 1, 1
+//! let [h, s, v] = orange.to_hsv();
+//! assert_eq!([h as f32, s as f32, v as f32], [0.083660133, 1.0, 1.0]);
+//! assert_eq!(Color::from_hsv(h, s, v, 1.0), orange);
+//!
+//! assert_eq!("#FF8000".parse(), Ok(orange));
+//! assert_eq!(orange.to_string(), "#FF8000FF");
+//! assert_eq!(Color::from_argb(0x80FF8000), orange.with_alpha(0.5));
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::error::ColorError;
+
+/// RGBA color, packed as `0xRRGGBBAA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color(u32);
+impl Color {
+    pub const TRANSPARENT: Self = Self(0x00000000);
+    pub const BLACK: Self = Self(0x000000FF);
+    pub const WHITE: Self = Self(0xFFFFFFFF);
+
+    /// From `0xRRGGBBAA`.
+    pub const fn from_rgba(rgba: u32) -> Self {
+        Self(rgba)
+    }
+
+    /// From `0xRRGGBB`, opaque.
+    pub const fn from_rgb(rgb: u32) -> Self {
+        Self(((rgb & 0xFFFFFF) << 8) | 0xFF)
+    }
+
+    /// From `0xAARRGGBB`.
+    pub const fn from_argb(argb: u32) -> Self {
+        Self(argb.rotate_left(8))
+    }
+
+    pub const fn from_rgba_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(u32::from_be_bytes([r, g, b, a]))
+    }
+
+    /// From components in `0.0..=1.0`, like `ColorConvertDouble4ToU32`.
+    pub fn from_rgba_f32(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::from_rgba_u8(to_u8(r), to_u8(g), to_u8(b), to_u8(a))
+    }
+
+    /// From components in `0.0..=1.0`, like `ColorConvertDouble4ToU32`.
+    pub fn from_rgba_f64(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self::from_rgba_f32(r as f32, g as f32, b as f32, a as f32)
+    }
+
+    /// From hue, saturation and value in `0.0..=1.0`, like
+    /// `ColorConvertHSVtoRGB`.
+    pub fn from_hsv(h: f64, s: f64, v: f64, a: f64) -> Self {
+        let [r, g, b] = hsv_to_rgb(h as f32, s as f32, v as f32);
+        Self::from_rgba_f32(r, g, b, a as f32)
+    }
+
+    /// From `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, `#` is optional.
+    pub fn from_hex(hex: &str) -> Result<Self, ColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ColorError::InvalidHex(hex.to_string()));
+        }
+        let value = u32::from_str_radix(digits, 16).unwrap_or(0);
+        let short = |value: u32, nibbles: u32| {
+            (0..nibbles).rev().fold(0, |acc, idx| {
+                let nibble = (value >> (idx * 4)) & 0xF;
+                (acc << 8) | (nibble * 0x11)
+            })
+        };
+        Ok(match digits.len() {
+            3 => Self::from_rgb(short(value, 3)),
+            4 => Self::from_rgba(short(value, 4)),
+            6 => Self::from_rgb(value),
+            8 => Self::from_rgba(value),
+            _ => return Err(ColorError::InvalidHex(hex.to_string())),
+        })
+    }
+
+    /// `0xRRGGBBAA`.
+    pub const fn to_rgba(self) -> u32 {
+        self.0
+    }
+
+    /// `0xRRGGBB`, without alpha.
+    pub const fn to_rgb(self) -> u32 {
+        self.0 >> 8
+    }
+
+    /// `0xAARRGGBB`.
+    pub const fn to_argb(self) -> u32 {
+        self.0.rotate_right(8)
+    }
+
+    pub const fn to_rgba_u8(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Components in `0.0..=1.0`, like `ColorConvertU32ToDouble4`.
+    pub fn to_rgba_f32(self) -> [f32; 4] {
+        self.to_rgba_u8().map(|c| c as f32 * (1.0 / 255.0))
+    }
+
+    /// Components in `0.0..=1.0`, like `ColorConvertU32ToDouble4`: computed
+    /// in single precision.
+    pub fn to_rgba_f64(self) -> [f64; 4] {
+        self.to_rgba_f32().map(f64::from)
+    }
+
+    /// Hue, saturation and value in `0.0..=1.0`, like
+    /// `ColorConvertRGBtoHSV`.
+    pub fn to_hsv(self) -> [f64; 3] {
+        let [r, g, b, _] = self.to_rgba_f32();
+        rgb_to_hsv(r, g, b).map(f64::from)
+    }
+
+    pub const fn r(self) -> u8 {
+        self.to_rgba_u8()[0]
+    }
+
+    pub const fn g(self) -> u8 {
+        self.to_rgba_u8()[1]
+    }
+
+    pub const fn b(self) -> u8 {
+        self.to_rgba_u8()[2]
+    }
+
+    pub const fn a(self) -> u8 {
+        self.to_rgba_u8()[3]
+    }
+
+    /// Track or item color as REAPER stores it (`I_CUSTOMCOLOR`): OS-native
+    /// `0xBBGGRR` on Windows and `0xRRGGBB` elsewhere, with the `0x1000000`
+    /// flag set if the color is custom. `None` without the flag: the default
+    /// theme color is used.
+    ///
+    /// Same as `ColorConvertNative`, but keeps the flag in mind.
+    ///
+    /// ```
+    /// # use reaper_imgui::Color;
+    /// let color = Color::from_rgb(0x3080C0);
+    /// let native = color.to_reaper_native();
+    /// assert_eq!(native & 0x1000000, 0x1000000);
+    /// assert_eq!(Color::from_reaper_native(native), Some(color));
+    /// assert_eq!(Color::from_reaper_native(0), None);
+    /// # #[cfg(windows)]
+    /// # assert_eq!(native, 0x1C08030);
+    /// # #[cfg(not(windows))]
+    /// # assert_eq!(native, 0x13080C0);
+    /// ```
+    pub fn from_reaper_native(native: i32) -> Option<Self> {
+        match native & REAPER_CUSTOM_COLOR != 0 {
+            true => Some(Self::from_rgb(swap_native(native as u32 & 0xFFFFFF))),
+            false => None,
+        }
+    }
+
+    /// Opaque custom color for REAPER, see [`Color::from_reaper_native`].
+    /// Alpha is dropped.
+    pub fn to_reaper_native(self) -> i32 {
+        swap_native(self.to_rgb()) as i32 | REAPER_CUSTOM_COLOR
+    }
+
+    /// Same color with the alpha in `0.0..=1.0`.
+    pub fn with_alpha(self, alpha: f64) -> Self {
+        Self((self.0 & !0xFF) | to_u8(alpha as f32) as u32)
+    }
+}
+impl From<u32> for Color {
+    /// From `0xRRGGBBAA`.
+    fn from(rgba: u32) -> Self {
+        Self::from_rgba(rgba)
+    }
+}
+impl From<Color> for u32 {
+    /// `0xRRGGBBAA`.
+    fn from(color: Color) -> Self {
+        color.to_rgba()
+    }
+}
+impl From<[f32; 4]> for Color {
+    fn from([r, g, b, a]: [f32; 4]) -> Self {
+        Self::from_rgba_f32(r, g, b, a)
+    }
+}
+impl From<[f64; 4]> for Color {
+    fn from([r, g, b, a]: [f64; 4]) -> Self {
+        Self::from_rgba_f64(r, g, b, a)
+    }
+}
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s.trim())
+    }
+}
+/// `#RRGGBBAA`.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:08X}", self.0)
+    }
+}
+
+/// Set in REAPER track and item colors, which are not the theme default.
+const REAPER_CUSTOM_COLOR: i32 = 0x1000000;
+
+/// `0xRRGGBB` to OS-native color and back: red and blue are swapped on
+/// Windows.
+fn swap_native(rgb: u32) -> u32 {
+    match cfg!(windows) {
+        true => ((rgb & 0xFF) << 16) | (rgb & 0xFF00) | ((rgb >> 16) & 0xFF),
+        false => rgb,
+    }
+}
+
+/// `IM_F32_TO_INT8_SAT`.
+fn to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// `ImGui::ColorConvertRGBtoHSV`.
+fn rgb_to_hsv(mut r: f32, mut g: f32, mut b: f32) -> [f32; 3] {
+    let mut k = 0.0;
+    if g < b {
+        std::mem::swap(&mut g, &mut b);
+        k = -1.0;
+    }
+    if r < g {
+        std::mem::swap(&mut r, &mut g);
+        k = -2.0 / 6.0 - k;
+    }
+    let chroma = r - g.min(b);
+    [
+        (k + (g - b) / (6.0 * chroma + 1e-20)).abs(),
+        chroma / (r + 1e-20),
+        r,
+    ]
+}
+
+/// `ImGui::ColorConvertHSVtoRGB`.
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> [f32; 3] {
+    if s == 0.0 {
+        return [v, v, v];
+    }
+    let h = (h % 1.0) / (60.0 / 360.0);
+    let i = h as i32;
+    let f = h - i as f32;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+    match i {
+        0 => [v, t, p],
+        1 => [q, v, p],
+        2 => [p, v, t],
+        3 => [p, q, v],
+        4 => [t, p, v],
+        _ => [v, p, q],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_lengths() {
+        assert_eq!(Color::from_hex("#F80"), Ok(Color::from_rgba(0xFF8800FF)));
+        assert_eq!(Color::from_hex("f808"), Ok(Color::from_rgba(0xFF880088)));
+        assert_eq!(Color::from_hex("#3080c0"), Ok(Color::from_rgba(0x3080C0FF)));
+        assert_eq!(
+            Color::from_hex("3080C040"),
+            Ok(Color::from_rgba(0x3080C040))
+        );
+        assert_eq!(" #000 ".parse(), Ok(Color::BLACK));
+        assert_eq!("#FFFFFFFF".parse(), Ok(Color::WHITE));
+    }
+
+    #[test]
+    fn invalid_hex() {
+        for hex in [
+            "",
+            "#",
+            "#F",
+            "#FF",
+            "#FFFFF",
+            "#FFFFFFF",
+            "#FFFFFFFFF",
+            "#GGG",
+            "##FFF",
+            "+FFF",
+            "#FF F",
+        ] {
+            assert_eq!(
+                Color::from_hex(hex),
+                Err(ColorError::InvalidHex(hex.to_string())),
+                "{:?}",
+                hex
+            );
+        }
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for rgba in [0x00000000, 0x12345678, 0xFF8000FF, 0xFFFFFFFF] {
+            let color = Color::from_rgba(rgba);
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+    }
+
+    #[test]
+    fn argb_rotation() {
+        let color = Color::from_rgba(0x11223344);
+        assert_eq!(color.to_argb(), 0x44112233);
+        assert_eq!(Color::from_argb(0x44112233), color);
+        assert_eq!(Color::from_argb(color.to_argb()), color);
+        assert_eq!(color.to_rgb(), 0x112233);
+        assert_eq!(Color::from_rgb(0xAA112233), Color::from_rgba(0x112233FF));
+        assert_eq!(color.to_rgba_u8(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(
+            [color.r(), color.g(), color.b(), color.a()],
+            [0x11, 0x22, 0x33, 0x44]
+        );
+    }
+
+    #[test]
+    fn float_saturation() {
+        assert_eq!(
+            Color::from_rgba_f32(1.5, -0.5, 0.5, 1.0),
+            Color::from_rgba(0xFF0080FF)
+        );
+        assert_eq!(
+            Color::from_rgba_f64(f64::INFINITY, f64::NEG_INFINITY, 0.0, 0.0),
+            Color::from_rgba(0xFF000000)
+        );
+        assert_eq!(Color::WHITE.with_alpha(2.0), Color::WHITE);
+        assert_eq!(Color::WHITE.with_alpha(-1.0), Color::from_rgba(0xFFFFFF00));
+    }
+
+    #[test]
+    fn float_round_trip() {
+        for rgba in [0x00000000, 0x01020304, 0x7F8081FE, 0xFFFFFFFF] {
+            let color = Color::from_rgba(rgba);
+            assert_eq!(Color::from(color.to_rgba_f32()), color);
+            assert_eq!(Color::from(color.to_rgba_f64()), color);
+        }
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(51) {
+                    let color = Color::from_rgba_u8(r, g, b, 0x80);
+                    let [h, s, v] = color.to_hsv();
+                    assert_eq!(Color::from_hsv(h, s, v, 0x80 as f64 / 255.0), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsv_edges() {
+        // Gray has no saturation and no defined hue.
+        assert_eq!(Color::from_rgb(0x808080).to_hsv()[1], 0.0);
+        assert_eq!(Color::from_hsv(0.3, 0.0, 1.0, 1.0), Color::WHITE);
+        // Hue wraps around.
+        assert_eq!(
+            Color::from_hsv(1.0, 1.0, 1.0, 1.0),
+            Color::from_hsv(0.0, 1.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Color::from_hsv(0.0, 1.0, 1.0, 1.0),
+            Color::from_rgb(0xFF0000)
+        );
+        assert_eq!(
+            Color::from_hsv(1.0 / 3.0, 1.0, 1.0, 1.0),
+            Color::from_rgb(0x00FF00)
+        );
+    }
+}
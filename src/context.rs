@@ -0,0 +1,177 @@
+//! Owned ReaImGui context.
+
+use std::ffi::{CStr, CString};
+
+use c_str_macro::c_str;
+
+use crate::{
+    error::ContextError, fonts::ContextFonts, scratch::Scratch, widgets::ContextClipper,
+    ConfigFlags, Context, FontRegistry, ImGui, Ui,
+};
+
+/// ReaImGui context, that is destroyed when dropped.
+///
+/// ReaImGui also destroys a context by itself, if it was not used during a
+/// defer cycle (e.g. the timer was stopped for a while). So the validity is
+/// checked every time it matters: see [`OwnedContext::is_valid`].
+///
+/// ```no_run
+/// # use reaper_imgui::{ConfigFlags, ImGui, OwnedContext};
+/// # fn f(imgui: &ImGui) -> Result<(), Box<dyn std::error::Error>> {
+/// let ctx = OwnedContext::new(imgui, "my context", ConfigFlags::empty())?;
+/// println!("frame {} at {:.3}s", ctx.frame_count(), ctx.time());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OwnedContext {
+    imgui: ImGui,
+    raw: Context,
+    label: CString,
+    scratch: Scratch,
+    fonts: ContextFonts,
+    clipper: ContextClipper,
+}
+impl OwnedContext {
+    /// Create a new context with the given configuration.
+    pub fn new(
+        imgui: &ImGui,
+        label: &str,
+        config_flags: ConfigFlags,
+    ) -> Result<Self, ContextError> {
+        Self::with_fonts(imgui, label, config_flags, FontRegistry::new())
+    }
+
+    /// Create a new context and attach all the fonts to it.
+    ///
+    /// Fonts are available in [`Ui::with_font`] by the ids given by the
+    /// registry.
+    pub fn with_fonts(
+        imgui: &ImGui,
+        label: &str,
+        config_flags: ConfigFlags,
+        fonts: FontRegistry,
+    ) -> Result<Self, ContextError> {
+        let label = CString::new(label)?;
+        let fonts = fonts
+            .create(imgui)
+            .map_err(ContextError::FontCreationFailed)?;
+        let mut config_flags = config_flags.to_raw(imgui);
+        let raw = unsafe { imgui.CreateContext(label.as_ptr(), &mut config_flags) };
+        if raw.is_null() {
+            return Err(ContextError::CreationFailed);
+        }
+        for font in fonts.raw() {
+            unsafe { imgui.Attach(raw, *font) };
+        }
+        let clipper = unsafe { ContextClipper::attach(imgui, raw) };
+        Ok(Self {
+            imgui: imgui.clone(),
+            raw,
+            label,
+            scratch: Scratch::default(),
+            fonts,
+            clipper,
+        })
+    }
+
+    /// Raw context pointer for direct calls to [`ImGui`].
+    pub fn raw(&self) -> Context {
+        self.raw
+    }
+
+    pub fn imgui(&self) -> &ImGui {
+        &self.imgui
+    }
+
+    pub fn label(&self) -> &CStr {
+        &self.label
+    }
+
+    /// Whether the context still exists.
+    ///
+    /// It becomes invalid when ReaImGui garbage-collects the context after a
+    /// defer cycle without it being used.
+    pub fn is_valid(&self) -> bool {
+        unsafe {
+            self.imgui
+                .ValidatePtr(self.raw, c_str!("ImGui_Context*").as_ptr())
+        }
+    }
+
+    /// Start building a frame.
+    ///
+    /// Returns `None` if the context has been destroyed.
+    pub fn frame(&mut self) -> Option<Ui<'_>> {
+        match self.is_valid() {
+            true => Some(unsafe {
+                Ui::new(
+                    &self.imgui,
+                    self.raw,
+                    &self.scratch,
+                    &self.fonts,
+                    &self.clipper,
+                )
+            }),
+            false => None,
+        }
+    }
+
+    /// Raw pointer, checked for validity.
+    ///
+    /// # Panics
+    ///
+    /// If the context has been destroyed.
+    fn valid_raw(&self) -> Context {
+        if !self.is_valid() {
+            panic!(
+                "ImGui context {:?} has been destroyed or garbage-collected",
+                self.label
+            );
+        }
+        self.raw
+    }
+
+    /// Time since the context creation, in seconds.
+    ///
+    /// # Panics
+    ///
+    /// If the context has been destroyed.
+    pub fn time(&self) -> f64 {
+        unsafe { self.imgui.GetTime(self.valid_raw()) }
+    }
+
+    /// Number of frames since the context creation.
+    ///
+    /// # Panics
+    ///
+    /// If the context has been destroyed.
+    pub fn frame_count(&self) -> i32 {
+        unsafe { self.imgui.GetFrameCount(self.valid_raw()) }
+    }
+
+    /// Time elapsed since the last frame, in seconds.
+    ///
+    /// # Panics
+    ///
+    /// If the context has been destroyed.
+    pub fn delta_time(&self) -> f64 {
+        unsafe { self.imgui.GetDeltaTime(self.valid_raw()) }
+    }
+
+    /// Estimation of application framerate (rolling average over 60 frames).
+    ///
+    /// # Panics
+    ///
+    /// If the context has been destroyed.
+    pub fn framerate(&self) -> f64 {
+        unsafe { self.imgui.GetFramerate(self.valid_raw()) }
+    }
+}
+impl Drop for OwnedContext {
+    fn drop(&mut self) {
+        if self.is_valid() {
+            unsafe { self.imgui.DestroyContext(self.raw) }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Drag and drop between items, windows and other applications.
+//!
+//! Payloads are typed by a string of up to 32 characters and carry text.
+//! Types starting with `_` are reserved: colors and files dropped from the
+//! OS have their own accept functions.
+//!
+//! ```no_run
+//! # use reaper_imgui::{Cond, DragDropFlags, Ui};
+//! # fn f(ui: &Ui, tracks: &mut Vec<String>) {
+//! for idx in 0..tracks.len() {
+//!     ui.selectable(&tracks[idx], false);
+//!     ui.drag_drop_source(DragDropFlags::empty(), |ui| {
+//!         ui.set_drag_drop_payload("TRACK", idx.to_string(), Cond::Always);
+//!         ui.text(&tracks[idx]);
+//!     });
+//!     ui.drag_drop_target(|ui| {
+//!         let dropped = ui.accept_drag_drop_payload("TRACK", DragDropFlags::empty());
+//!         if let Some(from) = dropped.and_then(|p| p.parse::<usize>().ok()) {
+//!             tracks.swap(from, idx);
+//!         }
+//!     });
+//! }
+//! # }
+//! ```
+
+use std::ffi::{c_char, c_int, CStr};
+
+use crate::{scratch::ImStr, Color, Cond, DragDropFlags, Ui};
+
+/// Bytes read from a text payload or a file name, longer ones are truncated.
+const PAYLOAD_CAPACITY: usize = 4096;
+/// Payload types are limited to 32 characters.
+const PAYLOAD_TYPE_CAPACITY: usize = 33;
+
+/// Payload being dragged, see [`Ui::drag_drop_payload`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DragDropPayload {
+    pub type_: String,
+    pub data: String,
+    /// Whether it is hovered over an accepting target.
+    pub is_preview: bool,
+    /// Whether it is dropped this frame.
+    pub is_delivery: bool,
+}
+
+impl<'frame> Ui<'frame> {
+    /// Make the last item a drag and drop source. `f` is called while it is
+    /// dragged, sets the payload with [`Ui::set_drag_drop_payload`] and
+    /// builds the tooltip shown under the mouse.
+    pub fn drag_drop_source<R>(
+        &self,
+        flags: DragDropFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        if !unsafe {
+            self.imgui()
+                .BeginDragDropSource(self.raw_context(), &mut flags)
+        } {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndDragDropSource(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Set the payload of the current source. Returns `true` when it was
+    /// accepted by a target.
+    pub fn set_drag_drop_payload(&self, type_: impl ImStr, data: impl ImStr, cond: Cond) -> bool {
+        let mut cond = cond.to_raw(self.imgui());
+        self.with_strs([Some(&type_), Some(&data)], |[type_, data]| unsafe {
+            self.imgui()
+                .SetDragDropPayload(self.raw_context(), type_, data, &mut cond)
+        })
+    }
+
+    /// Make the last item a drag and drop target. `f` is called while
+    /// something is dragged over it and checks the payload with the
+    /// `accept_drag_drop_payload*` functions.
+    pub fn drag_drop_target<R>(&self, f: impl FnOnce(&Self) -> R) -> Option<R> {
+        if !unsafe { self.imgui().BeginDragDropTarget(self.raw_context()) } {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndDragDropTarget(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Data of the payload of the type, once it is dropped. With
+    /// [`DragDropFlags::ACCEPT_BEFORE_DELIVERY`], also while it is hovered.
+    pub fn accept_drag_drop_payload(
+        &self,
+        type_: impl ImStr,
+        flags: DragDropFlags,
+    ) -> Option<String> {
+        let mut flags = flags.to_raw(self.imgui());
+        self.with_str(&type_, |type_| {
+            self.read_str(PAYLOAD_CAPACITY, |buf, size| unsafe {
+                self.imgui()
+                    .AcceptDragDropPayload(self.raw_context(), type_, buf, size, &mut flags)
+            })
+        })
+    }
+
+    /// Color dragged from a color widget, alpha is opaque.
+    pub fn accept_drag_drop_payload_rgb(&self, flags: DragDropFlags) -> Option<Color> {
+        let (mut rgb, mut flags): (c_int, _) = (0, flags.to_raw(self.imgui()));
+        let accepted = unsafe {
+            self.imgui()
+                .AcceptDragDropPayloadRGB(self.raw_context(), &mut rgb, &mut flags)
+        };
+        accepted.then(|| Color::from_rgb(rgb as u32))
+    }
+
+    /// Color dragged from a color widget.
+    pub fn accept_drag_drop_payload_rgba(&self, flags: DragDropFlags) -> Option<Color> {
+        let (mut rgba, mut flags): (c_int, _) = (0, flags.to_raw(self.imgui()));
+        let accepted = unsafe {
+            self.imgui()
+                .AcceptDragDropPayloadRGBA(self.raw_context(), &mut rgba, &mut flags)
+        };
+        accepted.then(|| Color::from_rgba(rgba as u32))
+    }
+
+    /// Paths of the files dropped from the OS.
+    pub fn accept_drag_drop_payload_files(&self, flags: DragDropFlags) -> Option<Vec<String>> {
+        let (mut count, mut flags) = (0, flags.to_raw(self.imgui()));
+        let accepted = unsafe {
+            self.imgui()
+                .AcceptDragDropPayloadFiles(self.raw_context(), &mut count, &mut flags)
+        };
+        if !accepted {
+            return None;
+        }
+        let files = (0..count).filter_map(|idx| {
+            self.read_str(PAYLOAD_CAPACITY, |buf, size| unsafe {
+                self.imgui()
+                    .GetDragDropPayloadFile(self.raw_context(), idx, buf, size)
+            })
+        });
+        Some(files.collect())
+    }
+
+    /// Payload being dragged, if any, e.g. to highlight the targets
+    /// accepting it.
+    pub fn drag_drop_payload(&self) -> Option<DragDropPayload> {
+        let mut type_ = [0u8; PAYLOAD_TYPE_CAPACITY];
+        let (mut is_preview, mut is_delivery) = (false, false);
+        let data = self.read_str(PAYLOAD_CAPACITY, |buf, size| unsafe {
+            self.imgui().GetDragDropPayload(
+                self.raw_context(),
+                type_.as_mut_ptr() as *mut c_char,
+                type_.len() as c_int,
+                buf,
+                size,
+                &mut is_preview,
+                &mut is_delivery,
+            )
+        })?;
+        let type_ = CStr::from_bytes_until_nul(&type_).unwrap_or_default();
+        Some(DragDropPayload {
+            type_: type_.to_string_lossy().into_owned(),
+            data,
+            is_preview,
+            is_delivery,
+        })
+    }
+}
@@ -0,0 +1,180 @@
+//! Custom drawing with the draw lists of a window or of the viewport.
+//!
+//! Coordinates are in screen space, colors are [`Color`](crate::Color) or
+//! `0xRRGGBBAA`.
+//!
+//! ```no_run
+//! # use reaper_imgui::{Rect, Ui, Vec2};
+//! # fn f(ui: &Ui, level: f64) {
+//! let pos = ui.cursor_screen_pos();
+//! let meter = Rect::from_pos_size(pos, (200.0, 12.0));
+//! let draw = ui.window_draw_list();
+//! draw.add_rect_filled(meter, 0x202020FF, 2.0);
+//! let filled = Rect::new(meter.min, (meter.min.x + meter.width() * level, meter.max.y));
+//! draw.add_rect_filled(filled, 0x40C040FF, 2.0);
+//! draw.add_text(meter.max + Vec2::new(4.0, -12.0), 0xFFFFFFFF, format!("{:.0}%", level * 100.0));
+//! ui.dummy(meter.size());
+//! # }
+//! ```
+
+use std::ptr::null_mut;
+
+use crate::{scratch::ImStr, Color, DrawList, Rect, Ui, Vec2};
+
+/// Draw list of the current frame, see [`Ui::window_draw_list`].
+#[derive(Debug, Clone, Copy)]
+pub struct DrawListRef<'ui> {
+    ui: &'ui Ui<'ui>,
+    raw: DrawList,
+}
+impl<'ui> DrawListRef<'ui> {
+    pub fn raw(&self) -> DrawList {
+        self.raw
+    }
+
+    pub fn add_line(
+        &self,
+        p1: impl Into<Vec2>,
+        p2: impl Into<Vec2>,
+        color: impl Into<Color>,
+        thickness: f64,
+    ) {
+        let (p1, p2, mut thickness) = (p1.into(), p2.into(), thickness);
+        unsafe {
+            self.ui.imgui().DrawList_AddLine(
+                self.raw,
+                p1.x,
+                p1.y,
+                p2.x,
+                p2.y,
+                color.into().to_rgba() as i32,
+                &mut thickness,
+            )
+        }
+    }
+
+    pub fn add_rect(&self, rect: Rect, color: impl Into<Color>, rounding: f64, thickness: f64) {
+        let (mut rounding, mut thickness) = (rounding, thickness);
+        let Rect { min, max } = rect;
+        unsafe {
+            self.ui.imgui().DrawList_AddRect(
+                self.raw,
+                min.x,
+                min.y,
+                max.x,
+                max.y,
+                color.into().to_rgba() as i32,
+                &mut rounding,
+                null_mut(),
+                &mut thickness,
+            )
+        }
+    }
+
+    pub fn add_rect_filled(&self, rect: Rect, color: impl Into<Color>, rounding: f64) {
+        let mut rounding = rounding;
+        let Rect { min, max } = rect;
+        unsafe {
+            self.ui.imgui().DrawList_AddRectFilled(
+                self.raw,
+                min.x,
+                min.y,
+                max.x,
+                max.y,
+                color.into().to_rgba() as i32,
+                &mut rounding,
+                null_mut(),
+            )
+        }
+    }
+
+    pub fn add_circle(
+        &self,
+        center: impl Into<Vec2>,
+        radius: f64,
+        color: impl Into<Color>,
+        thickness: f64,
+    ) {
+        let (center, mut thickness) = (center.into(), thickness);
+        unsafe {
+            self.ui.imgui().DrawList_AddCircle(
+                self.raw,
+                center.x,
+                center.y,
+                radius,
+                color.into().to_rgba() as i32,
+                null_mut(),
+                &mut thickness,
+            )
+        }
+    }
+
+    pub fn add_circle_filled(&self, center: impl Into<Vec2>, radius: f64, color: impl Into<Color>) {
+        let center = center.into();
+        unsafe {
+            self.ui.imgui().DrawList_AddCircleFilled(
+                self.raw,
+                center.x,
+                center.y,
+                radius,
+                color.into().to_rgba() as i32,
+                null_mut(),
+            )
+        }
+    }
+
+    /// Text with the top-left corner at `pos`.
+    pub fn add_text(&self, pos: impl Into<Vec2>, color: impl Into<Color>, text: impl ImStr) {
+        let pos = pos.into();
+        self.ui.with_str(&text, |text| unsafe {
+            self.ui.imgui().DrawList_AddText(
+                self.raw,
+                pos.x,
+                pos.y,
+                color.into().to_rgba() as i32,
+                text,
+            )
+        })
+    }
+
+    /// Draw with `f` clipped to the rectangle. With `intersect`, the
+    /// rectangle is clipped by the current one.
+    pub fn with_clip_rect<R>(&self, rect: Rect, intersect: bool, f: impl FnOnce(&Self) -> R) -> R {
+        let mut intersect = intersect;
+        let Rect { min, max } = rect;
+        unsafe {
+            self.ui.imgui().DrawList_PushClipRect(
+                self.raw,
+                min.x,
+                min.y,
+                max.x,
+                max.y,
+                &mut intersect,
+            )
+        };
+        let result = f(self);
+        unsafe { self.ui.imgui().DrawList_PopClipRect(self.raw) };
+        result
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    fn draw_list(&self, raw: DrawList) -> DrawListRef<'_> {
+        DrawListRef { ui: self, raw }
+    }
+
+    /// Draw list of the current window.
+    pub fn window_draw_list(&self) -> DrawListRef<'_> {
+        self.draw_list(unsafe { self.imgui().GetWindowDrawList(self.raw_context()) })
+    }
+
+    /// Draw list over all the windows of the viewport.
+    pub fn foreground_draw_list(&self) -> DrawListRef<'_> {
+        self.draw_list(unsafe { self.imgui().GetForegroundDrawList(self.raw_context()) })
+    }
+
+    /// Draw list under all the windows of the viewport.
+    pub fn background_draw_list(&self) -> DrawListRef<'_> {
+        self.draw_list(unsafe { self.imgui().GetBackgroundDrawList(self.raw_context()) })
+    }
+}
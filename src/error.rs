@@ -0,0 +1,125 @@
+//! Errors of the crate.
+
+use std::{error::Error, ffi::NulError, fmt};
+
+use crate::FontSpec;
+
+/// Returned by [`crate::ImGui::load_strict`] when the installed ReaImGui
+/// does not provide everything the bindings were generated for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadError {
+    /// Functions that could not be resolved.
+    pub functions: Vec<&'static str>,
+    /// Constants (enum values) that could not be resolved.
+    pub constants: Vec<&'static str>,
+}
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReaImGui API is incomplete.")?;
+        if !self.functions.is_empty() {
+            write!(f, " Missing functions: {}.", self.functions.join(", "))?;
+        }
+        if !self.constants.is_empty() {
+            write!(f, " Missing constants: {}.", self.constants.join(", "))?;
+        }
+        Ok(())
+    }
+}
+impl Error for LoadError {}
+
+/// Returned when a ReaImGui context can not be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextError {
+    /// Label contains an interior NUL byte.
+    InvalidLabel(NulError),
+    /// `CreateContext` returned a null pointer.
+    CreationFailed,
+    /// `CreateFont` returned a null pointer for the font.
+    FontCreationFailed(FontSpec),
+}
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLabel(err) => write!(f, "Invalid context label: {}", err),
+            Self::CreationFailed => write!(f, "ReaImGui could not create a context"),
+            Self::FontCreationFailed(font) => write!(
+                f,
+                "ReaImGui could not create font {:?} of size {}",
+                font.family_or_file(),
+                font.size()
+            ),
+        }
+    }
+}
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidLabel(err) => Some(err),
+            Self::CreationFailed | Self::FontCreationFailed(_) => None,
+        }
+    }
+}
+impl From<NulError> for ContextError {
+    fn from(value: NulError) -> Self {
+        Self::InvalidLabel(value)
+    }
+}
+
+/// Returned by [`crate::NumberFormat::parse`] for a format, that can not be
+/// safely passed to ReaImGui.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// There is no conversion for the value.
+    MissingConversion,
+    /// Second conversion at the byte offset.
+    ExtraConversion(usize),
+    /// Conversion at the byte offset is not numeric, or uses width or flags
+    /// other than `'`.
+    Unsupported(usize),
+    /// Format contains a NUL byte.
+    Nul,
+}
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingConversion => write!(f, "Format has no conversion"),
+            Self::ExtraConversion(pos) => write!(f, "Extra conversion at {}", pos),
+            Self::Unsupported(pos) => write!(f, "Unsupported conversion at {}", pos),
+            Self::Nul => write!(f, "Format contains a NUL byte"),
+        }
+    }
+}
+impl Error for FormatError {}
+
+/// Returned when a [`crate::KeyChord`] can not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyChordError {
+    /// There is no key after the modifiers.
+    MissingKey,
+    /// Key, which name is not known.
+    UnknownKey(String),
+}
+impl fmt::Display for KeyChordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingKey => write!(f, "Shortcut has no key"),
+            Self::UnknownKey(name) => write!(f, "Unknown key: {:?}", name),
+        }
+    }
+}
+impl Error for KeyChordError {}
+
+/// Returned when a [`crate::Color`] can not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    /// Not a hex color of 3, 4, 6 or 8 digits.
+    InvalidHex(String),
+}
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHex(hex) => write!(f, "Invalid hex color: {:?}", hex),
+        }
+    }
+}
+impl Error for ColorError {}
@@ -0,0 +1,410 @@
+//! Typed combinations of `*Flags_*` constants.
+//!
+//! Every flag family of ReaImGui (`WindowFlags_*`, `TableFlags_*` etc.) has a
+//! type in [`bindings`](crate::bindings), generated with `flags!` macro. Flags
+//! are combined with `|` as usual, and converted to the ReaImGui values only
+//! when passed to a raw function, since the values are known only at runtime.
+//!
+//! ```no_run
+//! # use reaper_imgui::{Ui, WindowFlags};
+//! # fn f(ui: &Ui) {
+//! ui.window("tool")
+//!     .flags(WindowFlags::NO_COLLAPSE | WindowFlags::ALWAYS_AUTO_RESIZE)
+//!     .build(|ui| ui.text("Hello"));
+//! # }
+//! ```
+//!
+//! `*Flags_None` has no constant: it is [`WindowFlags::empty`](crate::WindowFlags::empty)
+//! and `Default`.
+//!
+//! Composite constants are the union of their members:
+//! `WindowFlags::NO_DECORATION` is `NO_TITLE_BAR | NO_RESIZE | NO_SCROLLBAR |
+//! NO_COLLAPSE`, so removing `NO_TITLE_BAR` from it leaves the other three.
+
+/// Operations shared by all the flag types, for functions generic over the
+/// family, like [`Ui::checkbox_flags`](crate::Ui::checkbox_flags).
+pub trait Flags: Copy {
+    /// Whether all the flags of `other` are set.
+    fn contains(self, other: Self) -> bool;
+
+    /// Insert or remove the flags.
+    fn set(&mut self, other: Self, value: bool);
+
+    /// Value for the raw functions, see the `to_raw` of the types.
+    fn to_raw(self, imgui: &crate::ImGui) -> i32;
+
+    /// Flags of the runtime value, see the `from_raw` of the types.
+    fn from_raw(imgui: &crate::ImGui, raw: i32) -> Self;
+}
+
+/// Define a flags type from the `Name = Constant` members.
+///
+/// Single-bit members are stored as local bits, and `to_raw` ORs the
+/// runtime values of the contained members. Members of a multi-bit field
+/// (`TableFlags_Sizing*`) get local bits as well, but are decoded by exact
+/// match of the field. Composite constants (`WindowFlags_NoDecoration`)
+/// are the union of their members, so set operations agree with the
+/// runtime values.
+macro_rules! flags {
+    (@consts $bit:expr; $flag:ident = $constant:ident, $($rest:tt)*) => {
+        #[doc = concat!("`", stringify!($constant), "`")]
+        pub const $flag: Self = Self(1 << $bit);
+        $crate::flags::flags!(@consts $bit + 1; $($rest)*);
+    };
+    (@consts $bit:expr;) => {};
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($flag:ident = $constant:ident,)*
+        }
+        fields {
+            $([$($field_flag:ident = $field_constant:ident,)*])*
+        }
+        composites {
+            $($composite:ident = $composite_constant:ident ($($part:ident)|*),)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name(u64);
+        #[allow(clippy::eq_op, clippy::identity_op)]
+        impl $name {
+            $crate::flags::flags!(
+                @consts 0u32; $($flag = $constant,)* $($($field_flag = $field_constant,)*)*
+            );
+            $(
+                #[doc = concat!(
+                    "`", stringify!($composite_constant), "`: ", stringify!($($part)|*), "."
+                )]
+                pub const $composite: Self = Self(0 $(| Self::$part.0)*);
+            )*
+
+            /// Single members, which runtime values are ORed.
+            const MEMBERS: &'static [(Self, &'static str)] = &[
+                $((Self::$flag, stringify!($constant)),)*
+                $($((Self::$field_flag, stringify!($field_constant)),)*)*
+            ];
+
+            /// Members of every multi-bit field.
+            const FIELDS: &'static [&'static [Self]] = &[$(&[$(Self::$field_flag,)*],)*];
+
+            /// No flags (`*_None`).
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// All flags of the family.
+            pub const fn all() -> Self {
+                Self(0 $(| Self::$flag.0)* $($(| Self::$field_flag.0)*)*)
+            }
+
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Whether all the flags of `other` are set.
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether any of the flags of `other` is set.
+            pub const fn intersects(self, other: Self) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Insert or remove the flags.
+            pub fn set(&mut self, other: Self, value: bool) {
+                match value {
+                    true => self.insert(other),
+                    false => self.remove(other),
+                }
+            }
+
+            /// Value for the raw functions.
+            ///
+            /// Panics if a contained flag is not provided by the installed
+            /// ReaImGui.
+            pub fn to_raw(self, imgui: &$crate::ImGui) -> i32 {
+                self.to_raw_with(|flag| Self::member_value(imgui, flag))
+            }
+
+            /// Flags, which runtime values are fully contained in `raw`.
+            /// A multi-bit field gives the member, that matches it exactly.
+            ///
+            /// Members with the value 0 (`PopupFlags_MouseButtonLeft`) are
+            /// not decoded: they are the same as the empty set.
+            pub fn from_raw(imgui: &$crate::ImGui, raw: i32) -> Self {
+                Self::from_raw_with(raw, |flag| Self::member_value(imgui, flag))
+            }
+
+            /// Runtime value of a single member.
+            fn member_value(imgui: &$crate::ImGui, flag: Self) -> Option<i32> {
+                $(
+                    if flag == Self::$flag {
+                        return imgui.$constant;
+                    }
+                )*
+                $($(
+                    if flag == Self::$field_flag {
+                        return imgui.$field_constant;
+                    }
+                )*)*
+                None
+            }
+
+            /// [`Self::to_raw`] with the runtime values of the members.
+            pub(crate) fn to_raw_with(self, value: impl Fn(Self) -> Option<i32>) -> i32 {
+                let mut raw = 0;
+                for (flag, constant) in Self::MEMBERS {
+                    if self.contains(*flag) {
+                        raw |= match value(*flag) {
+                            Some(value) => value,
+                            None => panic!(
+                                "Attempt to use a constant that has not been loaded: {}",
+                                constant
+                            ),
+                        };
+                    }
+                }
+                raw
+            }
+
+            /// [`Self::from_raw`] with the runtime values of the members.
+            pub(crate) fn from_raw_with(raw: i32, value: impl Fn(Self) -> Option<i32>) -> Self {
+                let mut flags = Self::empty();
+                let mut in_fields = Self::empty();
+                for field in Self::FIELDS {
+                    let mask = field.iter().filter_map(|flag| value(*flag)).fold(0, |a, b| a | b);
+                    for flag in field.iter() {
+                        in_fields.insert(*flag);
+                        match value(*flag) {
+                            Some(value) if value != 0 && raw & mask == value => {
+                                flags.insert(*flag)
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+                for (flag, _) in Self::MEMBERS {
+                    if in_fields.contains(*flag) {
+                        continue;
+                    }
+                    match value(*flag) {
+                        Some(value) if value != 0 && raw & value == value => flags.insert(*flag),
+                        _ => (),
+                    }
+                }
+                flags
+            }
+        }
+        impl $crate::flags::Flags for $name {
+            fn contains(self, other: Self) -> bool {
+                self.contains(other)
+            }
+
+            fn set(&mut self, other: Self, value: bool) {
+                self.set(other, value)
+            }
+
+            fn to_raw(self, imgui: &$crate::ImGui) -> i32 {
+                self.to_raw(imgui)
+            }
+
+            fn from_raw(imgui: &$crate::ImGui, raw: i32) -> Self {
+                Self::from_raw(imgui, raw)
+            }
+        }
+        impl std::ops::BitOr for $name {
+            type Output = Self;
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+        impl std::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+        impl std::ops::BitAnd for $name {
+            type Output = Self;
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+        impl std::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+        impl std::ops::Sub for $name {
+            type Output = Self;
+            /// Flags of `self`, that are not in `rhs`.
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 & !rhs.0)
+            }
+        }
+        impl std::ops::Not for $name {
+            type Output = Self;
+            fn not(self) -> Self {
+                Self(Self::all().0 & !self.0)
+            }
+        }
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}(", stringify!($name))?;
+                let mut first = true;
+                for (flag, name) in Self::MEMBERS {
+                    if self.contains(*flag) {
+                        if !first {
+                            write!(f, " | ")?;
+                        }
+                        write!(f, "{}", name)?;
+                        first = false;
+                    }
+                }
+                write!(f, ")")
+            }
+        }
+    };
+}
+pub(crate) use flags;
+
+#[cfg(test)]
+mod tests {
+    use crate::{PopupFlags, TableFlags, WindowFlags};
+
+    // Values of Dear ImGui 1.89, that ReaImGui 0.8 provides.
+    const WINDOW: &[(WindowFlags, i32)] = &[
+        (WindowFlags::NO_TITLE_BAR, 1 << 0),
+        (WindowFlags::NO_RESIZE, 1 << 1),
+        (WindowFlags::NO_MOVE, 1 << 2),
+        (WindowFlags::NO_SCROLLBAR, 1 << 3),
+        (WindowFlags::NO_COLLAPSE, 1 << 5),
+        (WindowFlags::NO_MOUSE_INPUTS, 1 << 9),
+        (WindowFlags::NO_NAV_INPUTS, 1 << 18),
+        (WindowFlags::NO_NAV_FOCUS, 1 << 19),
+    ];
+    const TABLE: &[(TableFlags, i32)] = &[
+        (TableFlags::ROW_BG, 1 << 6),
+        (TableFlags::BORDERS_INNER_H, 1 << 7),
+        (TableFlags::BORDERS_OUTER_H, 1 << 8),
+        (TableFlags::BORDERS_INNER_V, 1 << 9),
+        (TableFlags::BORDERS_OUTER_V, 1 << 10),
+        (TableFlags::SIZING_FIXED_FIT, 1 << 13),
+        (TableFlags::SIZING_FIXED_SAME, 2 << 13),
+        (TableFlags::SIZING_STRETCH_PROP, 3 << 13),
+        (TableFlags::SIZING_STRETCH_SAME, 4 << 13),
+    ];
+    const POPUP: &[(PopupFlags, i32)] = &[
+        (PopupFlags::MOUSE_BUTTON_LEFT, 0),
+        (PopupFlags::MOUSE_BUTTON_RIGHT, 1),
+        (PopupFlags::MOUSE_BUTTON_MIDDLE, 2),
+        (PopupFlags::NO_OPEN_OVER_EXISTING_POPUP, 1 << 5),
+        (PopupFlags::ANY_POPUP_ID, 1 << 7),
+        (PopupFlags::ANY_POPUP_LEVEL, 1 << 8),
+    ];
+
+    fn values<F: PartialEq + Copy>(values: &[(F, i32)]) -> impl Fn(F) -> Option<i32> + '_ {
+        move |flag| {
+            values
+                .iter()
+                .find(|(member, _)| *member == flag)
+                .map(|(_, value)| *value)
+        }
+    }
+
+    #[test]
+    fn composite_is_union_of_members() {
+        assert_eq!(
+            WindowFlags::NO_TITLE_BAR
+                | WindowFlags::NO_RESIZE
+                | WindowFlags::NO_SCROLLBAR
+                | WindowFlags::NO_COLLAPSE,
+            WindowFlags::NO_DECORATION
+        );
+        assert!(WindowFlags::NO_INPUTS.contains(WindowFlags::NO_NAV));
+        assert!(!WindowFlags::NO_NAV.contains(WindowFlags::NO_INPUTS));
+        assert_eq!(
+            WindowFlags::NO_DECORATION.to_raw_with(values(WINDOW)),
+            0b10_1011
+        );
+    }
+
+    #[test]
+    fn composite_remove() {
+        let borders = TableFlags::BORDERS - TableFlags::BORDERS_INNER_V;
+        assert!(!borders.contains(TableFlags::BORDERS));
+        assert!(borders.contains(TableFlags::BORDERS_H));
+        assert_eq!(borders.to_raw_with(values(TABLE)), 0b101 << 8 | 1 << 7);
+
+        let mut flags = WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE;
+        flags.remove(WindowFlags::NO_TITLE_BAR);
+        assert!(!flags.contains(WindowFlags::NO_DECORATION));
+        assert_eq!(flags.to_raw_with(values(WINDOW)), 0b10_1110);
+    }
+
+    #[test]
+    fn not_excludes_composite_members() {
+        let not_title_bar = !WindowFlags::NO_TITLE_BAR;
+        assert!(!not_title_bar.contains(WindowFlags::NO_TITLE_BAR));
+        assert!(!not_title_bar.contains(WindowFlags::NO_DECORATION));
+        assert_eq!(
+            (WindowFlags::NO_DECORATION & not_title_bar).to_raw_with(values(WINDOW)),
+            0b10_1010
+        );
+    }
+
+    #[test]
+    fn composite_round_trip() {
+        for flags in [
+            WindowFlags::NO_DECORATION,
+            WindowFlags::NO_INPUTS | WindowFlags::NO_TITLE_BAR,
+            WindowFlags::NO_NAV,
+            WindowFlags::NO_DECORATION - WindowFlags::NO_RESIZE,
+        ] {
+            let raw = flags.to_raw_with(values(WINDOW));
+            assert_eq!(WindowFlags::from_raw_with(raw, values(WINDOW)), flags);
+        }
+        assert_eq!(
+            TableFlags::from_raw_with(0b1111 << 7, values(TABLE)),
+            TableFlags::BORDERS
+        );
+    }
+
+    #[test]
+    fn field_decodes_by_exact_match() {
+        assert_eq!(
+            TableFlags::from_raw_with(3 << 13 | 1 << 6, values(TABLE)),
+            TableFlags::SIZING_STRETCH_PROP | TableFlags::ROW_BG
+        );
+        for (flag, _) in TABLE {
+            let raw = flag.to_raw_with(values(TABLE));
+            assert_eq!(TableFlags::from_raw_with(raw, values(TABLE)), *flag);
+        }
+    }
+
+    #[test]
+    fn zero_field_member_is_empty() {
+        assert_eq!(PopupFlags::MOUSE_BUTTON_LEFT.to_raw_with(values(POPUP)), 0);
+        assert_eq!(
+            PopupFlags::from_raw_with(0, values(POPUP)),
+            PopupFlags::empty()
+        );
+        assert_eq!(
+            PopupFlags::from_raw_with(2 | 1 << 5, values(POPUP)),
+            PopupFlags::MOUSE_BUTTON_MIDDLE | PopupFlags::NO_OPEN_OVER_EXISTING_POPUP
+        );
+        assert_eq!(
+            PopupFlags::from_raw_with(0b11 << 7 | 1, values(POPUP)),
+            PopupFlags::ANY_POPUP | PopupFlags::MOUSE_BUTTON_RIGHT
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Fonts of a context.
+//!
+//! ReaImGui fonts have to be created and attached to a context before its
+//! first frame. [`FontRegistry`] collects the fonts up front, and
+//! [`OwnedContext::with_fonts`](crate::OwnedContext::with_fonts) creates and
+//! attaches them together with the context.
+//!
+//! ```no_run
+//! # use reaper_imgui::{ConfigFlags, FontRegistry, FontSpec, ImGui, OwnedContext};
+//! # fn f(imgui: &ImGui) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut fonts = FontRegistry::new();
+//! let title = fonts.add(FontSpec::new("sans-serif", 20).bold());
+//! let mono = fonts.add(FontSpec::new("monospace", 14));
+//! let mut ctx = OwnedContext::with_fonts(imgui, "my context", ConfigFlags::empty(), fonts)?;
+//! if let Some(ui) = ctx.frame() {
+//!     ui.window("fonts").build(|ui| {
+//!         ui.with_font(title, |ui| ui.text("Title"));
+//!         ui.with_font(mono, |ui| ui.text("let x = 1;"));
+//!     });
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    ffi::c_char,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::{scratch::ImStr, Font, FontFlags, ImGui, Ui};
+
+/// Font, declared in a [`FontRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontSpec {
+    family_or_file: String,
+    size: i32,
+    bold: bool,
+    italic: bool,
+}
+impl FontSpec {
+    /// Font family (`sans-serif`, `serif`, `monospace`, or a system font
+    /// name) or a path to a font file, and size in pixels.
+    pub fn new(family_or_file: impl Into<String>, size: i32) -> Self {
+        Self {
+            family_or_file: family_or_file.into(),
+            size,
+            bold: false,
+            italic: false,
+        }
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn family_or_file(&self) -> &str {
+        &self.family_or_file
+    }
+
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    pub fn flags(&self) -> FontFlags {
+        let mut flags = FontFlags::empty();
+        flags.set(FontFlags::BOLD, self.bold);
+        flags.set(FontFlags::ITALIC, self.italic);
+        flags
+    }
+}
+
+/// Font of a context, given by [`FontRegistry::add`].
+///
+/// Valid only for the contexts created from the same registry: it remembers
+/// the registry, so a font from another one is never confused with a font
+/// at the same index. A clone of the registry shares the fonts added before
+/// it was made, but not the ones added to either of them after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontId {
+    registry: u64,
+    index: usize,
+}
+
+/// Unique ids of the registries, `0` is never given.
+static NEXT_REGISTRY: AtomicU64 = AtomicU64::new(1);
+
+/// Registry, that gives [`FontId`]s: its own id, and the registries it was
+/// cloned from, with the number of fonts shared with each of them.
+#[derive(Debug, Default, Clone)]
+struct Owner {
+    id: u64,
+    shared: Vec<(u64, usize)>,
+}
+impl Owner {
+    fn new() -> Self {
+        Self {
+            id: NEXT_REGISTRY.fetch_add(1, Ordering::Relaxed),
+            shared: Vec::new(),
+        }
+    }
+
+    fn owns(&self, id: FontId) -> bool {
+        id.registry == self.id
+            || self
+                .shared
+                .iter()
+                .any(|(registry, count)| id.registry == *registry && id.index < *count)
+    }
+}
+
+/// Fonts to be attached to a new context.
+///
+/// A clone gets its own id, so the fonts added to the clone and to the
+/// original after cloning are never confused.
+#[derive(Debug)]
+pub struct FontRegistry {
+    owner: Owner,
+    fonts: Vec<FontSpec>,
+}
+impl Default for FontRegistry {
+    fn default() -> Self {
+        Self {
+            owner: Owner::new(),
+            fonts: Vec::new(),
+        }
+    }
+}
+impl Clone for FontRegistry {
+    fn clone(&self) -> Self {
+        let mut owner = Owner::new();
+        owner.shared = self.owner.shared.clone();
+        owner.shared.push((self.owner.id, self.fonts.len()));
+        Self {
+            owner,
+            fonts: self.fonts.clone(),
+        }
+    }
+}
+impl FontRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare the font.
+    pub fn add(&mut self, font: FontSpec) -> FontId {
+        self.fonts.push(font);
+        FontId {
+            registry: self.owner.id,
+            index: self.fonts.len() - 1,
+        }
+    }
+
+    /// Spec of the font, `None` if it is from another registry.
+    pub fn get(&self, id: FontId) -> Option<&FontSpec> {
+        match self.owner.owns(id) {
+            true => self.fonts.get(id.index),
+            false => None,
+        }
+    }
+
+    /// Create all the fonts. Returns the spec of the first font ReaImGui
+    /// failed to create, if any.
+    pub(crate) fn create(&self, imgui: &ImGui) -> Result<ContextFonts, FontSpec> {
+        let mut name = Vec::new();
+        let fonts = self
+            .fonts
+            .iter()
+            .map(|spec| {
+                name.clear();
+                spec.family_or_file.write_to(&mut name);
+                name.push(0);
+                let mut flags = spec.flags().to_raw(imgui);
+                let font = unsafe {
+                    imgui.CreateFont(name.as_ptr() as *const c_char, spec.size, &mut flags)
+                };
+                match font.is_null() {
+                    true => Err(spec.clone()),
+                    false => Ok(font),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(ContextFonts {
+            owner: self.owner.clone(),
+            fonts,
+        })
+    }
+}
+
+/// Fonts attached to a context, with the registry they were created from.
+#[derive(Debug, Default)]
+pub(crate) struct ContextFonts {
+    owner: Owner,
+    fonts: Vec<Font>,
+}
+impl ContextFonts {
+    pub(crate) fn raw(&self) -> &[Font] {
+        &self.fonts
+    }
+
+    fn get(&self, id: FontId) -> Option<Font> {
+        match self.owner.owns(id) {
+            true => self.fonts.get(id.index).copied(),
+            false => None,
+        }
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    /// Build `f` with the font.
+    ///
+    /// # Panics
+    ///
+    /// If the font is not from the registry of this context.
+    pub fn with_font<R>(&self, font: FontId, f: impl FnOnce(&Self) -> R) -> R {
+        let raw = self.font(font);
+        unsafe { self.imgui().PushFont(self.raw_context(), raw) };
+        let result = f(self);
+        unsafe { self.imgui().PopFont(self.raw_context()) };
+        result
+    }
+
+    /// Raw handle of the font.
+    ///
+    /// # Panics
+    ///
+    /// If the font is not from the registry of this context.
+    pub fn font(&self, font: FontId) -> Font {
+        match self.fonts().get(font) {
+            Some(raw) => raw,
+            None => panic!("{:?} is not registered in this context", font),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context_fonts(registry: &FontRegistry) -> ContextFonts {
+        ContextFonts {
+            owner: registry.owner.clone(),
+            fonts: vec![std::ptr::null_mut(); registry.fonts.len()],
+        }
+    }
+
+    #[test]
+    fn diverged_clones_reject_each_other() {
+        let mut original = FontRegistry::new();
+        let shared = original.add(FontSpec::new("sans-serif", 13));
+        let mut clone = original.clone();
+        let in_original = original.add(FontSpec::new("serif", 13));
+        let in_clone = clone.add(FontSpec::new("monospace", 13));
+        assert_ne!(in_original, in_clone);
+
+        let (original_ctx, clone_ctx) = (context_fonts(&original), context_fonts(&clone));
+        for (registry, ctx) in [(&original, &original_ctx), (&clone, &clone_ctx)] {
+            assert_eq!(registry.get(shared).unwrap().family_or_file(), "sans-serif");
+            assert!(ctx.get(shared).is_some());
+        }
+        assert!(original_ctx.get(in_original).is_some());
+        assert!(original_ctx.get(in_clone).is_none());
+        assert!(original.get(in_clone).is_none());
+        assert!(clone_ctx.get(in_clone).is_some());
+        assert!(clone_ctx.get(in_original).is_none());
+        assert!(clone.get(in_original).is_none());
+    }
+
+    #[test]
+    fn clone_of_clone_shares_the_common_fonts() {
+        let mut first = FontRegistry::new();
+        let a = first.add(FontSpec::new("a", 10));
+        let mut second = first.clone();
+        let b = second.add(FontSpec::new("b", 10));
+        let third = second.clone();
+        assert_eq!(third.get(a).unwrap().family_or_file(), "a");
+        assert_eq!(third.get(b).unwrap().family_or_file(), "b");
+        assert!(first.get(b).is_none());
+    }
+
+    #[test]
+    fn other_registry_is_rejected() {
+        let mut first = FontRegistry::new();
+        let mut second = FontRegistry::new();
+        let font = first.add(FontSpec::new("a", 10));
+        second.add(FontSpec::new("b", 10));
+        assert!(second.get(font).is_none());
+        assert!(context_fonts(&second).get(font).is_none());
+    }
+}
@@ -0,0 +1,345 @@
+//! Typed printf formats of numeric widgets.
+//!
+//! `DragDouble`, `SliderInt`, `InputDouble` etc. take a C printf format.
+//! A stray `%` in a unit name or a conversion not matching the value type is
+//! undefined behaviour in C, so the safe layer accepts only a
+//! [`NumberFormat`], which always produces exactly one conversion of the
+//! right type.
+//!
+//! ```
+//! use reaper_imgui::NumberFormat;
+//!
+//! let fmt = NumberFormat::new().precision(1).suffix(" %");
+//! assert_eq!(fmt.to_printf::<f64>(), "%.1f %%");
+//! assert_eq!(fmt.to_printf::<i32>(), "%d %%");
+//!
+//! let parsed = NumberFormat::parse("gain: %.2f dB").unwrap();
+//! assert_eq!(parsed, NumberFormat::new().prefix("gain: ").precision(2).suffix(" dB"));
+//! assert!(NumberFormat::parse("%s").is_err());
+//! ```
+
+use std::io::Write;
+
+use crate::{error::FormatError, scratch::ImStr, Scalar};
+
+/// Notation of floating point values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// `123.456` (`%f`).
+    #[default]
+    Fixed,
+    /// `1.235e+02` (`%e`).
+    Scientific,
+    /// Shortest of the two (`%g`).
+    General,
+}
+impl Notation {
+    fn conversion(self) -> u8 {
+        match self {
+            Self::Fixed => b'f',
+            Self::Scientific => b'e',
+            Self::General => b'g',
+        }
+    }
+}
+
+/// Format of a value in [`Drag`](crate::Drag), [`Slider`](crate::Slider) and
+/// [`Input`](crate::Input).
+///
+/// Integers are always formatted with `%d`: precision and notation apply
+/// only to floating point values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    prefix: String,
+    suffix: String,
+    precision: u8,
+    notation: Notation,
+    thousands: bool,
+}
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl NumberFormat {
+    /// Default format of ImGui: `%.3f` and `%d`.
+    pub fn new() -> Self {
+        Self {
+            prefix: String::new(),
+            suffix: String::new(),
+            precision: 3,
+            notation: Notation::Fixed,
+            thousands: false,
+        }
+    }
+
+    /// Digits after the decimal point.
+    pub fn precision(mut self, precision: u8) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Text before the value. `%` is shown literally, NUL bytes are dropped.
+    pub fn prefix(mut self, prefix: impl AsRef<str>) -> Self {
+        self.prefix = prefix.as_ref().replace('\0', "");
+        self
+    }
+
+    /// Text after the value, e.g. a unit name. `%` is shown literally, NUL
+    /// bytes are dropped.
+    pub fn suffix(mut self, suffix: impl AsRef<str>) -> Self {
+        self.suffix = suffix.as_ref().replace('\0', "");
+        self
+    }
+
+    /// Group thousands with the locale separator (printf `'` flag).
+    ///
+    /// The flag is POSIX-only, so it is not emitted on Windows, where the C
+    /// runtime rejects it.
+    pub fn thousands(mut self, thousands: bool) -> Self {
+        self.thousands = thousands;
+        self
+    }
+
+    /// Parse a printf format with exactly one numeric conversion.
+    ///
+    /// Accepted are `%%`, and a conversion of `d`, `i`, `f`, `F`, `e`, `E`,
+    /// `g` or `G` with optional `'` flag and precision. Width and other
+    /// flags are not supported.
+    pub fn parse(format: &str) -> Result<Self, FormatError> {
+        let bytes = format.as_bytes();
+        let mut result = Self::new();
+        let mut literal = String::new();
+        let mut found = false;
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let start = idx;
+            let Some(offset) = format[idx..].find('%') else {
+                literal.push_str(&format[idx..]);
+                break;
+            };
+            literal.push_str(&format[idx..idx + offset]);
+            idx += offset + 1;
+            if bytes.get(idx) == Some(&b'%') {
+                literal.push('%');
+                idx += 1;
+                continue;
+            }
+            let conversion_start = start + offset;
+            if found {
+                return Err(FormatError::ExtraConversion(conversion_start));
+            }
+            found = true;
+            result.prefix = std::mem::take(&mut literal);
+            if bytes.get(idx) == Some(&b'\'') {
+                result.thousands = true;
+                idx += 1;
+            }
+            let mut precision = None;
+            if bytes.get(idx) == Some(&b'.') {
+                idx += 1;
+                let digits = bytes[idx..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_digit())
+                    .count();
+                precision = match digits {
+                    0 => Some(0),
+                    _ => match format[idx..idx + digits].parse::<u8>() {
+                        Ok(precision) => Some(precision),
+                        Err(_) => return Err(FormatError::Unsupported(conversion_start)),
+                    },
+                };
+                idx += digits;
+            }
+            result.notation = match bytes.get(idx) {
+                Some(b'd' | b'i') => {
+                    result.precision = 0;
+                    precision = None;
+                    Notation::Fixed
+                }
+                Some(b'f' | b'F') => Notation::Fixed,
+                Some(b'e' | b'E') => Notation::Scientific,
+                Some(b'g' | b'G') => Notation::General,
+                _ => return Err(FormatError::Unsupported(conversion_start)),
+            };
+            if let Some(precision) = precision {
+                result.precision = precision;
+            }
+            idx += 1;
+        }
+        if !found {
+            return Err(FormatError::MissingConversion);
+        }
+        result.suffix = literal;
+        if result.prefix.contains('\0') || result.suffix.contains('\0') {
+            return Err(FormatError::Nul);
+        }
+        Ok(result)
+    }
+
+    /// The printf format for the values of type `T`.
+    pub fn to_printf<T: Scalar>(&self) -> String {
+        let mut buf = Vec::new();
+        self.write_printf(T::IS_INTEGER, &mut buf);
+        String::from_utf8(buf).expect("format is built from valid UTF-8")
+    }
+
+    pub(crate) fn write_printf(&self, integer: bool, buf: &mut Vec<u8>) {
+        write_escaped(&self.prefix, buf);
+        buf.push(b'%');
+        if self.thousands && cfg!(not(windows)) {
+            buf.push(b'\'');
+        }
+        match integer {
+            true => buf.push(b'd'),
+            false => {
+                // Writing into a Vec can not fail.
+                let _ = write!(buf, ".{}", self.precision);
+                buf.push(self.notation.conversion());
+            }
+        }
+        write_escaped(&self.suffix, buf);
+    }
+
+    /// Printf format for the values of type `T`, written directly into the
+    /// scratch buffer.
+    pub(crate) fn printf_for<T: Scalar>(&self) -> impl ImStr + '_ {
+        Printf {
+            format: self,
+            integer: T::IS_INTEGER,
+        }
+    }
+}
+
+struct Printf<'a> {
+    format: &'a NumberFormat,
+    integer: bool,
+}
+impl ImStr for Printf<'_> {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        self.format.write_printf(self.integer, buf)
+    }
+}
+
+fn write_escaped(text: &str, buf: &mut Vec<u8>) {
+    for byte in text.bytes() {
+        if byte == b'%' {
+            buf.push(b'%');
+        }
+        buf.push(byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_is_escaped() {
+        let fmt = NumberFormat::new().prefix("100% = ").suffix(" %%");
+        assert_eq!(fmt.to_printf::<f64>(), "100%% = %.3f %%%%");
+        assert_eq!(NumberFormat::parse(&fmt.to_printf::<f64>()), Ok(fmt));
+        assert_eq!(
+            NumberFormat::parse("%% %d%%"),
+            Ok(NumberFormat::new().prefix("% ").precision(0).suffix("%"))
+        );
+    }
+
+    #[test]
+    fn nul_is_stripped() {
+        let fmt = NumberFormat::new().prefix("a\0b").suffix("\0 dB\0");
+        assert_eq!(fmt.to_printf::<i32>(), "ab%d dB");
+        assert_eq!(NumberFormat::parse("a\0%d"), Err(FormatError::Nul));
+        assert_eq!(NumberFormat::parse("%d\0"), Err(FormatError::Nul));
+    }
+
+    #[test]
+    fn precision() {
+        let fmt = NumberFormat::new().precision(0);
+        assert_eq!(fmt.to_printf::<f64>(), "%.0f");
+        assert_eq!(NumberFormat::parse("%.f"), Ok(fmt.clone()));
+        assert_eq!(NumberFormat::parse("%.0f"), Ok(fmt));
+        assert_eq!(
+            NumberFormat::new().precision(12).to_printf::<f64>(),
+            "%.12f"
+        );
+        // Integers ignore the precision.
+        assert_eq!(NumberFormat::new().precision(2).to_printf::<i32>(), "%d");
+        assert_eq!(
+            NumberFormat::parse("%.255e"),
+            Ok(NumberFormat::new()
+                .precision(255)
+                .notation(Notation::Scientific))
+        );
+        assert_eq!(
+            NumberFormat::parse("x%.256f"),
+            Err(FormatError::Unsupported(1))
+        );
+    }
+
+    #[test]
+    fn width_and_flags_are_unsupported() {
+        for format in ["%5.2f", "%-d", "%+f", "%08.3f", "%ld", "%s", "%x", "%"] {
+            assert_eq!(
+                NumberFormat::parse(format),
+                Err(FormatError::Unsupported(0)),
+                "{}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn conversion_count() {
+        assert_eq!(
+            NumberFormat::parse("no value %%"),
+            Err(FormatError::MissingConversion)
+        );
+        assert_eq!(
+            NumberFormat::parse("%d..%d"),
+            Err(FormatError::ExtraConversion(4))
+        );
+    }
+
+    #[test]
+    fn notation() {
+        let fmt = NumberFormat::new().precision(2);
+        assert_eq!(
+            fmt.clone()
+                .notation(Notation::Scientific)
+                .to_printf::<f64>(),
+            "%.2e"
+        );
+        assert_eq!(
+            fmt.clone().notation(Notation::General).to_printf::<f64>(),
+            "%.2g"
+        );
+        assert_eq!(
+            NumberFormat::parse("%.2G"),
+            Ok(fmt.notation(Notation::General))
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn thousands() {
+        let fmt = NumberFormat::new().thousands(true);
+        assert_eq!(fmt.to_printf::<i32>(), "%'d");
+        assert_eq!(fmt.to_printf::<f64>(), "%'.3f");
+        assert_eq!(NumberFormat::parse("%'.3f"), Ok(fmt));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn thousands_omitted_on_windows() {
+        let fmt = NumberFormat::new().thousands(true);
+        assert_eq!(fmt.to_printf::<i32>(), "%d");
+        assert_eq!(fmt.to_printf::<f64>(), "%.3f");
+        assert_eq!(NumberFormat::parse("%'.3f"), Ok(fmt));
+    }
+}
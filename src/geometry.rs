@@ -0,0 +1,358 @@
+//! Points and rectangles in screen coordinates.
+//!
+//! With the `mint` or `glam` feature, [`Vec2`] converts to and from
+//! `mint::Vector2<f64>`, `mint::Point2<f64>` and `glam::DVec2`.
+//!
+//! ```
+//! # use reaper_imgui::{Rect, Vec2};
+//! let button = Rect::from_pos_size((10.0, 10.0), (80.0, 20.0));
+//! assert!(button.contains(Vec2::new(50.0, 25.0)));
+//! assert_eq!(button.center(), Vec2::new(50.0, 20.0));
+//!
+//! let visible = Rect::new((0.0, 0.0), (40.0, 100.0));
+//! assert_eq!(
+//!     button.intersection(visible),
+//!     Some(Rect::new((10.0, 10.0), (40.0, 30.0)))
+//! );
+//! assert_eq!(button.translate(Vec2::new(100.0, 0.0)).intersection(visible), None);
+//! ```
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Point or size.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Component-wise minimum.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Component-wise maximum.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    pub fn length(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+}
+impl From<(f64, f64)> for Vec2 {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self { x, y }
+    }
+}
+impl From<Vec2> for (f64, f64) {
+    fn from(value: Vec2) -> Self {
+        (value.x, value.y)
+    }
+}
+impl From<[f64; 2]> for Vec2 {
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self { x, y }
+    }
+}
+impl From<Vec2> for [f64; 2] {
+    fn from(value: Vec2) -> Self {
+        [value.x, value.y]
+    }
+}
+
+macro_rules! vec2_op {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl $trait for Vec2 {
+            type Output = Self;
+            fn $fn(self, rhs: Self) -> Self {
+                Self::new(self.x $op rhs.x, self.y $op rhs.y)
+            }
+        }
+        impl $assign_trait for Vec2 {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+vec2_op!(Add, add, AddAssign, add_assign, +);
+vec2_op!(Sub, sub, SubAssign, sub_assign, -);
+
+macro_rules! vec2_scale {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl $trait<f64> for Vec2 {
+            type Output = Self;
+            fn $fn(self, rhs: f64) -> Self {
+                Self::new(self.x $op rhs, self.y $op rhs)
+            }
+        }
+        impl $assign_trait<f64> for Vec2 {
+            fn $assign_fn(&mut self, rhs: f64) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+vec2_scale!(Mul, mul, MulAssign, mul_assign, *);
+vec2_scale!(Div, div, DivAssign, div_assign, /);
+
+impl Neg for Vec2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(feature = "mint")]
+impl From<mint::Vector2<f64>> for Vec2 {
+    fn from(value: mint::Vector2<f64>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+#[cfg(feature = "mint")]
+impl From<Vec2> for mint::Vector2<f64> {
+    fn from(value: Vec2) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+#[cfg(feature = "mint")]
+impl From<mint::Point2<f64>> for Vec2 {
+    fn from(value: mint::Point2<f64>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+#[cfg(feature = "mint")]
+impl From<Vec2> for mint::Point2<f64> {
+    fn from(value: Vec2) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DVec2> for Vec2 {
+    fn from(value: glam::DVec2) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+#[cfg(feature = "glam")]
+impl From<Vec2> for glam::DVec2 {
+    fn from(value: Vec2) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+/// Axis-aligned rectangle from the top-left `min` to the bottom-right `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rect {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+impl Rect {
+    pub fn new(min: impl Into<Vec2>, max: impl Into<Vec2>) -> Self {
+        Self {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+
+    /// Rectangle with the top-left corner at `pos`.
+    pub fn from_pos_size(pos: impl Into<Vec2>, size: impl Into<Vec2>) -> Self {
+        let pos = pos.into();
+        Self::new(pos, pos + size.into())
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.width(), self.height())
+    }
+
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Whether the point is inside, edges included.
+    ///
+    /// Unlike `ImRect::Contains` of ImGui, which excludes the `max` edges,
+    /// so that a point on the shared edge of two adjacent rectangles is in
+    /// both of them.
+    pub fn contains(&self, point: impl Into<Vec2>) -> bool {
+        let point = point.into();
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Whether `other` is fully inside.
+    pub fn contains_rect(&self, other: Rect) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Whether the rectangles overlap with a non-empty area.
+    pub fn intersects(&self, other: Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Overlapping area, if not empty.
+    pub fn intersection(&self, other: Rect) -> Option<Rect> {
+        let rect = Rect {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        };
+        match rect.width() > 0.0 && rect.height() > 0.0 {
+            true => Some(rect),
+            false => None,
+        }
+    }
+
+    /// Smallest rectangle containing both.
+    pub fn union(&self, other: Rect) -> Rect {
+        Rect {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Moved by `offset`.
+    pub fn translate(&self, offset: impl Into<Vec2>) -> Rect {
+        let offset = offset.into();
+        Rect {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Grown by `amount` on every side, shrunk if negative. Shrinking by
+    /// more than half the size gives a rectangle with negative size, that
+    /// contains nothing.
+    pub fn expand(&self, amount: f64) -> Rect {
+        let amount = Vec2::new(amount, amount);
+        Rect {
+            min: self.min - amount,
+            max: self.max + amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f64, max: f64) -> Rect {
+        Rect::new((min, min), (max, max))
+    }
+
+    #[test]
+    fn touching_edges_do_not_intersect() {
+        let left = Rect::new((0.0, 0.0), (10.0, 10.0));
+        let right = Rect::new((10.0, 0.0), (20.0, 10.0));
+        assert_eq!(left.intersection(right), None);
+        assert!(!left.intersects(right));
+        // Touching corners only.
+        assert_eq!(left.intersection(square(10.0, 20.0)), None);
+        assert_eq!(
+            left.intersection(Rect::new((9.0, 5.0), (20.0, 20.0))),
+            Some(Rect::new((9.0, 5.0), (10.0, 10.0)))
+        );
+    }
+
+    #[test]
+    fn contains_edges() {
+        let rect = square(0.0, 10.0);
+        for point in [(0.0, 0.0), (10.0, 10.0), (0.0, 10.0), (10.0, 5.0)] {
+            assert!(rect.contains(point), "{:?}", point);
+        }
+        for point in [(-0.1, 5.0), (10.1, 5.0), (5.0, 10.1), (5.0, -0.1)] {
+            assert!(!rect.contains(point), "{:?}", point);
+        }
+        // The shared edge is in both.
+        let right = Rect::new((10.0, 0.0), (20.0, 10.0));
+        assert!(rect.contains((10.0, 5.0)) && right.contains((10.0, 5.0)));
+    }
+
+    #[test]
+    fn contains_rect() {
+        let rect = square(0.0, 10.0);
+        assert!(rect.contains_rect(rect));
+        assert!(rect.contains_rect(square(2.0, 8.0)));
+        assert!(!rect.contains_rect(square(2.0, 11.0)));
+        assert!(!square(2.0, 8.0).contains_rect(rect));
+    }
+
+    #[test]
+    fn union() {
+        let rect = square(0.0, 10.0);
+        assert_eq!(rect.union(square(2.0, 8.0)), rect);
+        assert_eq!(
+            rect.union(Rect::new((20.0, -5.0), (30.0, 5.0))),
+            Rect::new((0.0, -5.0), (30.0, 10.0))
+        );
+        assert_eq!(
+            rect.union(square(20.0, 30.0)),
+            square(20.0, 30.0).union(rect)
+        );
+    }
+
+    #[test]
+    fn expand() {
+        let rect = square(0.0, 10.0);
+        assert_eq!(rect.expand(2.0), square(-2.0, 12.0));
+        assert_eq!(rect.expand(-2.0), square(2.0, 8.0));
+        assert_eq!(rect.expand(-5.0).size(), Vec2::ZERO);
+        let inverted = rect.expand(-6.0);
+        assert_eq!(inverted.size(), Vec2::new(-2.0, -2.0));
+        assert_eq!(inverted.center(), rect.center());
+        assert!(!inverted.contains(rect.center()));
+        assert_eq!(inverted.intersection(rect), None);
+    }
+
+    #[test]
+    fn vec2_ops() {
+        let mut v = Vec2::new(1.0, 2.0);
+        v += Vec2::new(2.0, 2.0);
+        v *= 2.0;
+        assert_eq!(v, Vec2::new(6.0, 8.0));
+        assert_eq!(v.length(), 10.0);
+        assert_eq!(-v / 2.0, Vec2::new(-3.0, -4.0));
+        assert_eq!(<[f64; 2]>::from(v), [6.0, 8.0]);
+        assert_eq!(Vec2::from((6.0, 8.0)), v);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_conversions() {
+        let v = Vec2::new(1.5, -2.0);
+        let vector: mint::Vector2<f64> = v.into();
+        assert_eq!((vector.x, vector.y), (1.5, -2.0));
+        assert_eq!(Vec2::from(vector), v);
+        let point: mint::Point2<f64> = v.into();
+        assert_eq!((point.x, point.y), (1.5, -2.0));
+        assert_eq!(Vec2::from(point), v);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_conversions() {
+        let v = Vec2::new(1.5, -2.0);
+        let glam: glam::DVec2 = v.into();
+        assert_eq!(glam, glam::DVec2::new(1.5, -2.0));
+        assert_eq!(Vec2::from(glam), v);
+    }
+}
@@ -0,0 +1,398 @@
+//! Widget IDs from Rust values.
+//!
+//! ImGui identifies widgets by hashing their labels within the ID stack.
+//! Here IDs are derived from any [`StableHash`] value: loop indices, keys,
+//! GUIDs etc. The value is hashed with FNV-1a over a defined byte encoding,
+//! which does not depend on the process, platform or Rust version, so the
+//! same value gives the same ID across sessions (and e.g. keeps the state
+//! saved in the ini file).
+//!
+//! Pointers give the per-object IDs, as `PushID(void*)` does in ImGui, and
+//! any [`std::hash::Hash`] value gives an ID with [`Id::from_hash`].
+//!
+//! ```no_run
+//! # use reaper_imgui::{Label, Ui};
+//! # struct Track { name: String, guid: u128 }
+//! # fn f(ui: &Ui, tracks: &[Track]) {
+//! for track in tracks {
+//!     ui.with_id(track.guid, |ui| {
+//!         ui.text(&track.name);
+//!         ui.button("Mute").build();
+//!     });
+//! }
+//! // The label changes, but the widget keeps its identity.
+//! let count = tracks.len();
+//! ui.button(Label::new(&format!("{count} tracks")).stable_id("tracks")).build();
+//! # }
+//! ```
+
+use std::hash::{Hash, Hasher};
+
+use crate::{scratch::ImStr, Ui};
+
+/// ID derived from a hashable value.
+///
+/// Passed as a string of 16 hex digits.
+///
+/// ```
+/// # use reaper_imgui::Id;
+/// assert_eq!(Id::new(42u8).value(), 0xaf63a74c8601927d);
+/// assert_eq!(Id::new(42usize), Id::new(42u64));
+/// assert_ne!(Id::new(("a", "bc")), Id::new(("ab", "c")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+impl Id {
+    pub fn new(value: impl StableHash) -> Self {
+        let mut hasher = Fnv1a::default();
+        value.stable_hash(&mut hasher);
+        Self(hasher.finish())
+    }
+
+    /// ID of any [`Hash`] value, like a `#[derive(Hash)]` type.
+    ///
+    /// It is hashed with the same fixed-key FNV-1a, so the ID does not
+    /// change between runs of the same build. But `Hash` implementations
+    /// write integers in the native byte order and `usize` in its native
+    /// width, and may change between Rust versions, so the ID may differ on
+    /// other platforms or builds. Use [`StableHash`] for IDs, that must be
+    /// kept in the saved state.
+    ///
+    /// ```
+    /// # use reaper_imgui::Id;
+    /// #[derive(Hash)]
+    /// struct Send {
+    ///     track: u32,
+    ///     index: u32,
+    /// }
+    /// assert_eq!(
+    ///     Id::from_hash(&Send { track: 1, index: 2 }),
+    ///     Id::from_hash(&Send { track: 1, index: 2 })
+    /// );
+    /// ```
+    pub fn from_hash(value: &(impl Hash + ?Sized)) -> Self {
+        let mut hasher = Fnv1a::default();
+        value.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+impl ImStr for Id {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        for shift in (0..16).rev() {
+            buf.push(DIGITS[(self.0 >> (shift * 4)) as usize & 0xF]);
+        }
+    }
+}
+
+/// Value hashed as a defined sequence of bytes, unlike [`std::hash::Hash`],
+/// which may change between Rust versions and platforms.
+///
+/// - Integers are little-endian, `usize` and `isize` as 64 bit, `bool` as a
+///   byte and `char` as `u32`.
+/// - Strings are their UTF-8 bytes followed by `0xFF`, which can not appear
+///   in UTF-8, so `("a", "bc")` and `("ab", "c")` differ.
+/// - Slices, arrays and vectors are their length as `u64` followed by the
+///   elements, tuples are their elements in order.
+/// - `Option` is a byte `0` for `None`, or `1` followed by the value.
+/// - Pointers are their address as `u64`. It identifies the object only
+///   while it is alive, and is not kept across sessions.
+///
+/// Own types can be hashed by their fields:
+///
+/// ```
+/// # use reaper_imgui::{Id, StableHash};
+/// # use std::hash::{Hash, Hasher};
+/// struct Send {
+///     track: u32,
+///     index: u32,
+/// }
+/// impl StableHash for Send {
+///     fn stable_hash<H: Hasher>(&self, state: &mut H) {
+///         (self.track, self.index).stable_hash(state);
+///     }
+/// }
+/// assert_eq!(Id::new(Send { track: 1, index: 2 }), Id::new((1u32, 2u32)));
+/// ```
+pub trait StableHash {
+    fn stable_hash<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! stable_hash_le {
+    ($($ty:ty $(as $cast:ty)?),*) => {
+        $(
+            impl StableHash for $ty {
+                fn stable_hash<H: Hasher>(&self, state: &mut H) {
+                    state.write(&(*self $(as $cast)?).to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+stable_hash_le!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize as u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize as i64,
+    bool as u8,
+    char as u32
+);
+
+impl StableHash for str {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.as_bytes());
+        state.write(&[0xFF]);
+    }
+}
+impl StableHash for String {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().stable_hash(state)
+    }
+}
+impl<T: StableHash> StableHash for [T] {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (self.len() as u64).stable_hash(state);
+        for item in self {
+            item.stable_hash(state);
+        }
+    }
+}
+impl<T: StableHash, const N: usize> StableHash for [T; N] {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().stable_hash(state)
+    }
+}
+impl<T: StableHash> StableHash for Vec<T> {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().stable_hash(state)
+    }
+}
+impl<T: StableHash> StableHash for Option<T> {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            None => 0u8.stable_hash(state),
+            Some(value) => {
+                1u8.stable_hash(state);
+                value.stable_hash(state);
+            }
+        }
+    }
+}
+impl<T: StableHash + ?Sized> StableHash for &T {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (**self).stable_hash(state)
+    }
+}
+impl<T: ?Sized> StableHash for *const T {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (self.cast::<()>() as usize).stable_hash(state)
+    }
+}
+impl<T: ?Sized> StableHash for *mut T {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.cast_const().stable_hash(state)
+    }
+}
+impl StableHash for Id {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.stable_hash(state)
+    }
+}
+
+macro_rules! stable_hash_tuple {
+    ($(($($name:ident),+)),*) => {
+        $(
+            impl<$($name: StableHash),+> StableHash for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn stable_hash<H: Hasher>(&self, state: &mut H) {
+                    let ($($name,)+) = self;
+                    $($name.stable_hash(state);)+
+                }
+            }
+        )*
+    };
+}
+stable_hash_tuple!((A), (A, B), (A, B, C), (A, B, C, D));
+
+/// FNV-1a, 64 bit.
+struct Fnv1a(u64);
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+/// Label with an explicit ID.
+///
+/// ImGui shows the text before `##` and uses the rest for the ID:
+/// - `text##id`, made by [`Label::id`], keeps the text out of the ID, so
+///   widgets with the same text do not clash.
+/// - `text###id`, made by [`Label::stable_id`], makes the ID independent of
+///   the text and of the ID stack, so the text can change every frame.
+///
+/// Text after a `##` in the text itself is dropped, so it can not change
+/// the ID.
+///
+/// ```
+/// # use reaper_imgui::{ImStr, Label};
+/// let mut buf = Vec::new();
+/// Label::new("Gain##old").id(1u8).write_to(&mut buf);
+/// assert!(buf.starts_with(b"Gain##") && !buf.starts_with(b"Gain##old"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Label<'a> {
+    text: &'a str,
+    id: Option<(Id, bool)>,
+}
+impl<'a> Label<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, id: None }
+    }
+
+    /// ID combined with the current ID stack: `text##id`.
+    pub fn id(mut self, id: impl StableHash) -> Self {
+        self.id = Some((Id::new(id), false));
+        self
+    }
+
+    /// ID independent of the text: `text###id`.
+    pub fn stable_id(mut self, id: impl StableHash) -> Self {
+        self.id = Some((Id::new(id), true));
+        self
+    }
+}
+impl ImStr for Label<'_> {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        let text = match self.text.find("##") {
+            Some(end) => &self.text[..end],
+            None => self.text,
+        };
+        text.write_to(buf);
+        if let Some((id, stable)) = self.id {
+            buf.extend_from_slice(match stable {
+                true => b"###",
+                false => b"##",
+            });
+            id.write_to(buf);
+        }
+    }
+}
+
+/// Pushed ID, popped on drop.
+#[must_use = "ID is popped as soon as the token is dropped"]
+#[derive(Debug)]
+pub struct IdToken<'ui> {
+    ui: &'ui Ui<'ui>,
+}
+impl<'ui> IdToken<'ui> {
+    /// Pop the ID now.
+    pub fn pop(self) {}
+}
+impl Drop for IdToken<'_> {
+    fn drop(&mut self) {
+        unsafe { self.ui.imgui().PopID(self.ui.raw_context()) }
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    /// Push the ID of the value to the ID stack until the token is dropped.
+    pub fn push_id(&self, id: impl StableHash) -> IdToken<'_> {
+        let id = Id::new(id);
+        self.with_str(&id, |id| unsafe {
+            self.imgui().PushID(self.raw_context(), id)
+        });
+        IdToken { ui: self }
+    }
+
+    /// Build `f` with the ID of the value pushed.
+    pub fn with_id<R>(&self, id: impl StableHash, f: impl FnOnce(&Self) -> R) -> R {
+        let _token = self.push_id(id);
+        f(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(label: Label) -> String {
+        let mut buf = Vec::new();
+        label.write_to(&mut buf);
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn label_strips_id_from_text() {
+        assert_eq!(label(Label::new("Gain##old")), "Gain");
+        assert_eq!(label(Label::new("Gain###old")), "Gain");
+        assert_eq!(label(Label::new("##hidden")), "");
+        assert_eq!(label(Label::new("Gain")), "Gain");
+        let id = format!("{:016x}", Id::new(1u8).value());
+        assert_eq!(
+            label(Label::new("Gain##old").id(1u8)),
+            format!("Gain##{}", id)
+        );
+        assert_eq!(
+            label(Label::new("Gain##old").stable_id(1u8)),
+            format!("Gain###{}", id)
+        );
+    }
+
+    #[test]
+    fn equal_values_give_equal_ids() {
+        assert_eq!(Id::new("track"), Id::new(String::from("track")));
+        assert_eq!(Id::new((1u32, "a")), Id::new((1u32, "a")));
+        assert_eq!(Id::new([1u8, 2]), Id::new(vec![1u8, 2]));
+        assert_eq!(Id::new(Some("a")), Id::new(Some(String::from("a"))));
+        assert_ne!(Id::new(Some(0u8)), Id::new(None::<u8>));
+        assert_ne!(Id::new(1u8), Id::new(1u16));
+        assert_ne!(
+            Id::new([[1u8], [2]].as_slice()),
+            Id::new([[1u8, 2]].as_slice())
+        );
+    }
+
+    #[test]
+    fn pointer_ids() {
+        let (a, b) = (1u8, 1u8);
+        let ptr = &a as *const u8;
+        assert_eq!(Id::new(ptr), Id::new(ptr as *mut u8));
+        assert_eq!(Id::new(ptr), Id::new(ptr as usize));
+        assert_ne!(Id::new(ptr), Id::new(&b as *const u8));
+        let slice: *const [u8] = &[a, b][..];
+        assert_eq!(Id::new(slice), Id::new(slice.cast::<u8>()));
+    }
+
+    #[test]
+    fn hash_ids() {
+        #[derive(Hash)]
+        struct Key(u32, &'static str);
+        assert_eq!(Id::from_hash(&Key(1, "a")), Id::from_hash(&Key(1, "a")));
+        assert_ne!(Id::from_hash(&Key(1, "a")), Id::from_hash(&Key(2, "a")));
+        assert_eq!(Id::from_hash("a"), Id::from_hash(&String::from("a")));
+    }
+}
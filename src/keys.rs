@@ -0,0 +1,368 @@
+//! Keyboard state: keys, modifiers and key chords.
+//!
+//! [`KeyChord`] is parsed from and formatted to REAPER-style text like
+//! `Ctrl+Shift+S`, so the same definition can be stored in a config file,
+//! shown in a menu and checked every frame. [`Mods::SHORTCUT`] is shown as
+//! `Cmd` on macOS and `Ctrl` elsewhere, and matches the held modifier it
+//! stands for, see [`Mods::resolve`].
+//!
+//! ```no_run
+//! # use reaper_imgui::{Key, KeyChord, Mods, Ui};
+//! # fn f(ui: &Ui) {
+//! let save = KeyChord::new(Mods::SHORTCUT | Mods::SHIFT, Key::S);
+//! if ui.menu_item("Save as...", Some(save), false, true) || ui.shortcut_pressed(&save) {
+//!     // save as...
+//! }
+//! if ui.is_key_down(Key::Space) {
+//!     ui.text(format!("held for {:.1}s", ui.key_down_duration(Key::Space)));
+//! }
+//! # }
+//! ```
+
+use std::{fmt, io::Write, str::FromStr};
+
+use crate::{error::KeyChordError, scratch::ImStr, Key, Mods, Ui};
+
+const MACOS: bool = cfg!(target_os = "macos");
+
+/// Key with modifiers, e.g. `Ctrl+Shift+S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub mods: Mods,
+    pub key: Key,
+}
+impl KeyChord {
+    pub const fn new(mods: Mods, key: Key) -> Self {
+        Self { mods, key }
+    }
+}
+impl From<Key> for KeyChord {
+    fn from(key: Key) -> Self {
+        Self::new(Mods::empty(), key)
+    }
+}
+/// Modifiers and key joined with `+`.
+///
+/// ```
+/// # use reaper_imgui::{Key, KeyChord, Mods};
+/// let chord: KeyChord = "shift+f5".parse().unwrap();
+/// assert_eq!(chord, KeyChord::new(Mods::SHIFT, Key::F5));
+/// assert_eq!(chord.to_string(), "Shift+F5");
+/// // `Cmd` is the platform shortcut modifier everywhere, `Ctrl` is
+/// // the shortcut modifier outside macOS.
+/// assert_eq!("Cmd+S".parse(), Ok(KeyChord::new(Mods::SHORTCUT, Key::S)));
+/// #[cfg(not(target_os = "macos"))]
+/// assert_eq!("Ctrl+Num+".parse(), "Cmd+KeypadAdd".parse::<KeyChord>());
+/// ```
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (flag, name) in mod_names() {
+            if self.mods.contains(flag) && !self.is_alias_shown(flag) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        f.write_str(key_name(self.key))
+    }
+}
+impl FromStr for KeyChord {
+    type Err = KeyChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut mods = Mods::empty();
+        let mut rest = s.trim();
+        while let Some((head, tail)) = rest.split_once('+') {
+            match parse_mod(head.trim()) {
+                Some(flag) => mods.insert(flag),
+                None => break,
+            }
+            rest = tail.trim_start();
+        }
+        let name = rest.trim();
+        if name.is_empty() || parse_mod(name).is_some() {
+            return Err(KeyChordError::MissingKey);
+        }
+        Key::ALL
+            .iter()
+            .find(|key| {
+                key_name(**key).eq_ignore_ascii_case(name) || key.name().eq_ignore_ascii_case(name)
+            })
+            .map(|key| Self::new(mods, *key))
+            .ok_or_else(|| KeyChordError::UnknownKey(name.to_string()))
+    }
+}
+impl ImStr for KeyChord {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        write!(buf, "{}", self).expect("Writing to Vec can not fail");
+    }
+}
+
+impl KeyChord {
+    /// Whether the platform modifier is already shown as
+    /// [`Mods::SHORTCUT`].
+    fn is_alias_shown(&self, flag: Mods) -> bool {
+        flag == shortcut_alias() && self.mods.contains(Mods::SHORTCUT)
+    }
+}
+
+impl Mods {
+    /// Same modifiers with [`Mods::SHORTCUT`] replaced by the one it stands
+    /// for: [`Mods::CTRL`], or [`Mods::SUPER`] (`Cmd`) on macOS.
+    ///
+    /// ```
+    /// # use reaper_imgui::Mods;
+    /// let save_as = Mods::SHORTCUT | Mods::SHIFT;
+    /// # #[cfg(not(target_os = "macos"))]
+    /// assert_eq!(save_as.resolve(), Mods::CTRL | Mods::SHIFT);
+    /// # #[cfg(target_os = "macos")]
+    /// # assert_eq!(save_as.resolve(), Mods::SUPER | Mods::SHIFT);
+    /// ```
+    pub fn resolve(self) -> Self {
+        match self.contains(Mods::SHORTCUT) {
+            true => (self - Mods::SHORTCUT) | shortcut_alias(),
+            false => self,
+        }
+    }
+}
+
+/// Platform modifier, that [`Mods::SHORTCUT`] stands for.
+fn shortcut_alias() -> Mods {
+    match MACOS {
+        true => Mods::SUPER,
+        false => Mods::CTRL,
+    }
+}
+
+/// Modifiers in the display order with their platform names.
+fn mod_names() -> [(Mods, &'static str); 5] {
+    [
+        (Mods::SHORTCUT, if MACOS { "Cmd" } else { "Ctrl" }),
+        (Mods::CTRL, if MACOS { "Control" } else { "Ctrl" }),
+        (Mods::SUPER, if cfg!(windows) { "Win" } else { "Super" }),
+        (Mods::ALT, if MACOS { "Opt" } else { "Alt" }),
+        (Mods::SHIFT, "Shift"),
+    ]
+}
+
+/// Modifier by any of its names, case insensitive.
+fn parse_mod(name: &str) -> Option<Mods> {
+    let name = name.to_ascii_lowercase();
+    Some(match name.as_str() {
+        "shortcut" | "cmd" | "command" => Mods::SHORTCUT,
+        "ctrl" | "control" if MACOS => Mods::CTRL,
+        "ctrl" | "control" => Mods::SHORTCUT,
+        "super" | "win" | "meta" => Mods::SUPER,
+        "alt" | "opt" | "option" => Mods::ALT,
+        "shift" => Mods::SHIFT,
+        _ => return None,
+    })
+}
+
+/// REAPER-style key name. Keys not listed here use [`Key::name`].
+fn key_name(key: Key) -> &'static str {
+    match key {
+        Key::LeftArrow => "Left",
+        Key::RightArrow => "Right",
+        Key::UpArrow => "Up",
+        Key::DownArrow => "Down",
+        Key::Escape => "Esc",
+        Key::Delete => "Del",
+        Key::Insert => "Ins",
+        Key::Minus => "-",
+        Key::Equal => "=",
+        Key::Comma => ",",
+        Key::Period => ".",
+        Key::Slash => "/",
+        Key::Backslash => "\\",
+        Key::Semicolon => ";",
+        Key::Apostrophe => "'",
+        Key::LeftBracket => "[",
+        Key::RightBracket => "]",
+        Key::GraveAccent => "`",
+        Key::Keypad0 => "Num0",
+        Key::Keypad1 => "Num1",
+        Key::Keypad2 => "Num2",
+        Key::Keypad3 => "Num3",
+        Key::Keypad4 => "Num4",
+        Key::Keypad5 => "Num5",
+        Key::Keypad6 => "Num6",
+        Key::Keypad7 => "Num7",
+        Key::Keypad8 => "Num8",
+        Key::Keypad9 => "Num9",
+        Key::KeypadAdd => "Num+",
+        Key::KeypadSubtract => "Num-",
+        Key::KeypadMultiply => "Num*",
+        Key::KeypadDivide => "Num/",
+        Key::KeypadDecimal => "Num.",
+        Key::KeypadEnter => "NumEnter",
+        Key::KeypadEqual => "Num=",
+        key => key.name(),
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    pub fn is_key_down(&self, key: Key) -> bool {
+        unsafe {
+            self.imgui()
+                .IsKeyDown(self.raw_context(), key.to_raw(self.imgui()))
+        }
+    }
+
+    /// Whether the key was pressed during the frame. With `repeat`, also
+    /// reports the repeats while it is held.
+    pub fn is_key_pressed(&self, key: Key, repeat: bool) -> bool {
+        let mut repeat = repeat;
+        unsafe {
+            self.imgui()
+                .IsKeyPressed(self.raw_context(), key.to_raw(self.imgui()), &mut repeat)
+        }
+    }
+
+    pub fn is_key_released(&self, key: Key) -> bool {
+        unsafe {
+            self.imgui()
+                .IsKeyReleased(self.raw_context(), key.to_raw(self.imgui()))
+        }
+    }
+
+    /// Seconds the key is held, negative if it is not.
+    pub fn key_down_duration(&self, key: Key) -> f64 {
+        unsafe {
+            self.imgui()
+                .GetKeyDownDuration(self.raw_context(), key.to_raw(self.imgui()))
+        }
+    }
+
+    /// Number of presses during the frame, with the repeats after
+    /// `repeat_delay` seconds every `rate` seconds.
+    pub fn key_pressed_amount(&self, key: Key, repeat_delay: f64, rate: f64) -> i32 {
+        unsafe {
+            self.imgui().GetKeyPressedAmount(
+                self.raw_context(),
+                key.to_raw(self.imgui()),
+                repeat_delay,
+                rate,
+            )
+        }
+    }
+
+    /// Held modifiers.
+    ///
+    /// [`Mods::SHORTCUT`] is an alias of a platform modifier, so it is
+    /// never reported: compare with [`Ui::key_mods_match`] instead.
+    pub fn key_mods(&self) -> Mods {
+        Mods::from_raw(self.imgui(), self.raw_key_mods()) - Mods::SHORTCUT
+    }
+
+    /// Whether exactly these modifiers are held. Aliases are resolved.
+    pub fn key_mods_match(&self, mods: Mods) -> bool {
+        Mods::from_raw(self.imgui(), self.raw_key_mods()).resolve() == mods.resolve()
+    }
+
+    /// Whether the key of the chord was pressed during the frame with exactly
+    /// its modifiers held. See [`Ui::is_key_pressed`] for `repeat`.
+    pub fn is_chord_pressed(&self, chord: KeyChord, repeat: bool) -> bool {
+        self.key_mods_match(chord.mods) && self.is_key_pressed(chord.key, repeat)
+    }
+
+    /// Whether the shortcut was triggered: the chord was pressed, without
+    /// repeat, and no widget (e.g. a text input) is active.
+    pub fn shortcut_pressed(&self, chord: &KeyChord) -> bool {
+        let item_active = unsafe { self.imgui().IsAnyItemActive(self.raw_context()) };
+        !item_active && self.is_chord_pressed(*chord, false)
+    }
+
+    fn raw_key_mods(&self) -> i32 {
+        unsafe { self.imgui().GetKeyMods(self.raw_context()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(s: &str) -> KeyChord {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn shortcut_resolves_to_platform_modifier() {
+        assert_eq!(Mods::SHORTCUT.resolve(), shortcut_alias());
+        assert_eq!(
+            (Mods::SHORTCUT | Mods::SHIFT).resolve(),
+            shortcut_alias() | Mods::SHIFT
+        );
+        assert_eq!((Mods::ALT | Mods::SHIFT).resolve(), Mods::ALT | Mods::SHIFT);
+        // The alias held together with the shortcut is the same modifier.
+        assert_eq!(
+            (Mods::SHORTCUT | shortcut_alias()).resolve(),
+            shortcut_alias()
+        );
+    }
+
+    #[test]
+    fn ctrl_matches_held_control() {
+        let ctrl_s = chord("Ctrl+S");
+        assert_eq!(ctrl_s.key, Key::S);
+        // What `key_mods_match` compares, with Ctrl held.
+        assert_eq!(ctrl_s.mods.resolve(), Mods::CTRL);
+        assert_eq!(chord("Cmd+S").mods.resolve(), shortcut_alias());
+    }
+
+    #[test]
+    fn parse_display_round_trip() {
+        for text in [
+            "S",
+            "Ctrl+S",
+            "Cmd+Shift+S",
+            "Control+Opt+F5",
+            "Super+Alt+Left",
+            "Win+Num+",
+            "Shift+-",
+            "Ctrl+Shift+Alt+Super+Del",
+        ] {
+            let parsed = chord(text);
+            assert_eq!(chord(&parsed.to_string()), parsed, "{}", text);
+        }
+    }
+
+    #[test]
+    fn display_parse_round_trip() {
+        let mods = [
+            Mods::SHORTCUT,
+            Mods::CTRL,
+            Mods::SUPER,
+            Mods::ALT,
+            Mods::SHIFT,
+        ];
+        for bits in 0..1 << mods.len() {
+            let mods = mods
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| bits & (1 << idx) != 0)
+                .fold(Mods::empty(), |acc, (_, flag)| acc | *flag);
+            for key in [Key::S, Key::KeypadAdd, Key::Minus, Key::Alpha1] {
+                let original = KeyChord::new(mods, key);
+                let parsed = chord(&original.to_string());
+                assert_eq!(parsed.key, key);
+                assert_eq!(parsed.mods.resolve(), mods.resolve(), "{}", original);
+            }
+        }
+    }
+
+    #[test]
+    fn super_is_not_shortcut() {
+        let chord = KeyChord::new(Mods::SUPER, Key::S);
+        assert_eq!(chord.to_string().parse(), Ok(chord));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("".parse::<KeyChord>(), Err(KeyChordError::MissingKey));
+        assert_eq!("Ctrl+".parse::<KeyChord>(), Err(KeyChordError::MissingKey));
+        assert_eq!("Shift".parse::<KeyChord>(), Err(KeyChordError::MissingKey));
+        assert_eq!(
+            "Ctrl+Nope".parse::<KeyChord>(),
+            Err(KeyChordError::UnknownKey("Nope".into()))
+        );
+    }
+}
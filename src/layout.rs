@@ -0,0 +1,119 @@
+//! Scoped layout parameters: item width, text wrapping, keyboard focus and
+//! button repeat.
+//!
+//! Each `push_*` returns a [`LayoutToken`], that pops the parameter when
+//! dropped, and each `with_*` applies it to the widgets built by a closure.
+//!
+//! ```no_run
+//! # use reaper_imgui::Ui;
+//! # fn f(ui: &Ui, gain: &mut f64, name: &mut String) {
+//! ui.with_item_width(120.0, |ui| {
+//!     ui.drag("gain", gain).build();
+//!     ui.input_text("name", name).build();
+//! });
+//! // Only this widget is affected: stretched to the window edge.
+//! ui.drag("full width", gain).width(-1.0).build();
+//! # }
+//! ```
+
+use crate::{scratch::opt_ptr, Context, ImGui, Ui};
+
+/// Pushed layout parameter, popped on drop.
+#[must_use = "parameter is popped as soon as the token is dropped"]
+pub struct LayoutToken<'ui> {
+    ui: &'ui Ui<'ui>,
+    pop: unsafe fn(&ImGui, Context),
+}
+impl<'ui> LayoutToken<'ui> {
+    /// Pop the parameter now.
+    pub fn pop(self) {}
+}
+impl Drop for LayoutToken<'_> {
+    fn drop(&mut self) {
+        unsafe { (self.pop)(self.ui.imgui(), self.ui.raw_context()) }
+    }
+}
+impl std::fmt::Debug for LayoutToken<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LayoutToken").finish_non_exhaustive()
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    fn layout_token(&self, pop: unsafe fn(&ImGui, Context)) -> LayoutToken<'_> {
+        LayoutToken { ui: self, pop }
+    }
+
+    /// Width of the following items until the token is dropped.
+    ///
+    /// Positive is the width in pixels, negative aligns the right edge to
+    /// that distance from the window edge.
+    pub fn push_item_width(&self, width: f64) -> LayoutToken<'_> {
+        unsafe { self.imgui().PushItemWidth(self.raw_context(), width) };
+        self.layout_token(|imgui, ctx| unsafe { imgui.PopItemWidth(ctx) })
+    }
+
+    /// Build `f` with the item width, see [`Ui::push_item_width`].
+    pub fn with_item_width<R>(&self, width: f64, f: impl FnOnce(&Self) -> R) -> R {
+        let _token = self.push_item_width(width);
+        f(self)
+    }
+
+    /// Width of the next item only, see [`Ui::push_item_width`].
+    pub fn set_next_item_width(&self, width: f64) {
+        unsafe { self.imgui().SetNextItemWidth(self.raw_context(), width) }
+    }
+
+    /// Wrap text at the position in window coordinates until the token is
+    /// dropped. `None` wraps at the end of the window, `Some(0.0)`
+    /// disables wrapping.
+    pub fn push_text_wrap_pos(&self, wrap_local_pos_x: Option<f64>) -> LayoutToken<'_> {
+        let mut pos = wrap_local_pos_x;
+        unsafe {
+            self.imgui()
+                .PushTextWrapPos(self.raw_context(), opt_ptr(pos.as_mut()))
+        };
+        self.layout_token(|imgui, ctx| unsafe { imgui.PopTextWrapPos(ctx) })
+    }
+
+    /// Build `f` with the text wrap position, see [`Ui::push_text_wrap_pos`].
+    pub fn with_text_wrap_pos<R>(
+        &self,
+        wrap_local_pos_x: Option<f64>,
+        f: impl FnOnce(&Self) -> R,
+    ) -> R {
+        let _token = self.push_text_wrap_pos(wrap_local_pos_x);
+        f(self)
+    }
+
+    /// Whether the following items can be focused with Tab, until the token
+    /// is dropped.
+    pub fn push_allow_keyboard_focus(&self, allow: bool) -> LayoutToken<'_> {
+        unsafe {
+            self.imgui()
+                .PushAllowKeyboardFocus(self.raw_context(), allow)
+        };
+        self.layout_token(|imgui, ctx| unsafe { imgui.PopAllowKeyboardFocus(ctx) })
+    }
+
+    /// Build `f` with keyboard focus allowed or not, see
+    /// [`Ui::push_allow_keyboard_focus`].
+    pub fn with_allow_keyboard_focus<R>(&self, allow: bool, f: impl FnOnce(&Self) -> R) -> R {
+        let _token = self.push_allow_keyboard_focus(allow);
+        f(self)
+    }
+
+    /// Whether the following buttons report clicks repeatedly while held,
+    /// until the token is dropped.
+    pub fn push_button_repeat(&self, repeat: bool) -> LayoutToken<'_> {
+        unsafe { self.imgui().PushButtonRepeat(self.raw_context(), repeat) };
+        self.layout_token(|imgui, ctx| unsafe { imgui.PopButtonRepeat(ctx) })
+    }
+
+    /// Build `f` with button repeat enabled or not, see
+    /// [`Ui::push_button_repeat`].
+    pub fn with_button_repeat<R>(&self, repeat: bool, f: impl FnOnce(&Self) -> R) -> R {
+        let _token = self.push_button_repeat(repeat);
+        f(self)
+    }
+}
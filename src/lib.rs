@@ -1,49 +1,44 @@
 //! Simple bindings to ReaImGui Reaper extension.
 //!
-//! They are unsafe and hard to use because of all c-types.
+//! Raw [`ImGui`] methods are unsafe and hard to use because of all c-types.
 //! But, at least, it works, and can be used raw as in `rea-rs` crate, as well
 //! as in `reaper-rs`. But for the last one it should be published with the recent version,
 //! to make it possible for selecting back-end with features.
 //!
+//! On top of them there is a safe layer: [`OwnedContext`] owns a context,
+//! and gives a [`Ui`] for every frame, which methods draw the widgets.
+//!
+//! ReaImGui must be used from the REAPER main thread only. [`ImGui`] and all
+//! handle types are `!Send` and `!Sync`, and in debug builds every call
+//! panics if made from a thread other than the one that called [`ImGui::load`].
+//!
+//! With `debug-validate` feature every handle argument is checked with
+//! `ValidatePtr` before the call, and a destroyed `Context` or a stale
+//! `DrawList` panics with the function and argument name instead of crashing
+//! REAPER.
+//!
 //! Minimal example crate can be found on GitHub repository:
 //! https://github.com/Levitanus/reaper-imgui/tree/master/hello_world_example
 //!
 //! ```no_run
 //! use rea_rs::{PluginContext, Reaper, Timer};
 //! use rea_rs_macros::reaper_extension_plugin;
-//! use reaper_imgui::{
-//!     Context, DrawList, DrawListSplitter, Font, ImGui, Image, ImageSet, ListClipper, Resource,
-//!     TextFilter, Viewport,
-//! };
-//! use std::{error::Error, mem::MaybeUninit};
-//!
-//! use c_str_macro::c_str;
+//! use reaper_imgui::{ConfigFlags, ImGui, OwnedContext};
+//! use std::{cell::RefCell, error::Error, sync::Arc};
 //!
 //! #[derive(Debug)]
 //! struct GuiRunner {
-//!     imgui: ImGui,
-//!     ctx: Context,
+//!     ctx: OwnedContext,
 //! }
 //! impl Timer for GuiRunner {
 //!     fn run(&mut self) -> Result<(), Box<dyn Error>> {
-//!         let (mut open, mut flags) = (MaybeUninit::new(true), MaybeUninit::zeroed());
-//!         unsafe {
-//!             self.imgui.Begin(
-//!                 self.ctx,
-//!                 c_str!("my window").as_ptr(),
-//!                 open.as_mut_ptr(),
-//!                 flags.as_mut_ptr(),
-//!             )
-//!         };
-//!         let open = unsafe { open.assume_init() };
-//!         println!("ctx: {:?} open: {:?}", self.ctx, open);
-//!         if open {
-//!             unsafe {
-//!                 self.imgui.Text(self.ctx, c_str!("Hello World!").as_ptr());
-//!             }
-//!             unsafe { self.imgui.End(self.ctx) };
-//!         } else {
-//!             unsafe { self.imgui.End(self.ctx) };
+//!         let mut open = true;
+//!         if let Some(ui) = self.ctx.frame() {
+//!             ui.window("my window").opened(&mut open).build(|ui| {
+//!                 ui.text("Hello World!");
+//!             });
+//!         }
+//!         if !open {
 //!             self.stop();
 //!         }
 //!         Ok(())
@@ -59,18 +54,64 @@
 //!     Reaper::init_global(context);
 //!     let rpr = Reaper::get_mut();
 //!     let imgui = ImGui::load(context);
-//!     let mut zero = MaybeUninit::zeroed();
-//!     let ctx = unsafe { imgui.CreateContext(c_str!("my context").as_ptr(), zero.as_mut_ptr()) };
+//!     let ctx = OwnedContext::new(&imgui, "my context", ConfigFlags::empty())?;
 //!
-//!     rpr.register_timer(Box::new(GuiRunner { imgui, ctx }));
+//!     rpr.register_timer(Arc::new(RefCell::new(GuiRunner { ctx })));
 //!
 //!     Ok(())
 //! }
 //! ```
 
 pub mod bindings;
+pub mod button;
+pub mod checker;
+pub mod color;
+pub mod context;
+pub mod drag_drop;
+pub mod draw;
+pub mod error;
+pub mod flags;
+pub mod fonts;
+pub mod format;
+pub mod geometry;
+pub mod id;
+pub mod keys;
+pub mod layout;
+pub mod scratch;
+pub mod style;
+pub mod ui;
+pub mod widgets;
+pub mod window;
+
+// The generator helpers are tested with the library, since the tests of
+// `build.rs` are not run.
+#[cfg(test)]
+#[path = "../build/names.rs"]
+mod names;
 
 pub use bindings::{
-    Context, DrawList, DrawListSplitter, Font, ImGui, Image, ImageSet, ListClipper, Resource,
-    TextFilter, Viewport,
+    ButtonFlags, Col, ColorEditFlags, ComboFlags, Cond, ConfigFlags, Context, Dir, DragDropFlags,
+    DrawFlags, DrawList, DrawListSplitter, FocusedFlags, Font, FontFlags, HoveredFlags, ImGui,
+    Image, ImageSet, InputTextFlags, Key, ListClipper, Mods, MouseButton, MouseCursor, PopupFlags,
+    Resource, SelectableFlags, SliderFlags, SortDirection, StyleVar, TabBarFlags, TabItemFlags,
+    TableBgTarget, TableColumnFlags, TableFlags, TableRowFlags, TextFilter, TreeNodeFlags,
+    Viewport, WindowFlags,
 };
+pub use button::Button;
+pub use checker::CheckedImGui;
+pub use color::Color;
+pub use context::OwnedContext;
+pub use drag_drop::DragDropPayload;
+pub use draw::DrawListRef;
+pub use error::{ColorError, ContextError, FormatError, KeyChordError, LoadError};
+pub use fonts::{FontId, FontRegistry, FontSpec};
+pub use format::{Notation, NumberFormat};
+pub use geometry::{Rect, Vec2};
+pub use id::{Id, IdToken, Label, StableHash};
+pub use keys::KeyChord;
+pub use layout::LayoutToken;
+pub use scratch::ImStr;
+pub use style::{Style, StyleToken, StyleValue};
+pub use ui::{LastItem, Ui};
+pub use widgets::{Drag, ImageView, Input, Scalar, ScalarValue, Slider, TableSortSpec, Variants};
+pub use window::{Child, Window};
@@ -0,0 +1,222 @@
+//! Conversion of Rust strings to the NUL-terminated ones ReaImGui expects.
+//!
+//! Every [`OwnedContext`](crate::OwnedContext) keeps a [`Scratch`] buffer.
+//! Text arguments are copied into it right before a call and the buffer is
+//! reused by the next one, so after the first frames no allocations are
+//! made.
+
+use std::{
+    cell::RefCell,
+    ffi::c_char,
+    ptr::{null, null_mut},
+};
+
+use crate::Vec2;
+
+/// Text, that can be passed to the safe layer.
+///
+/// Implemented for everything that is `AsRef<str>`.
+///
+/// C strings can not contain NUL bytes, so the text is truncated at the first
+/// interior NUL: `"abc\0def"` is passed as `"abc"`.
+pub trait ImStr {
+    /// Append the text to the buffer, without the terminating NUL.
+    fn write_to(&self, buf: &mut Vec<u8>);
+}
+impl<T: AsRef<str> + ?Sized> ImStr for T {
+    fn write_to(&self, buf: &mut Vec<u8>) {
+        let bytes = self.as_ref().as_bytes();
+        let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        buf.extend_from_slice(&bytes[..end]);
+    }
+}
+
+/// Reusable buffers for string arguments.
+#[derive(Debug, Default)]
+pub(crate) struct Scratch {
+    texts: RefCell<Vec<u8>>,
+    edit: RefCell<Vec<u8>>,
+}
+impl Scratch {
+    /// Write all texts NUL-terminated and call `f` with pointers to them.
+    ///
+    /// `None` becomes a null pointer. Pointers are valid only within `f`.
+    pub fn with<R, const N: usize>(
+        &self,
+        texts: [Option<&dyn ImStr>; N],
+        f: impl FnOnce([*const c_char; N]) -> R,
+    ) -> R {
+        // Nested use can happen only if `f` calls back into the safe layer.
+        // In that case a temporary buffer is used.
+        let mut temp = Vec::new();
+        let mut guard = self.texts.try_borrow_mut();
+        let buf = match guard.as_mut() {
+            Ok(buf) => &mut **buf,
+            Err(_) => &mut temp,
+        };
+        buf.clear();
+        let mut offsets = [None; N];
+        for (offset, text) in offsets.iter_mut().zip(texts.iter()) {
+            if let Some(text) = text {
+                *offset = Some(buf.len());
+                text.write_to(buf);
+                buf.push(0);
+            }
+        }
+        let pointers = offsets.map(|offset| match offset {
+            None => null(),
+            Some(o) => buf[o..].as_ptr() as *const c_char,
+        });
+        f(pointers)
+    }
+
+    /// Let `f` edit the `text` in place through a NUL-terminated buffer of
+    /// at least `text.len() + reserve` bytes.
+    ///
+    /// `f` receives the buffer and its size, and returns whether the text was
+    /// changed.
+    pub fn edit(
+        &self,
+        text: &mut String,
+        reserve: usize,
+        f: impl FnOnce(*mut c_char, i32) -> bool,
+    ) -> bool {
+        let mut temp = Vec::new();
+        let mut guard = self.edit.try_borrow_mut();
+        let buf = match guard.as_mut() {
+            Ok(buf) => &mut **buf,
+            Err(_) => &mut temp,
+        };
+        buf.clear();
+        text.write_to(buf);
+        buf.resize(buf.len() + 1 + reserve, 0);
+        let changed = f(buf.as_mut_ptr() as *mut c_char, buf.len() as i32);
+        if changed {
+            let len = buf.iter().position(|b| *b == 0).unwrap_or(buf.len());
+            text.clear();
+            text.push_str(&String::from_utf8_lossy(&buf[..len]));
+        }
+        changed
+    }
+}
+
+/// Pointer to an optional in/out argument, or null.
+pub(crate) fn opt_ptr<T>(value: Option<&mut T>) -> *mut T {
+    match value {
+        None => null_mut(),
+        Some(v) => v,
+    }
+}
+
+/// Optional point as two optional in/out pointers, or nulls.
+pub(crate) fn vec2_ptrs(value: &mut Option<Vec2>) -> (*mut f64, *mut f64) {
+    match value {
+        Some(Vec2 { x, y }) => (x, y),
+        None => (null_mut(), null_mut()),
+    }
+}
+
+/// Optional text as a [`Scratch::with`] argument.
+pub(crate) fn opt_str<S: ImStr>(value: &Option<S>) -> Option<&dyn ImStr> {
+    value.as_ref().map(|s| s as &dyn ImStr)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    unsafe fn text<'a>(ptr: *const c_char) -> &'a str {
+        CStr::from_ptr(ptr).to_str().unwrap()
+    }
+
+    #[test]
+    fn truncated_at_interior_nul() {
+        let mut buf = Vec::new();
+        "abc\0def".write_to(&mut buf);
+        assert_eq!(buf, b"abc");
+        let scratch = Scratch::default();
+        scratch.with(
+            [Some(&"\0hidden"), Some(&String::from("a\0b"))],
+            |[a, b]| unsafe {
+                assert_eq!(text(a), "");
+                assert_eq!(text(b), "a");
+            },
+        );
+    }
+
+    #[test]
+    fn none_is_null() {
+        let scratch = Scratch::default();
+        scratch.with([None, Some(&"x" as &dyn ImStr), None], |[a, b, c]| unsafe {
+            assert!(a.is_null() && c.is_null());
+            assert_eq!(text(b), "x");
+        });
+    }
+
+    #[test]
+    fn buffer_is_reused() {
+        let scratch = Scratch::default();
+        scratch.with([Some(&"first text")], |_| ());
+        let capacity = scratch.texts.borrow().capacity();
+        let ptr = scratch.with([Some(&"second")], |[ptr]| ptr);
+        assert_eq!(ptr as *const u8, scratch.texts.borrow().as_ptr());
+        assert_eq!(scratch.texts.borrow().capacity(), capacity);
+    }
+
+    #[test]
+    fn nested_use_falls_back_to_temporary_buffer() {
+        let scratch = Scratch::default();
+        scratch.with(
+            [Some(&"outer"), Some(&"second")],
+            |[outer, second]| unsafe {
+                scratch.with([Some(&"inner text, longer than the outer")], |[inner]| {
+                    assert_eq!(text(inner), "inner text, longer than the outer");
+                    assert_ne!(inner as *const u8, outer as *const u8);
+                });
+                // The outer texts are not overwritten by the nested call.
+                assert_eq!(text(outer), "outer");
+                assert_eq!(text(second), "second");
+            },
+        );
+    }
+
+    #[test]
+    fn edit_in_place() {
+        let scratch = Scratch::default();
+        let mut value = String::from("ab");
+        let changed = scratch.edit(&mut value, 4, |buf, size| unsafe {
+            assert_eq!(size, 7);
+            assert_eq!(text(buf), "ab");
+            *buf.add(2) = b'c' as c_char;
+            true
+        });
+        assert!(changed);
+        assert_eq!(value, "abc");
+
+        let unchanged = scratch.edit(&mut value, 0, |buf, _| unsafe {
+            *buf = b'x' as c_char;
+            false
+        });
+        assert!(!unchanged);
+        assert_eq!(value, "abc");
+    }
+
+    #[test]
+    fn nested_edit_falls_back_to_temporary_buffer() {
+        let scratch = Scratch::default();
+        let (mut outer, mut inner) = (String::from("outer"), String::from("inner"));
+        scratch.edit(&mut outer, 1, |outer_buf, _| unsafe {
+            scratch.edit(&mut inner, 1, |inner_buf, _| {
+                assert_ne!(inner_buf, outer_buf);
+                *inner_buf = b'I' as c_char;
+                true
+            });
+            assert_eq!(text(outer_buf), "outer");
+            *outer_buf = b'O' as c_char;
+            true
+        });
+        assert_eq!((outer.as_str(), inner.as_str()), ("Outer", "Inner"));
+    }
+}
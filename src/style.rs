@@ -0,0 +1,180 @@
+//! Scoped style changes.
+//!
+//! Every push returns a [`StyleToken`], that pops exactly what was pushed
+//! when dropped, so the style stacks stay balanced whatever path the code
+//! takes.
+//!
+//! ```no_run
+//! # use reaper_imgui::{Col, Color, Style, StyleVar, Ui};
+//! # fn f(ui: &Ui) {
+//! {
+//!     let _red = ui.push_style_color(Col::Button, 0xFF0000FF);
+//!     ui.button("Delete").build();
+//! }
+//! ui.with_style(
+//!     &[
+//!         Style::Color(Col::Text, Color::from_rgb(0x00FF00)),
+//!         Style::Var(StyleVar::FrameRounding, 4.0.into()),
+//!         Style::Var(StyleVar::FramePadding, (8.0, 2.0).into()),
+//!     ],
+//!     |ui| ui.button("Rounded").build(),
+//! );
+//! # }
+//! ```
+
+use crate::{Col, Color, StyleVar, Ui, Vec2};
+
+/// Value of a [`StyleVar`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleValue {
+    Float(f64),
+    Vec2(Vec2),
+}
+impl From<f64> for StyleValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+impl From<Vec2> for StyleValue {
+    fn from(value: Vec2) -> Self {
+        Self::Vec2(value)
+    }
+}
+impl From<(f64, f64)> for StyleValue {
+    fn from(value: (f64, f64)) -> Self {
+        Self::Vec2(value.into())
+    }
+}
+
+impl StyleVar {
+    /// Whether the variable has two components (padding, spacing, alignment
+    /// etc.).
+    pub fn is_vec2(self) -> bool {
+        matches!(
+            self,
+            Self::ButtonTextAlign
+                | Self::CellPadding
+                | Self::FramePadding
+                | Self::ItemInnerSpacing
+                | Self::ItemSpacing
+                | Self::SelectableTextAlign
+                | Self::WindowMinSize
+                | Self::WindowPadding
+                | Self::WindowTitleAlign
+        )
+    }
+}
+
+/// Single change of [`Ui::with_style`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    Color(Col, Color),
+    Var(StyleVar, StyleValue),
+}
+
+/// Pushed style colors and variables, popped on drop.
+///
+/// Tokens have to be dropped in reverse order of creation, which is what
+/// Rust does for locals of a scope.
+#[must_use = "style is popped as soon as the token is dropped"]
+#[derive(Debug)]
+pub struct StyleToken<'ui> {
+    ui: &'ui Ui<'ui>,
+    colors: i32,
+    vars: i32,
+}
+impl<'ui> StyleToken<'ui> {
+    /// Pop the style now.
+    pub fn pop(self) {}
+}
+impl Drop for StyleToken<'_> {
+    fn drop(&mut self) {
+        let (imgui, ctx) = (self.ui.imgui(), self.ui.raw_context());
+        unsafe {
+            if self.vars > 0 {
+                imgui.PopStyleVar(ctx, &mut self.vars);
+            }
+            if self.colors > 0 {
+                imgui.PopStyleColor(ctx, &mut self.colors);
+            }
+        }
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    /// Use the color (or `0xRRGGBBAA`) for the element until the token is
+    /// dropped.
+    pub fn push_style_color(&self, col: Col, color: impl Into<Color>) -> StyleToken<'_> {
+        self.push_style(&[Style::Color(col, color.into())])
+    }
+
+    /// Change the variable until the token is dropped.
+    ///
+    /// A single value for a two-component variable sets both components.
+    ///
+    /// # Panics
+    ///
+    /// If two values are given for a single-component variable.
+    pub fn push_style_var(&self, var: StyleVar, value: impl Into<StyleValue>) -> StyleToken<'_> {
+        self.push_style(&[Style::Var(var, value.into())])
+    }
+
+    /// Push all the changes at once, until the token is dropped.
+    pub fn push_style(&self, styles: &[Style]) -> StyleToken<'_> {
+        let (imgui, ctx) = (self.imgui(), self.raw_context());
+        let mut token = StyleToken {
+            ui: self,
+            colors: 0,
+            vars: 0,
+        };
+        for style in styles {
+            match *style {
+                Style::Color(col, color) => {
+                    unsafe { imgui.PushStyleColor(ctx, col.to_raw(imgui), color.to_rgba() as i32) };
+                    token.colors += 1;
+                }
+                Style::Var(var, value) => {
+                    let (val1, mut val2) = match value {
+                        StyleValue::Float(v) => (v, v),
+                        StyleValue::Vec2(Vec2 { x, y }) => (x, y),
+                    };
+                    let val2 = match (var.is_vec2(), value) {
+                        (true, _) => &mut val2 as *mut f64,
+                        (false, StyleValue::Float(_)) => std::ptr::null_mut(),
+                        (false, StyleValue::Vec2(..)) => {
+                            panic!("StyleVar::{:?} has a single component", var)
+                        }
+                    };
+                    unsafe { imgui.PushStyleVar(ctx, var.to_raw(imgui), val1, val2) };
+                    token.vars += 1;
+                }
+            }
+        }
+        token
+    }
+
+    /// Build `f` with the style changed.
+    pub fn with_style<R>(&self, styles: &[Style], f: impl FnOnce(&Self) -> R) -> R {
+        let _token = self.push_style(styles);
+        f(self)
+    }
+
+    /// Current color of the element.
+    pub fn style_color(&self, col: Col) -> Color {
+        let raw = unsafe {
+            self.imgui()
+                .GetStyleColor(self.raw_context(), col.to_raw(self.imgui()))
+        };
+        Color::from_rgba(raw as u32)
+    }
+
+    /// Current value of the variable.
+    pub fn style_var(&self, var: StyleVar) -> StyleValue {
+        let raw = var.to_raw(self.imgui());
+        let (x, y) = self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetStyleVar(ctx, raw, x, y) });
+        match var.is_vec2() {
+            true => StyleValue::Vec2(Vec2::new(x, y)),
+            false => StyleValue::Float(x),
+        }
+    }
+}
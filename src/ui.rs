@@ -0,0 +1,543 @@
+//! Safe per-frame interface over the raw [`ImGui`] methods.
+//!
+//! [`Ui`] is obtained from [`OwnedContext::frame`](crate::OwnedContext::frame)
+//! and borrows the context for the frame, so the context can be neither used
+//! outside of a frame nor destroyed while the frame is built.
+//!
+//! ```no_run
+//! # use reaper_imgui::OwnedContext;
+//! # fn run(ctx: &mut OwnedContext) {
+//! let mut open = true;
+//! if let Some(ui) = ctx.frame() {
+//!     ui.window("my window").opened(&mut open).build(|ui| {
+//!         ui.text("Hello World!");
+//!         if ui.button(format!("Clicked {} times", 0)).build() {
+//!             println!("clicked");
+//!         }
+//!     });
+//! }
+//! # }
+//! ```
+//!
+//! Text arguments are anything [`ImStr`]: `&str`, `String` etc. They are
+//! copied into a buffer reused for the whole context, see [`crate::scratch`].
+
+use std::{
+    ffi::{c_char, c_int},
+    ptr::null_mut,
+};
+
+use crate::{
+    fonts::ContextFonts,
+    scratch::{opt_ptr, ImStr, Scratch},
+    widgets::ContextClipper,
+    Color, Context, FocusedFlags, HoveredFlags, ImGui, MouseButton, MouseCursor, Rect, Vec2,
+};
+
+/// Interface to build a single frame of a context.
+///
+/// All widgets are methods of `Ui`. Scoped widgets (windows, menus, trees
+/// etc.) take a closure, that receives the same `Ui`, and close the scope
+/// after the closure returns.
+#[derive(Debug)]
+pub struct Ui<'frame> {
+    imgui: &'frame ImGui,
+    ctx: Context,
+    scratch: &'frame Scratch,
+    fonts: &'frame ContextFonts,
+    clipper: &'frame ContextClipper,
+}
+impl<'frame> Ui<'frame> {
+    /// # Safety
+    ///
+    /// `ctx` has to be valid for the whole `'frame`.
+    pub(crate) unsafe fn new(
+        imgui: &'frame ImGui,
+        ctx: Context,
+        scratch: &'frame Scratch,
+        fonts: &'frame ContextFonts,
+        clipper: &'frame ContextClipper,
+    ) -> Self {
+        Self {
+            imgui,
+            ctx,
+            scratch,
+            fonts,
+            clipper,
+        }
+    }
+
+    /// Raw API, for functions that are not covered by `Ui`.
+    pub fn imgui(&self) -> &'frame ImGui {
+        self.imgui
+    }
+
+    /// Raw context, for functions that are not covered by `Ui`.
+    pub fn raw_context(&self) -> Context {
+        self.ctx
+    }
+
+    /// Fonts attached to the context, by [`crate::FontId`].
+    pub(crate) fn fonts(&self) -> &'frame ContextFonts {
+        self.fonts
+    }
+
+    /// List clipper of the context, see [`Ui::list_clipper`].
+    pub(crate) fn clipper(&self) -> &'frame ContextClipper {
+        self.clipper
+    }
+
+    /// Read two out-values of a raw function.
+    pub(crate) fn get_pair(
+        &self,
+        f: impl FnOnce(&ImGui, Context, *mut f64, *mut f64),
+    ) -> (f64, f64) {
+        let (mut x, mut y) = (0.0, 0.0);
+        f(self.imgui, self.ctx, &mut x, &mut y);
+        (x, y)
+    }
+
+    /// Pass the text to a raw function as a C string.
+    pub(crate) fn with_str<R>(&self, text: &dyn ImStr, f: impl FnOnce(*const c_char) -> R) -> R {
+        self.scratch.with([Some(text)], |[text]| f(text))
+    }
+
+    /// Pass several texts to a raw function as C strings. `None` is passed
+    /// as null.
+    pub(crate) fn with_strs<R, const N: usize>(
+        &self,
+        texts: [Option<&dyn ImStr>; N],
+        f: impl FnOnce([*const c_char; N]) -> R,
+    ) -> R {
+        self.scratch.with(texts, f)
+    }
+
+    /// Let a raw input function edit the text in place.
+    pub(crate) fn edit_str(
+        &self,
+        text: &mut String,
+        reserve: usize,
+        f: impl FnOnce(*mut c_char, i32) -> bool,
+    ) -> bool {
+        self.scratch.edit(text, reserve, f)
+    }
+
+    /// Read a text out-value of a raw function through a buffer of
+    /// `capacity` bytes. Longer text is truncated. `None` if `f` returns
+    /// `false`.
+    pub(crate) fn read_str(
+        &self,
+        capacity: usize,
+        f: impl FnOnce(*mut c_char, i32) -> bool,
+    ) -> Option<String> {
+        let mut buf = vec![0u8; capacity];
+        if !f(buf.as_mut_ptr() as *mut c_char, capacity as i32) {
+            return None;
+        }
+        let len = buf.iter().position(|b| *b == 0).unwrap_or(capacity);
+        buf.truncate(len);
+        Some(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    // ---- Window ----
+
+    pub fn window_pos(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetWindowPos(ctx, x, y) })
+            .into()
+    }
+
+    pub fn window_size(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, w, h| unsafe { imgui.GetWindowSize(ctx, w, h) })
+            .into()
+    }
+
+    pub fn is_window_appearing(&self) -> bool {
+        unsafe { self.imgui.IsWindowAppearing(self.ctx) }
+    }
+
+    pub fn is_window_collapsed(&self) -> bool {
+        unsafe { self.imgui.IsWindowCollapsed(self.ctx) }
+    }
+
+    pub fn is_window_focused(&self, flags: FocusedFlags) -> bool {
+        let mut flags = flags.to_raw(self.imgui);
+        unsafe { self.imgui.IsWindowFocused(self.ctx, &mut flags) }
+    }
+
+    pub fn is_window_hovered(&self, flags: HoveredFlags) -> bool {
+        let mut flags = flags.to_raw(self.imgui);
+        unsafe { self.imgui.IsWindowHovered(self.ctx, &mut flags) }
+    }
+
+    pub fn content_region_avail(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetContentRegionAvail(ctx, x, y) })
+            .into()
+    }
+
+    pub fn scroll(&self) -> Vec2 {
+        unsafe {
+            Vec2::new(
+                self.imgui.GetScrollX(self.ctx),
+                self.imgui.GetScrollY(self.ctx),
+            )
+        }
+    }
+
+    pub fn set_scroll_here_y(&self, center_y_ratio: f64) {
+        let mut center_y_ratio = center_y_ratio;
+        unsafe { self.imgui.SetScrollHereY(self.ctx, &mut center_y_ratio) }
+    }
+
+    // ---- Text ----
+
+    pub fn text(&self, text: impl ImStr) {
+        self.with_str(&text, |text| unsafe { self.imgui.Text(self.ctx, text) })
+    }
+
+    /// Text of the color, a [`Color`] or `0xRRGGBBAA`.
+    pub fn text_colored(&self, color: impl Into<Color>, text: impl ImStr) {
+        let rgba = color.into().to_rgba();
+        self.with_str(&text, |text| unsafe {
+            self.imgui.TextColored(self.ctx, rgba as c_int, text)
+        })
+    }
+
+    pub fn text_disabled(&self, text: impl ImStr) {
+        self.with_str(&text, |text| unsafe {
+            self.imgui.TextDisabled(self.ctx, text)
+        })
+    }
+
+    pub fn text_wrapped(&self, text: impl ImStr) {
+        self.with_str(&text, |text| unsafe {
+            self.imgui.TextWrapped(self.ctx, text)
+        })
+    }
+
+    /// Text with a label aligned the same way as value+label widgets.
+    pub fn label_text(&self, label: impl ImStr, text: impl ImStr) {
+        self.with_strs([Some(&label), Some(&text)], |[label, text]| unsafe {
+            self.imgui.LabelText(self.ctx, label, text)
+        })
+    }
+
+    pub fn bullet_text(&self, text: impl ImStr) {
+        self.with_str(&text, |text| unsafe {
+            self.imgui.BulletText(self.ctx, text)
+        })
+    }
+
+    pub fn bullet(&self) {
+        unsafe { self.imgui.Bullet(self.ctx) }
+    }
+
+    /// Vertically align upcoming text baseline to the frame padding, so text
+    /// is aligned with the following framed widget.
+    pub fn align_text_to_frame_padding(&self) {
+        unsafe { self.imgui.AlignTextToFramePadding(self.ctx) }
+    }
+
+    /// Size of the text as it would be drawn, without wrapping.
+    pub fn calc_text_size(&self, text: impl ImStr) -> Vec2 {
+        self.with_str(&text, |text| {
+            self.get_pair(|imgui, ctx, w, h| unsafe {
+                imgui.CalcTextSize(ctx, text, w, h, null_mut(), null_mut())
+            })
+        })
+        .into()
+    }
+
+    pub fn text_line_height(&self) -> f64 {
+        unsafe { self.imgui.GetTextLineHeight(self.ctx) }
+    }
+
+    pub fn text_line_height_with_spacing(&self) -> f64 {
+        unsafe { self.imgui.GetTextLineHeightWithSpacing(self.ctx) }
+    }
+
+    pub fn frame_height(&self) -> f64 {
+        unsafe { self.imgui.GetFrameHeight(self.ctx) }
+    }
+
+    pub fn frame_height_with_spacing(&self) -> f64 {
+        unsafe { self.imgui.GetFrameHeightWithSpacing(self.ctx) }
+    }
+
+    // ---- Layout ----
+
+    pub fn separator(&self) {
+        unsafe { self.imgui.Separator(self.ctx) }
+    }
+
+    /// Place the next item on the same line as the previous one.
+    pub fn same_line(&self) {
+        unsafe { self.imgui.SameLine(self.ctx, null_mut(), null_mut()) }
+    }
+
+    /// Same as [`Ui::same_line`], but with the given offset from the start of
+    /// the line and spacing between items.
+    pub fn same_line_with(&self, offset_from_start_x: f64, spacing: f64) {
+        let (mut offset, mut spacing) = (offset_from_start_x, spacing);
+        unsafe { self.imgui.SameLine(self.ctx, &mut offset, &mut spacing) }
+    }
+
+    pub fn new_line(&self) {
+        unsafe { self.imgui.NewLine(self.ctx) }
+    }
+
+    pub fn spacing(&self) {
+        unsafe { self.imgui.Spacing(self.ctx) }
+    }
+
+    /// Empty item of the given size.
+    pub fn dummy(&self, size: impl Into<Vec2>) {
+        let size = size.into();
+        unsafe { self.imgui.Dummy(self.ctx, size.x, size.y) }
+    }
+
+    /// Indent by the given width, or by the style indent spacing if `None`.
+    pub fn indent(&self, width: Option<f64>) {
+        let mut width = width;
+        unsafe { self.imgui.Indent(self.ctx, opt_ptr(width.as_mut())) }
+    }
+
+    /// Revert [`Ui::indent`] with the same width.
+    pub fn unindent(&self, width: Option<f64>) {
+        let mut width = width;
+        unsafe { self.imgui.Unindent(self.ctx, opt_ptr(width.as_mut())) }
+    }
+
+    /// Cursor position in window coordinates.
+    pub fn cursor_pos(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetCursorPos(ctx, x, y) })
+            .into()
+    }
+
+    pub fn set_cursor_pos(&self, pos: impl Into<Vec2>) {
+        let pos = pos.into();
+        unsafe { self.imgui.SetCursorPos(self.ctx, pos.x, pos.y) }
+    }
+
+    /// Cursor position in absolute screen coordinates.
+    pub fn cursor_screen_pos(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetCursorScreenPos(ctx, x, y) })
+            .into()
+    }
+
+    pub fn set_cursor_screen_pos(&self, pos: impl Into<Vec2>) {
+        let pos = pos.into();
+        unsafe { self.imgui.SetCursorScreenPos(self.ctx, pos.x, pos.y) }
+    }
+
+    /// Build `f` disabled if `disabled` is `true`: the widgets are greyed out
+    /// and do not react to input.
+    ///
+    /// Nested scopes can not enable widgets back: inside a disabled scope
+    /// everything is disabled, whatever `disabled` is.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::Ui;
+    /// # fn f(ui: &Ui, armed: bool) {
+    /// ui.disabled(!armed, |ui| {
+    ///     ui.button("Record").build();
+    /// });
+    /// if !armed {
+    ///     ui.item_tooltip("Arm a track first");
+    /// }
+    /// # }
+    /// ```
+    pub fn disabled<R>(&self, disabled: bool, f: impl FnOnce(&Self) -> R) -> R {
+        let mut disabled = disabled;
+        unsafe { self.imgui.BeginDisabled(self.ctx, &mut disabled) };
+        let result = f(self);
+        unsafe { self.imgui.EndDisabled(self.ctx) };
+        result
+    }
+
+    // ---- Item and input queries ----
+
+    pub fn is_item_hovered(&self, flags: HoveredFlags) -> bool {
+        let mut flags = flags.to_raw(self.imgui);
+        unsafe { self.imgui.IsItemHovered(self.ctx, &mut flags) }
+    }
+
+    pub fn is_item_active(&self) -> bool {
+        unsafe { self.imgui.IsItemActive(self.ctx) }
+    }
+
+    pub fn is_item_focused(&self) -> bool {
+        unsafe { self.imgui.IsItemFocused(self.ctx) }
+    }
+
+    /// Whether the last item is hovered and clicked by the button.
+    pub fn is_item_clicked(&self, button: MouseButton) -> bool {
+        let mut button = button.to_raw(self.imgui);
+        unsafe { self.imgui.IsItemClicked(self.ctx, &mut button) }
+    }
+
+    pub fn is_item_edited(&self) -> bool {
+        unsafe { self.imgui.IsItemEdited(self.ctx) }
+    }
+
+    pub fn is_item_deactivated_after_edit(&self) -> bool {
+        unsafe { self.imgui.IsItemDeactivatedAfterEdit(self.ctx) }
+    }
+
+    pub fn set_item_default_focus(&self) {
+        unsafe { self.imgui.SetItemDefaultFocus(self.ctx) }
+    }
+
+    /// Focus the next widget, or the widget at `offset` from it.
+    pub fn set_keyboard_focus_here(&self, offset: i32) {
+        let mut offset = offset;
+        unsafe { self.imgui.SetKeyboardFocusHere(self.ctx, &mut offset) }
+    }
+
+    /// Rect and state of the last item.
+    pub fn last_item(&self) -> LastItem {
+        unsafe {
+            LastItem {
+                rect: self.item_rect(),
+                hovered: self.imgui.IsItemHovered(self.ctx, null_mut()),
+                active: self.imgui.IsItemActive(self.ctx),
+                focused: self.imgui.IsItemFocused(self.ctx),
+                visible: self.imgui.IsItemVisible(self.ctx),
+                clicked: self.imgui.IsItemClicked(self.ctx, null_mut()),
+                edited: self.imgui.IsItemEdited(self.ctx),
+                activated: self.imgui.IsItemActivated(self.ctx),
+                deactivated: self.imgui.IsItemDeactivated(self.ctx),
+                deactivated_after_edit: self.imgui.IsItemDeactivatedAfterEdit(self.ctx),
+                toggled_open: self.imgui.IsItemToggledOpen(self.ctx),
+            }
+        }
+    }
+
+    /// Lay out the items built by `f` as a single item, and measure them.
+    ///
+    /// Returns the result of `f` and the rect of the group. Afterwards
+    /// [`Ui::last_item`] and `is_item_*` refer to the whole group.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::Ui;
+    /// # fn f(ui: &Ui) {
+    /// let (clicked, rect) = ui.group(|ui| {
+    ///     ui.text("Volume");
+    ///     ui.button("Reset").build()
+    /// });
+    /// ui.text(format!("{clicked}: {} x {}", rect.width(), rect.height()));
+    /// # }
+    /// ```
+    pub fn group<R>(&self, f: impl FnOnce(&Self) -> R) -> (R, Rect) {
+        unsafe { self.imgui.BeginGroup(self.ctx) };
+        let result = f(self);
+        unsafe { self.imgui.EndGroup(self.ctx) };
+        (result, self.item_rect())
+    }
+
+    fn item_rect(&self) -> Rect {
+        let min = self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetItemRectMin(ctx, x, y) });
+        let max = self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetItemRectMax(ctx, x, y) });
+        Rect::new(min, max)
+    }
+
+    pub fn mouse_pos(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetMousePos(ctx, x, y) })
+            .into()
+    }
+
+    pub fn mouse_delta(&self) -> Vec2 {
+        self.get_pair(|imgui, ctx, x, y| unsafe { imgui.GetMouseDelta(ctx, x, y) })
+            .into()
+    }
+
+    /// Whether the mouse is over the rectangle in screen coordinates. With
+    /// `clip` only the part inside the current clipping rectangle counts.
+    pub fn is_mouse_hovering_rect(&self, rect: Rect, clip: bool) -> bool {
+        let mut clip = clip;
+        let Rect { min, max } = rect;
+        unsafe {
+            self.imgui
+                .IsMouseHoveringRect(self.ctx, min.x, min.y, max.x, max.y, &mut clip)
+        }
+    }
+
+    /// Vertical and horizontal wheel movement.
+    pub fn mouse_wheel(&self) -> (f64, f64) {
+        self.get_pair(|imgui, ctx, v, h| unsafe { imgui.GetMouseWheel(ctx, v, h) })
+    }
+
+    /// The button is held.
+    pub fn is_mouse_down(&self, button: MouseButton) -> bool {
+        unsafe { self.imgui.IsMouseDown(self.ctx, button.to_raw(self.imgui)) }
+    }
+
+    /// The button was clicked during the frame.
+    pub fn is_mouse_clicked(&self, button: MouseButton) -> bool {
+        unsafe {
+            self.imgui
+                .IsMouseClicked(self.ctx, button.to_raw(self.imgui), null_mut())
+        }
+    }
+
+    pub fn is_mouse_double_clicked(&self, button: MouseButton) -> bool {
+        unsafe {
+            self.imgui
+                .IsMouseDoubleClicked(self.ctx, button.to_raw(self.imgui))
+        }
+    }
+
+    pub fn is_mouse_released(&self, button: MouseButton) -> bool {
+        unsafe {
+            self.imgui
+                .IsMouseReleased(self.ctx, button.to_raw(self.imgui))
+        }
+    }
+
+    /// Cursor requested for the frame. `None` if hidden: ReaImGui has no
+    /// `MouseCursor_None` constant, the hidden cursor is `-1` as in ImGui.
+    pub fn mouse_cursor(&self) -> Option<MouseCursor> {
+        let raw = unsafe { self.imgui.GetMouseCursor(self.ctx) };
+        match raw < 0 {
+            true => None,
+            false => MouseCursor::from_raw(self.imgui, raw),
+        }
+    }
+
+    /// Cursor shape for the rest of the frame, `None` hides it.
+    pub fn set_mouse_cursor(&self, cursor: Option<MouseCursor>) {
+        let raw = cursor.map_or(-1, |cursor| cursor.to_raw(self.imgui));
+        unsafe { self.imgui.SetMouseCursor(self.ctx, raw) }
+    }
+
+    pub fn set_clipboard_text(&self, text: impl ImStr) {
+        self.with_str(&text, |text| unsafe {
+            self.imgui.SetClipboardText(self.ctx, text)
+        })
+    }
+}
+
+/// State of the last item, see [`Ui::last_item`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LastItem {
+    /// Bounding rect in screen coordinates.
+    pub rect: Rect,
+    pub hovered: bool,
+    /// Being held, dragged, typed in etc.
+    pub active: bool,
+    /// Has keyboard or gamepad focus.
+    pub focused: bool,
+    /// Not clipped.
+    pub visible: bool,
+    /// Hovered and clicked with the left button.
+    pub clicked: bool,
+    /// Value changed during this frame.
+    pub edited: bool,
+    /// Became active during this frame.
+    pub activated: bool,
+    /// Stopped being active during this frame.
+    pub deactivated: bool,
+    /// Stopped being active after the value was changed, e.g. drag finished.
+    pub deactivated_after_edit: bool,
+    /// Tree node or collapsing header was opened or closed.
+    pub toggled_open: bool,
+}
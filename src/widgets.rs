@@ -0,0 +1,1614 @@
+//! Widgets of the [`Ui`].
+//!
+//! `PlotLines` and `PlotHistogram` take a `reaper_array`, which the bindings
+//! do not support, so there are no plot widgets.
+
+use std::{
+    cell::Cell,
+    ffi::{c_char, c_int},
+    ptr::{null, null_mut},
+};
+
+use crate::{
+    flags::Flags,
+    scratch::{opt_ptr, opt_str, vec2_ptrs, ImStr},
+    Color, ColorEditFlags, ComboFlags, Cond, Context, HoveredFlags, ImGui, Image, InputTextFlags,
+    ListClipper, NumberFormat, PopupFlags, SliderFlags, SortDirection, TabBarFlags, TabItemFlags,
+    TableBgTarget, TableColumnFlags, TableFlags, TreeNodeFlags, Ui, Vec2, WindowFlags,
+};
+
+/// Scalar type, that can be edited by [`Drag`], [`Slider`] and [`Input`].
+///
+/// Implemented for `f64` (`*Double` functions) and `i32` (`*Int` functions).
+/// The hidden functions edit one to four components, see [`ScalarValue`].
+pub trait Scalar: Copy {
+    /// Speed of dragging, if not set explicitly.
+    const DEFAULT_SPEED: f64;
+    /// Whether the value is formatted with `%d`, see [`NumberFormat`].
+    const IS_INTEGER: bool;
+
+    #[allow(clippy::too_many_arguments)]
+    #[doc(hidden)]
+    unsafe fn drag(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        speed: f64,
+        range: Option<(Self, Self)>,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool;
+
+    #[allow(clippy::too_many_arguments)]
+    #[doc(hidden)]
+    unsafe fn slider(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        min: Self,
+        max: Self,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool;
+
+    #[allow(clippy::too_many_arguments)]
+    #[doc(hidden)]
+    unsafe fn input(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        step: Option<(Self, Self)>,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool;
+}
+impl Scalar for f64 {
+    const DEFAULT_SPEED: f64 = 1.0;
+    const IS_INTEGER: bool = false;
+
+    unsafe fn drag(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        speed: f64,
+        range: Option<(Self, Self)>,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool {
+        let mut speed = speed;
+        let (mut min, mut max) = range.unwrap_or((0.0, 0.0));
+        let (speed, min, max, mut flags) = (&mut speed, &mut min, &mut max, flags);
+        match values {
+            [v] => imgui.DragDouble(ctx, label, v, speed, min, max, format, &mut flags),
+            [v1, v2] => imgui.DragDouble2(ctx, label, v1, v2, speed, min, max, format, &mut flags),
+            [v1, v2, v3] => {
+                imgui.DragDouble3(ctx, label, v1, v2, v3, speed, min, max, format, &mut flags)
+            }
+            [v1, v2, v3, v4] => imgui.DragDouble4(
+                ctx, label, v1, v2, v3, v4, speed, min, max, format, &mut flags,
+            ),
+            _ => unreachable!("1 to 4 components"),
+        }
+    }
+
+    unsafe fn slider(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        min: Self,
+        max: Self,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool {
+        let mut flags = flags;
+        match values {
+            [v] => imgui.SliderDouble(ctx, label, v, min, max, format, &mut flags),
+            [v1, v2] => imgui.SliderDouble2(ctx, label, v1, v2, min, max, format, &mut flags),
+            [v1, v2, v3] => {
+                imgui.SliderDouble3(ctx, label, v1, v2, v3, min, max, format, &mut flags)
+            }
+            [v1, v2, v3, v4] => {
+                imgui.SliderDouble4(ctx, label, v1, v2, v3, v4, min, max, format, &mut flags)
+            }
+            _ => unreachable!("1 to 4 components"),
+        }
+    }
+
+    /// Several components have no step buttons.
+    unsafe fn input(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        step: Option<(Self, Self)>,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool {
+        let (mut step, mut step_fast) = step.unwrap_or((0.0, 0.0));
+        let mut flags = flags;
+        match values {
+            [v] => imgui.InputDouble(ctx, label, v, &mut step, &mut step_fast, format, &mut flags),
+            [v1, v2] => imgui.InputDouble2(ctx, label, v1, v2, format, &mut flags),
+            [v1, v2, v3] => imgui.InputDouble3(ctx, label, v1, v2, v3, format, &mut flags),
+            [v1, v2, v3, v4] => imgui.InputDouble4(ctx, label, v1, v2, v3, v4, format, &mut flags),
+            _ => unreachable!("1 to 4 components"),
+        }
+    }
+}
+impl Scalar for i32 {
+    const DEFAULT_SPEED: f64 = 1.0;
+    const IS_INTEGER: bool = true;
+
+    unsafe fn drag(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        speed: f64,
+        range: Option<(Self, Self)>,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool {
+        let mut speed = speed;
+        let (mut min, mut max) = range.unwrap_or((0, 0));
+        let (speed, min, max, mut flags) = (&mut speed, &mut min, &mut max, flags);
+        match values {
+            [v] => imgui.DragInt(ctx, label, v, speed, min, max, format, &mut flags),
+            [v1, v2] => imgui.DragInt2(ctx, label, v1, v2, speed, min, max, format, &mut flags),
+            [v1, v2, v3] => {
+                imgui.DragInt3(ctx, label, v1, v2, v3, speed, min, max, format, &mut flags)
+            }
+            [v1, v2, v3, v4] => imgui.DragInt4(
+                ctx, label, v1, v2, v3, v4, speed, min, max, format, &mut flags,
+            ),
+            _ => unreachable!("1 to 4 components"),
+        }
+    }
+
+    unsafe fn slider(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        min: Self,
+        max: Self,
+        format: *const c_char,
+        flags: c_int,
+    ) -> bool {
+        let mut flags = flags;
+        match values {
+            [v] => imgui.SliderInt(ctx, label, v, min, max, format, &mut flags),
+            [v1, v2] => imgui.SliderInt2(ctx, label, v1, v2, min, max, format, &mut flags),
+            [v1, v2, v3] => imgui.SliderInt3(ctx, label, v1, v2, v3, min, max, format, &mut flags),
+            [v1, v2, v3, v4] => {
+                imgui.SliderInt4(ctx, label, v1, v2, v3, v4, min, max, format, &mut flags)
+            }
+            _ => unreachable!("1 to 4 components"),
+        }
+    }
+
+    /// `InputInt*` have no format argument, so it is ignored. Several
+    /// components have no step buttons.
+    unsafe fn input(
+        imgui: &ImGui,
+        ctx: Context,
+        label: *const c_char,
+        values: &mut [Self],
+        step: Option<(Self, Self)>,
+        _format: *const c_char,
+        flags: c_int,
+    ) -> bool {
+        let (mut step, mut step_fast) = step.unwrap_or((1, 100));
+        let mut flags = flags;
+        match values {
+            [v] => imgui.InputInt(ctx, label, v, &mut step, &mut step_fast, &mut flags),
+            [v1, v2] => imgui.InputInt2(ctx, label, v1, v2, &mut flags),
+            [v1, v2, v3] => imgui.InputInt3(ctx, label, v1, v2, v3, &mut flags),
+            [v1, v2, v3, v4] => imgui.InputInt4(ctx, label, v1, v2, v3, v4, &mut flags),
+            _ => unreachable!("1 to 4 components"),
+        }
+    }
+}
+
+/// Value with one to four [`Scalar`] components: a scalar itself or an
+/// array, like `[f64; 2]` for a position.
+///
+/// ```no_run
+/// # use reaper_imgui::Ui;
+/// # fn f(ui: &Ui, pos: &mut [f64; 2], rgb: &mut [i32; 3]) {
+/// ui.drag("position", pos).speed(0.5).build();
+/// ui.slider("rgb", rgb, 0, 255).build();
+/// # }
+/// ```
+pub trait ScalarValue {
+    type Scalar: Scalar;
+
+    #[doc(hidden)]
+    fn components(&mut self) -> &mut [Self::Scalar];
+}
+impl<T: Scalar> ScalarValue for T {
+    type Scalar = T;
+
+    fn components(&mut self) -> &mut [T] {
+        std::slice::from_mut(self)
+    }
+}
+macro_rules! impl_scalar_array {
+    ($($n:literal),*) => {
+        $(
+            impl<T: Scalar> ScalarValue for [T; $n] {
+                type Scalar = T;
+
+                fn components(&mut self) -> &mut [T] {
+                    self
+                }
+            }
+        )*
+    };
+}
+impl_scalar_array!(2, 3, 4);
+
+/// Enum with a fixed set of values, that can be chosen with
+/// [`Ui::radio_group`].
+///
+/// Implemented for the small ReaImGui enums, like [`Dir`](crate::Dir) and
+/// [`MouseButton`](crate::MouseButton).
+///
+/// ```no_run
+/// # use reaper_imgui::{Ui, Variants};
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Mode {
+///     Read,
+///     Write,
+///     Latch,
+/// }
+/// impl Variants for Mode {
+///     const VARIANTS: &'static [Self] = &[Mode::Read, Mode::Write, Mode::Latch];
+///     fn label(self) -> &'static str {
+///         match self {
+///             Mode::Read => "Read",
+///             Mode::Write => "Write",
+///             Mode::Latch => "Latch",
+///         }
+///     }
+/// }
+/// # fn f(ui: &Ui, mode: &mut Mode) {
+/// if ui.radio_group(mode) {
+///     // apply the mode
+/// }
+/// # }
+/// ```
+pub trait Variants: PartialEq + Copy + 'static {
+    /// All the values, in the order they are shown.
+    const VARIANTS: &'static [Self];
+
+    fn label(self) -> &'static str;
+}
+
+macro_rules! impl_variants {
+    ($($name:ident),*) => {
+        $(
+            impl Variants for crate::$name {
+                const VARIANTS: &'static [Self] = Self::ALL;
+
+                fn label(self) -> &'static str {
+                    self.name()
+                }
+            }
+        )*
+    };
+}
+// Only the small enums of a single choice: `Col`, `StyleVar` and `Key` have
+// too many members for a row of radio buttons, and `Cond` is a bitmask.
+impl_variants!(Dir, MouseButton, MouseCursor, SortDirection, TableBgTarget);
+
+/// Builder of a drag widget, see [`Ui::drag`].
+#[must_use = "call .build() to draw the widget"]
+pub struct Drag<'ui, L, V: ScalarValue> {
+    ui: &'ui Ui<'ui>,
+    label: L,
+    value: &'ui mut V,
+    speed: f64,
+    range: Option<(V::Scalar, V::Scalar)>,
+    format: Option<&'ui NumberFormat>,
+    flags: SliderFlags,
+    width: Option<f64>,
+}
+impl<'ui, L: ImStr, V: ScalarValue> Drag<'ui, L, V> {
+    /// Value change per pixel of mouse movement.
+    pub fn speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn range(mut self, min: V::Scalar, max: V::Scalar) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Format of the displayed value.
+    pub fn format(mut self, format: &'ui NumberFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn flags(mut self, flags: SliderFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Width of the widget, see [`Ui::push_item_width`].
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Draw the widget. Returns `true` if the value was changed.
+    pub fn build(self) -> bool {
+        if let Some(width) = self.width {
+            self.ui.set_next_item_width(width);
+        }
+        let format = self.format.map(|format| format.printf_for::<V::Scalar>());
+        let texts = [Some(&self.label as &dyn ImStr), opt_str(&format)];
+        self.ui.with_strs(texts, |[label, format]| unsafe {
+            V::Scalar::drag(
+                self.ui.imgui(),
+                self.ui.raw_context(),
+                label,
+                self.value.components(),
+                self.speed,
+                self.range,
+                format,
+                self.flags.to_raw(self.ui.imgui()),
+            )
+        })
+    }
+}
+
+/// Builder of a slider widget, see [`Ui::slider`].
+#[must_use = "call .build() to draw the widget"]
+pub struct Slider<'ui, L, V: ScalarValue> {
+    ui: &'ui Ui<'ui>,
+    label: L,
+    value: &'ui mut V,
+    min: V::Scalar,
+    max: V::Scalar,
+    format: Option<&'ui NumberFormat>,
+    flags: SliderFlags,
+    width: Option<f64>,
+}
+impl<'ui, L: ImStr, V: ScalarValue> Slider<'ui, L, V> {
+    /// Format of the displayed value.
+    pub fn format(mut self, format: &'ui NumberFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn flags(mut self, flags: SliderFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Width of the widget, see [`Ui::push_item_width`].
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Draw the widget. Returns `true` if the value was changed.
+    pub fn build(self) -> bool {
+        if let Some(width) = self.width {
+            self.ui.set_next_item_width(width);
+        }
+        let format = self.format.map(|format| format.printf_for::<V::Scalar>());
+        let texts = [Some(&self.label as &dyn ImStr), opt_str(&format)];
+        self.ui.with_strs(texts, |[label, format]| unsafe {
+            V::Scalar::slider(
+                self.ui.imgui(),
+                self.ui.raw_context(),
+                label,
+                self.value.components(),
+                self.min,
+                self.max,
+                format,
+                self.flags.to_raw(self.ui.imgui()),
+            )
+        })
+    }
+}
+
+/// Builder of a numeric input widget, see [`Ui::input`].
+#[must_use = "call .build() to draw the widget"]
+pub struct Input<'ui, L, V: ScalarValue> {
+    ui: &'ui Ui<'ui>,
+    label: L,
+    value: &'ui mut V,
+    step: Option<(V::Scalar, V::Scalar)>,
+    format: Option<&'ui NumberFormat>,
+    flags: InputTextFlags,
+    width: Option<f64>,
+}
+impl<'ui, L: ImStr, V: ScalarValue> Input<'ui, L, V> {
+    /// Step of +/- buttons, and the step with Ctrl held. Ignored for
+    /// several components, which have no buttons.
+    pub fn step(mut self, step: V::Scalar, step_fast: V::Scalar) -> Self {
+        self.step = Some((step, step_fast));
+        self
+    }
+
+    /// Format of the displayed value. Ignored for integers.
+    pub fn format(mut self, format: &'ui NumberFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn flags(mut self, flags: InputTextFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Width of the widget, see [`Ui::push_item_width`].
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Draw the widget. Returns `true` if the value was changed.
+    pub fn build(self) -> bool {
+        if let Some(width) = self.width {
+            self.ui.set_next_item_width(width);
+        }
+        let format = self.format.map(|format| format.printf_for::<V::Scalar>());
+        let texts = [Some(&self.label as &dyn ImStr), opt_str(&format)];
+        self.ui.with_strs(texts, |[label, format]| unsafe {
+            V::Scalar::input(
+                self.ui.imgui(),
+                self.ui.raw_context(),
+                label,
+                self.value.components(),
+                self.step,
+                format,
+                self.flags.to_raw(self.ui.imgui()),
+            )
+        })
+    }
+}
+
+/// Builder of an image, see [`Ui::image`].
+#[must_use = "call .build() to draw the image"]
+pub struct ImageView<'ui> {
+    ui: &'ui Ui<'ui>,
+    image: Image,
+    size: Vec2,
+    uv: Option<(Vec2, Vec2)>,
+    tint: Option<Color>,
+    border_color: Option<Color>,
+}
+impl<'ui> ImageView<'ui> {
+    /// Part of the image in normalized coordinates, `(0, 0)` to `(1, 1)` by
+    /// default.
+    pub fn uv(mut self, uv0: impl Into<Vec2>, uv1: impl Into<Vec2>) -> Self {
+        self.uv = Some((uv0.into(), uv1.into()));
+        self
+    }
+
+    /// Color multiplied with the image, white by default.
+    pub fn tint(mut self, color: impl Into<Color>) -> Self {
+        self.tint = Some(color.into());
+        self
+    }
+
+    /// Border around the image, none by default.
+    pub fn border_color(mut self, color: impl Into<Color>) -> Self {
+        self.border_color = Some(color.into());
+        self
+    }
+
+    pub fn build(self) {
+        let (mut uv0, mut uv1) = match self.uv {
+            Some((uv0, uv1)) => (Some(uv0), Some(uv1)),
+            None => (None, None),
+        };
+        let (uv0_x, uv0_y) = vec2_ptrs(&mut uv0);
+        let (uv1_x, uv1_y) = vec2_ptrs(&mut uv1);
+        let mut tint = self.tint.map(|color| color.to_rgba() as c_int);
+        let mut border_color = self.border_color.map(|color| color.to_rgba() as c_int);
+        unsafe {
+            self.ui.imgui().Image(
+                self.ui.raw_context(),
+                self.image,
+                self.size.x,
+                self.size.y,
+                uv0_x,
+                uv0_y,
+                uv1_x,
+                uv1_y,
+                opt_ptr(tint.as_mut()),
+                opt_ptr(border_color.as_mut()),
+            )
+        }
+    }
+}
+
+/// Extra bytes given to ReaImGui for typing into a text input, by default.
+const INPUT_TEXT_RESERVE: usize = 256;
+
+/// Builder of a text input, see [`Ui::input_text`].
+#[must_use = "call .build() to draw the widget"]
+pub struct InputText<'ui, L, H = &'static str> {
+    ui: &'ui Ui<'ui>,
+    label: L,
+    text: &'ui mut String,
+    hint: Option<H>,
+    multiline: Option<Vec2>,
+    reserve: usize,
+    flags: InputTextFlags,
+    width: Option<f64>,
+}
+impl<'ui, L: ImStr, H: ImStr> InputText<'ui, L, H> {
+    /// Text shown while the input is empty. Ignored for multi-line inputs.
+    pub fn hint<H2: ImStr>(self, hint: H2) -> InputText<'ui, L, H2> {
+        InputText {
+            ui: self.ui,
+            label: self.label,
+            text: self.text,
+            hint: Some(hint),
+            multiline: self.multiline,
+            reserve: self.reserve,
+            flags: self.flags,
+            width: self.width,
+        }
+    }
+
+    /// Multi-line input of the size. Zero for an axis uses the default.
+    pub fn multiline(mut self, size: impl Into<Vec2>) -> Self {
+        self.multiline = Some(size.into());
+        self
+    }
+
+    /// Bytes that can be added to the text in a frame, 256 by default.
+    pub fn reserve(mut self, bytes: usize) -> Self {
+        self.reserve = bytes;
+        self
+    }
+
+    pub fn flags(mut self, flags: InputTextFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Width of the widget, see [`Ui::push_item_width`]. Ignored for
+    /// multi-line inputs, which have a size.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Draw the widget. Returns `true` when the text was edited.
+    pub fn build(self) -> bool {
+        let ui = self.ui;
+        if let (Some(width), None) = (self.width, self.multiline) {
+            ui.set_next_item_width(width);
+        }
+        let mut flags = self.flags.to_raw(ui.imgui());
+        let texts = [Some(&self.label as &dyn ImStr), opt_str(&self.hint)];
+        let multiline = self.multiline;
+        let (mut width, mut height) = multiline.unwrap_or_default().into();
+        ui.edit_str(self.text, self.reserve, |buf, size| {
+            ui.with_strs(texts, |[label, hint]| unsafe {
+                match (multiline, hint.is_null()) {
+                    (Some(_), _) => ui.imgui().InputTextMultiline(
+                        ui.raw_context(),
+                        label,
+                        buf,
+                        size,
+                        &mut width,
+                        &mut height,
+                        &mut flags,
+                    ),
+                    (None, true) => {
+                        ui.imgui()
+                            .InputText(ui.raw_context(), label, buf, size, &mut flags)
+                    }
+                    (None, false) => ui.imgui().InputTextWithHint(
+                        ui.raw_context(),
+                        label,
+                        hint,
+                        buf,
+                        size,
+                        &mut flags,
+                    ),
+                }
+            })
+        })
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    // ---- Checkboxes ----
+
+    /// Returns `true` when the value was toggled.
+    pub fn checkbox(&self, label: impl ImStr, value: &mut bool) -> bool {
+        self.with_str(&label, |label| unsafe {
+            self.imgui().Checkbox(self.raw_context(), label, value)
+        })
+    }
+
+    /// Checkbox toggling `flags_value` in `flags`. Shown as mixed when only
+    /// some of them are set.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::{Ui, WindowFlags};
+    /// # fn f(ui: &Ui, flags: &mut WindowFlags) {
+    /// ui.checkbox_flags("No title bar", flags, WindowFlags::NO_TITLE_BAR);
+    /// # }
+    /// ```
+    pub fn checkbox_flags<F: Flags>(
+        &self,
+        label: impl ImStr,
+        flags: &mut F,
+        flags_value: F,
+    ) -> bool {
+        let (mut raw, raw_value) = (flags.to_raw(self.imgui()), flags_value.to_raw(self.imgui()));
+        let changed = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .CheckboxFlags(self.raw_context(), label, &mut raw, raw_value)
+        });
+        if changed {
+            *flags = F::from_raw(self.imgui(), raw);
+        }
+        changed
+    }
+
+    /// Returns `true` when clicked.
+    pub fn radio_button(&self, label: impl ImStr, active: bool) -> bool {
+        self.with_str(&label, |label| unsafe {
+            self.imgui().RadioButton(self.raw_context(), label, active)
+        })
+    }
+
+    /// Radio button, that sets `value` to `button_value` when clicked.
+    pub fn radio_button_int(&self, label: impl ImStr, value: &mut i32, button_value: i32) -> bool {
+        self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .RadioButtonEx(self.raw_context(), label, value, button_value)
+        })
+    }
+
+    /// Radio button, that sets `value` to `button_value` when clicked. It is
+    /// active while they are equal. Returns `true` when clicked.
+    pub fn radio<T: PartialEq + Copy>(
+        &self,
+        label: impl ImStr,
+        value: &mut T,
+        button_value: T,
+    ) -> bool {
+        let clicked = self.radio_button(label, *value == button_value);
+        if clicked {
+            *value = button_value;
+        }
+        clicked
+    }
+
+    /// Radio button for every variant of the enum, on the same line. Returns
+    /// `true` when `value` was changed.
+    ///
+    /// IDs are made of the type and the labels: wrap several groups of the
+    /// same type into [`Ui::with_id`].
+    pub fn radio_group<T: Variants>(&self, value: &mut T) -> bool {
+        let old = *value;
+        self.with_id(std::any::type_name::<T>(), |ui| {
+            for (idx, variant) in T::VARIANTS.iter().enumerate() {
+                if idx > 0 {
+                    ui.same_line();
+                }
+                ui.radio(variant.label(), value, *variant);
+            }
+        });
+        *value != old
+    }
+
+    /// Progress bar of the `fraction` in `0.0..=1.0`, with optional text.
+    ///
+    /// Without a text, its type has to be given: `None::<&str>`.
+    pub fn progress_bar(&self, fraction: f64, overlay: Option<impl ImStr>) {
+        self.with_strs([opt_str(&overlay)], |[overlay]| unsafe {
+            self.imgui().ProgressBar(
+                self.raw_context(),
+                fraction,
+                null_mut(),
+                null_mut(),
+                overlay,
+            )
+        })
+    }
+
+    // ---- Numeric ----
+
+    /// Value, changed by dragging the mouse or typing in with double-click.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::{NumberFormat, Ui};
+    /// # fn f(ui: &Ui, volume: &mut f64) {
+    /// ui.drag("volume", volume)
+    ///     .range(-60.0, 12.0)
+    ///     .speed(0.1)
+    ///     .format(&NumberFormat::new().precision(1).suffix(" dB"))
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn drag<'ui, L: ImStr, V: ScalarValue>(
+        &'ui self,
+        label: L,
+        value: &'ui mut V,
+    ) -> Drag<'ui, L, V> {
+        Drag {
+            ui: self,
+            label,
+            value,
+            speed: V::Scalar::DEFAULT_SPEED,
+            range: None,
+            format: None,
+            flags: Default::default(),
+            width: None,
+        }
+    }
+
+    /// Value in the `min..=max` range, changed by a slider. Every component
+    /// has the same range.
+    pub fn slider<'ui, L: ImStr, V: ScalarValue>(
+        &'ui self,
+        label: L,
+        value: &'ui mut V,
+        min: V::Scalar,
+        max: V::Scalar,
+    ) -> Slider<'ui, L, V> {
+        Slider {
+            ui: self,
+            label,
+            value,
+            min,
+            max,
+            format: None,
+            flags: Default::default(),
+            width: None,
+        }
+    }
+
+    /// Slider of the angle in radians, displayed in degrees.
+    pub fn slider_angle(
+        &self,
+        label: impl ImStr,
+        radians: &mut f64,
+        min_degrees: f64,
+        max_degrees: f64,
+    ) -> bool {
+        let (mut min, mut max) = (min_degrees, max_degrees);
+        self.with_str(&label, |label| unsafe {
+            self.imgui().SliderAngle(
+                self.raw_context(),
+                label,
+                radians,
+                &mut min,
+                &mut max,
+                null(),
+                null_mut(),
+            )
+        })
+    }
+
+    /// Value, typed in by the user.
+    pub fn input<'ui, L: ImStr, V: ScalarValue>(
+        &'ui self,
+        label: L,
+        value: &'ui mut V,
+    ) -> Input<'ui, L, V> {
+        Input {
+            ui: self,
+            label,
+            value,
+            step: None,
+            format: None,
+            flags: Default::default(),
+            width: None,
+        }
+    }
+
+    // ---- Images ----
+
+    /// Image, which must be attached to the context.
+    pub fn image(&self, image: Image, size: impl Into<Vec2>) -> ImageView<'_> {
+        ImageView {
+            ui: self,
+            image,
+            size: size.into(),
+            uv: None,
+            tint: None,
+            border_color: None,
+        }
+    }
+
+    // ---- Text input ----
+
+    /// Text input, single line by default.
+    ///
+    /// ReaImGui edits the text in a fixed buffer, so only
+    /// [`InputText::reserve`] bytes (256 by default) can be typed or pasted
+    /// in a frame. Longer input is truncated.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::{InputTextFlags, Ui};
+    /// # fn f(ui: &Ui, name: &mut String, notes: &mut String) {
+    /// ui.input_text("name", name).hint("track name").build();
+    /// ui.input_text("notes", notes)
+    ///     .multiline((-1.0, 200.0))
+    ///     .reserve(64 * 1024)
+    ///     .flags(InputTextFlags::ALLOW_TAB_INPUT)
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn input_text<'ui, L: ImStr>(
+        &'ui self,
+        label: L,
+        text: &'ui mut String,
+    ) -> InputText<'ui, L> {
+        InputText {
+            ui: self,
+            label,
+            text,
+            hint: None,
+            multiline: None,
+            reserve: INPUT_TEXT_RESERVE,
+            flags: InputTextFlags::empty(),
+            width: None,
+        }
+    }
+
+    // ---- Selection ----
+
+    /// Returns `true` when clicked.
+    pub fn selectable(&self, label: impl ImStr, selected: bool) -> bool {
+        self.selectable_dyn(&label, selected)
+    }
+
+    fn selectable_dyn(&self, label: &dyn ImStr, selected: bool) -> bool {
+        let mut selected = selected;
+        self.with_str(label, |label| unsafe {
+            self.imgui().Selectable(
+                self.raw_context(),
+                label,
+                &mut selected,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            )
+        })
+    }
+
+    /// Combo box, that selects one of the `items` into `current`.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn combo<S: ImStr>(&self, label: impl ImStr, current: &mut usize, items: &[S]) -> bool {
+        let preview = items.get(*current).map(|s| s as &dyn ImStr);
+        let opened = self.with_strs([Some(&label), preview], |[label, preview]| unsafe {
+            self.imgui()
+                .BeginCombo(self.raw_context(), label, preview, null_mut())
+        });
+        if !opened {
+            return false;
+        }
+        let changed = self.select_from(current, items);
+        unsafe { self.imgui().EndCombo(self.raw_context()) };
+        changed
+    }
+
+    /// Combo box with custom contents, built by `f`.
+    ///
+    /// `f` is called only when the combo is opened.
+    pub fn combo_with<R>(
+        &self,
+        label: impl ImStr,
+        preview: impl ImStr,
+        flags: ComboFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        let opened = self.with_strs([Some(&label), Some(&preview)], |[label, preview]| unsafe {
+            self.imgui()
+                .BeginCombo(self.raw_context(), label, preview, &mut flags)
+        });
+        if !opened {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndCombo(self.raw_context()) };
+        Some(result)
+    }
+
+    /// List box, that selects one of the `items` into `current`.
+    ///
+    /// Returns `true` when the selection changed.
+    pub fn list_box<S: ImStr>(&self, label: impl ImStr, current: &mut usize, items: &[S]) -> bool {
+        let opened = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .BeginListBox(self.raw_context(), label, null_mut(), null_mut())
+        });
+        if !opened {
+            return false;
+        }
+        let changed = self.select_from(current, items);
+        unsafe { self.imgui().EndListBox(self.raw_context()) };
+        changed
+    }
+
+    /// Build only the visible ones of `count` items of the same height:
+    /// `f` is called with the index of every visible item. Without
+    /// `item_height`, it is measured from the first item.
+    ///
+    /// Only the first `i32::MAX` items are shown. The clipper is created
+    /// once per context, a nested call uses a temporary one.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::Ui;
+    /// # fn f(ui: &Ui, lines: &[String]) {
+    /// ui.list_clipper(lines.len(), None, |ui, idx| ui.text(&lines[idx]));
+    /// # }
+    /// ```
+    pub fn list_clipper(
+        &self,
+        count: usize,
+        item_height: Option<f64>,
+        mut f: impl FnMut(&Self, usize),
+    ) {
+        let count = c_int::try_from(count).unwrap_or(c_int::MAX);
+        let mut item_height = item_height;
+        let cached = self.clipper().take();
+        unsafe {
+            let clipper = match cached {
+                Some(clipper) => clipper,
+                None => self.imgui().CreateListClipper(self.raw_context()),
+            };
+            self.imgui()
+                .ListClipper_Begin(clipper, count, opt_ptr(item_height.as_mut()));
+            while self.imgui().ListClipper_Step(clipper) {
+                let (mut start, mut end) = (0, 0);
+                self.imgui()
+                    .ListClipper_GetDisplayRange(clipper, &mut start, &mut end);
+                for idx in start..end {
+                    f(self, idx as usize);
+                }
+            }
+        }
+        if cached.is_some() {
+            self.clipper().put_back();
+        }
+    }
+
+    fn select_from<S: ImStr>(&self, current: &mut usize, items: &[S]) -> bool {
+        let mut changed = false;
+        for (idx, item) in items.iter().enumerate() {
+            let selected = idx == *current;
+            if self.selectable_dyn(item, selected) && !selected {
+                *current = idx;
+                changed = true;
+            }
+            if selected {
+                self.set_item_default_focus();
+            }
+        }
+        changed
+    }
+
+    // ---- Color ----
+
+    /// Edit the color without alpha, which is kept as is.
+    pub fn color_edit3(&self, label: impl ImStr, color: &mut Color, flags: ColorEditFlags) -> bool {
+        let (mut col, mut flags) = (color.to_rgb() as c_int, flags.to_raw(self.imgui()));
+        let changed = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .ColorEdit3(self.raw_context(), label, &mut col, &mut flags)
+        });
+        *color = Color::from_rgba(((col as u32) << 8) | color.a() as u32);
+        changed
+    }
+
+    /// Edit the color with alpha.
+    pub fn color_edit4(&self, label: impl ImStr, color: &mut Color, flags: ColorEditFlags) -> bool {
+        let (mut col, mut flags) = (color.to_rgba() as c_int, flags.to_raw(self.imgui()));
+        let changed = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .ColorEdit4(self.raw_context(), label, &mut col, &mut flags)
+        });
+        *color = Color::from_rgba(col as u32);
+        changed
+    }
+
+    /// Color picker without alpha, which is kept as is.
+    pub fn color_picker3(
+        &self,
+        label: impl ImStr,
+        color: &mut Color,
+        flags: ColorEditFlags,
+    ) -> bool {
+        let (mut col, mut flags) = (color.to_rgb() as c_int, flags.to_raw(self.imgui()));
+        let changed = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .ColorPicker3(self.raw_context(), label, &mut col, &mut flags)
+        });
+        *color = Color::from_rgba(((col as u32) << 8) | color.a() as u32);
+        changed
+    }
+
+    /// Color picker with alpha. With `reference`, it is shown next to the
+    /// edited color.
+    pub fn color_picker4(
+        &self,
+        label: impl ImStr,
+        color: &mut Color,
+        reference: Option<Color>,
+        flags: ColorEditFlags,
+    ) -> bool {
+        let (mut col, mut flags) = (color.to_rgba() as c_int, flags.to_raw(self.imgui()));
+        let mut reference = reference.map(|color| color.to_rgba() as c_int);
+        let changed = self.with_str(&label, |label| unsafe {
+            self.imgui().ColorPicker4(
+                self.raw_context(),
+                label,
+                &mut col,
+                &mut flags,
+                opt_ptr(reference.as_mut()),
+            )
+        });
+        *color = Color::from_rgba(col as u32);
+        changed
+    }
+
+    /// Color square. Returns `true` when clicked.
+    pub fn color_button(
+        &self,
+        desc_id: impl ImStr,
+        color: impl Into<Color>,
+        flags: ColorEditFlags,
+    ) -> bool {
+        let (rgba, mut flags) = (color.into().to_rgba(), flags.to_raw(self.imgui()));
+        self.with_str(&desc_id, |desc_id| unsafe {
+            self.imgui().ColorButton(
+                self.raw_context(),
+                desc_id,
+                rgba as c_int,
+                &mut flags,
+                null_mut(),
+                null_mut(),
+            )
+        })
+    }
+
+    // ---- Trees ----
+
+    /// Tree node with children built by `f` when it is opened.
+    pub fn tree_node<R>(&self, label: impl ImStr, f: impl FnOnce(&Self) -> R) -> Option<R> {
+        self.tree_node_ex(label, TreeNodeFlags::empty(), f)
+    }
+
+    /// Same as [`Ui::tree_node`], with flags.
+    ///
+    /// With [`TreeNodeFlags::NO_TREE_PUSH_ON_OPEN`] the tree is not popped.
+    pub fn tree_node_ex<R>(
+        &self,
+        label: impl ImStr,
+        flags: TreeNodeFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut raw_flags = flags.to_raw(self.imgui());
+        let opened = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .TreeNode(self.raw_context(), label, &mut raw_flags)
+        });
+        if !opened {
+            return None;
+        }
+        let result = f(self);
+        if !flags.contains(TreeNodeFlags::NO_TREE_PUSH_ON_OPEN) {
+            unsafe { self.imgui().TreePop(self.raw_context()) };
+        }
+        Some(result)
+    }
+
+    /// Header, that does not indent its contents. Returns `true` when open.
+    ///
+    /// If `visible` is given, the header has a close button, which sets it to
+    /// `false`. Hidden headers are not drawn and return `false`.
+    pub fn collapsing_header(
+        &self,
+        label: impl ImStr,
+        visible: Option<&mut bool>,
+        flags: TreeNodeFlags,
+    ) -> bool {
+        let mut flags = flags.to_raw(self.imgui());
+        self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .CollapsingHeader(self.raw_context(), label, opt_ptr(visible), &mut flags)
+        })
+    }
+
+    /// Set the open state of the next tree node or collapsing header, under
+    /// the condition.
+    pub fn set_next_item_open(&self, is_open: bool, cond: Cond) {
+        let mut cond = cond.to_raw(self.imgui());
+        unsafe {
+            self.imgui()
+                .SetNextItemOpen(self.raw_context(), is_open, &mut cond)
+        }
+    }
+
+    // ---- Tooltips ----
+
+    /// Tooltip with contents built by `f`.
+    pub fn tooltip<R>(&self, f: impl FnOnce(&Self) -> R) -> R {
+        unsafe { self.imgui().BeginTooltip(self.raw_context()) };
+        let result = f(self);
+        unsafe { self.imgui().EndTooltip(self.raw_context()) };
+        result
+    }
+
+    /// Tooltip with a single text.
+    pub fn set_tooltip(&self, text: impl ImStr) {
+        self.with_str(&text, |text| unsafe {
+            self.imgui().SetTooltip(self.raw_context(), text)
+        })
+    }
+
+    /// Tooltip of the last item, shown while it is hovered.
+    ///
+    /// Shown for disabled items too (`HoveredFlags_AllowWhenDisabled`), so
+    /// it can explain why the item is disabled.
+    pub fn item_tooltip(&self, text: impl ImStr) {
+        if self.is_item_hovered_allow_disabled() {
+            self.set_tooltip(text);
+        }
+    }
+
+    /// Tooltip of the last item with contents built by `f`, see
+    /// [`Ui::item_tooltip`].
+    pub fn item_tooltip_with<R>(&self, f: impl FnOnce(&Self) -> R) -> Option<R> {
+        match self.is_item_hovered_allow_disabled() {
+            true => Some(self.tooltip(f)),
+            false => None,
+        }
+    }
+
+    fn is_item_hovered_allow_disabled(&self) -> bool {
+        self.is_item_hovered(HoveredFlags::ALLOW_WHEN_DISABLED)
+    }
+
+    // ---- Menus ----
+
+    /// Menu bar of the current window (needs `WindowFlags_MenuBar`).
+    pub fn menu_bar<R>(&self, f: impl FnOnce(&Self) -> R) -> Option<R> {
+        if !unsafe { self.imgui().BeginMenuBar(self.raw_context()) } {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndMenuBar(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Sub-menu with items built by `f` while it is opened.
+    pub fn menu<R>(
+        &self,
+        label: impl ImStr,
+        enabled: bool,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut enabled = enabled;
+        let opened = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .BeginMenu(self.raw_context(), label, &mut enabled)
+        });
+        if !opened {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndMenu(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Menu item with optional shortcut text, e.g. a
+    /// [`KeyChord`](crate::KeyChord) or `"Ctrl+S"`. Returns `true` when
+    /// activated.
+    ///
+    /// Without a shortcut, its type has to be given: `None::<&str>`.
+    pub fn menu_item(
+        &self,
+        label: impl ImStr,
+        shortcut: Option<impl ImStr>,
+        selected: bool,
+        enabled: bool,
+    ) -> bool {
+        let (mut selected, mut enabled) = (selected, enabled);
+        self.with_strs(
+            [Some(&label), opt_str(&shortcut)],
+            |[label, shortcut]| unsafe {
+                self.imgui().MenuItem(
+                    self.raw_context(),
+                    label,
+                    shortcut,
+                    &mut selected,
+                    &mut enabled,
+                )
+            },
+        )
+    }
+
+    /// Menu item, that toggles `selected` when activated.
+    pub fn menu_item_toggle(
+        &self,
+        label: impl ImStr,
+        shortcut: Option<impl ImStr>,
+        selected: &mut bool,
+        enabled: bool,
+    ) -> bool {
+        let mut enabled = enabled;
+        self.with_strs(
+            [Some(&label), opt_str(&shortcut)],
+            |[label, shortcut]| unsafe {
+                self.imgui()
+                    .MenuItem(self.raw_context(), label, shortcut, selected, &mut enabled)
+            },
+        )
+    }
+
+    // ---- Popups ----
+
+    /// Mark the popup as open. Should not be called every frame.
+    ///
+    /// E.g. [`PopupFlags::NO_OPEN_OVER_EXISTING_POPUP`] keeps another popup
+    /// of the same level open.
+    pub fn open_popup(&self, str_id: impl ImStr, flags: PopupFlags) {
+        let mut flags = flags.to_raw(self.imgui());
+        self.with_str(&str_id, |str_id| unsafe {
+            self.imgui()
+                .OpenPopup(self.raw_context(), str_id, &mut flags)
+        })
+    }
+
+    /// Whether the popup is open at the current level of the popup stack.
+    /// With [`PopupFlags::ANY_POPUP_ID`] or [`PopupFlags::ANY_POPUP_LEVEL`]
+    /// `str_id` is ignored or any level is checked.
+    pub fn is_popup_open(&self, str_id: impl ImStr, flags: PopupFlags) -> bool {
+        let mut flags = flags.to_raw(self.imgui());
+        self.with_str(&str_id, |str_id| unsafe {
+            self.imgui()
+                .IsPopupOpen(self.raw_context(), str_id, &mut flags)
+        })
+    }
+
+    /// Close the popup we are building. Call it from within the popup closure.
+    pub fn close_current_popup(&self) {
+        unsafe { self.imgui().CloseCurrentPopup(self.raw_context()) }
+    }
+
+    /// Popup with contents built by `f` while it is open.
+    pub fn popup<R>(
+        &self,
+        str_id: impl ImStr,
+        flags: WindowFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        let opened = self.with_str(&str_id, |str_id| unsafe {
+            self.imgui()
+                .BeginPopup(self.raw_context(), str_id, &mut flags)
+        });
+        self.end_popup(opened, f)
+    }
+
+    /// Modal popup, that blocks interaction with other windows.
+    ///
+    /// If `open` is given, the popup has a close button, which sets it to
+    /// `false`.
+    pub fn popup_modal<R>(
+        &self,
+        name: impl ImStr,
+        open: Option<&mut bool>,
+        flags: WindowFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        let opened = self.with_str(&name, |name| unsafe {
+            self.imgui()
+                .BeginPopupModal(self.raw_context(), name, opt_ptr(open), &mut flags)
+        });
+        self.end_popup(opened, f)
+    }
+
+    /// Popup opened by a click on the last item, see
+    /// [`Ui::popup_context_window`] for the flags.
+    pub fn popup_context_item<R>(
+        &self,
+        str_id: Option<impl ImStr>,
+        flags: PopupFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = self.context_popup_flags(flags);
+        let opened = self.with_strs([opt_str(&str_id)], |[str_id]| unsafe {
+            self.imgui()
+                .BeginPopupContextItem(self.raw_context(), str_id, &mut flags)
+        });
+        self.end_popup(opened, f)
+    }
+
+    /// Popup opened by a click in the current window.
+    ///
+    /// It is opened by the right button, unless one of the
+    /// `PopupFlags::MOUSE_BUTTON_*` is given. With
+    /// [`PopupFlags::NO_OPEN_OVER_ITEMS`] clicks over items are ignored.
+    ///
+    /// Without `str_id`, its type has to be given: `None::<&str>`.
+    pub fn popup_context_window<R>(
+        &self,
+        str_id: Option<impl ImStr>,
+        flags: PopupFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = self.context_popup_flags(flags);
+        let opened = self.with_strs([opt_str(&str_id)], |[str_id]| unsafe {
+            self.imgui()
+                .BeginPopupContextWindow(self.raw_context(), str_id, &mut flags)
+        });
+        self.end_popup(opened, f)
+    }
+
+    /// `PopupFlags_MouseButtonLeft` is zero, so the right button is set
+    /// explicitly, if none is given.
+    fn context_popup_flags(&self, mut flags: PopupFlags) -> c_int {
+        let buttons = PopupFlags::MOUSE_BUTTON_LEFT
+            | PopupFlags::MOUSE_BUTTON_MIDDLE
+            | PopupFlags::MOUSE_BUTTON_RIGHT;
+        if !flags.intersects(buttons) {
+            flags.insert(PopupFlags::MOUSE_BUTTON_RIGHT);
+        }
+        flags.to_raw(self.imgui())
+    }
+
+    fn end_popup<R>(&self, opened: bool, f: impl FnOnce(&Self) -> R) -> Option<R> {
+        if !opened {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndPopup(self.raw_context()) };
+        Some(result)
+    }
+
+    // ---- Tabs ----
+
+    /// Tab bar, which tabs are built by `f`.
+    pub fn tab_bar<R>(
+        &self,
+        str_id: impl ImStr,
+        flags: TabBarFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        let opened = self.with_str(&str_id, |str_id| unsafe {
+            self.imgui()
+                .BeginTabBar(self.raw_context(), str_id, &mut flags)
+        });
+        if !opened {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndTabBar(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Tab with contents built by `f` while it is selected.
+    ///
+    /// If `open` is given, the tab has a close button, which sets it to
+    /// `false`.
+    pub fn tab_item<R>(
+        &self,
+        label: impl ImStr,
+        open: Option<&mut bool>,
+        flags: TabItemFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        let selected = self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .BeginTabItem(self.raw_context(), label, opt_ptr(open), &mut flags)
+        });
+        if !selected {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndTabItem(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Tab, that behaves like a button. Returns `true` when clicked.
+    pub fn tab_item_button(&self, label: impl ImStr, flags: TabItemFlags) -> bool {
+        let mut flags = flags.to_raw(self.imgui());
+        self.with_str(&label, |label| unsafe {
+            self.imgui()
+                .TabItemButton(self.raw_context(), label, &mut flags)
+        })
+    }
+
+    // ---- Tables ----
+
+    /// Table of `columns`, which rows are built by `f`.
+    ///
+    /// `f` is not called if the table is clipped.
+    pub fn table<R>(
+        &self,
+        str_id: impl ImStr,
+        columns: i32,
+        flags: TableFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let mut flags = flags.to_raw(self.imgui());
+        let visible = self.with_str(&str_id, |str_id| unsafe {
+            self.imgui().BeginTable(
+                self.raw_context(),
+                str_id,
+                columns,
+                &mut flags,
+                null_mut(),
+                null_mut(),
+                null_mut(),
+            )
+        });
+        if !visible {
+            return None;
+        }
+        let result = f(self);
+        unsafe { self.imgui().EndTable(self.raw_context()) };
+        Some(result)
+    }
+
+    /// Declare a column. Call before the first row.
+    pub fn table_setup_column(&self, label: impl ImStr, flags: TableColumnFlags) {
+        let mut flags = flags.to_raw(self.imgui());
+        self.with_str(&label, |label| unsafe {
+            self.imgui().TableSetupColumn(
+                self.raw_context(),
+                label,
+                &mut flags,
+                null_mut(),
+                null_mut(),
+            )
+        })
+    }
+
+    /// Row of headers, made of the labels given to [`Ui::table_setup_column`].
+    pub fn table_headers_row(&self) {
+        unsafe { self.imgui().TableHeadersRow(self.raw_context()) }
+    }
+
+    pub fn table_next_row(&self) {
+        unsafe {
+            self.imgui()
+                .TableNextRow(self.raw_context(), null_mut(), null_mut())
+        }
+    }
+
+    /// Move to the next column. Returns `true` if the column is visible.
+    pub fn table_next_column(&self) -> bool {
+        unsafe { self.imgui().TableNextColumn(self.raw_context()) }
+    }
+
+    /// Move to the column. Returns `true` if the column is visible.
+    pub fn table_set_column_index(&self, column: i32) -> bool {
+        unsafe { self.imgui().TableSetColumnIndex(self.raw_context(), column) }
+    }
+
+    /// Background color of the current row or cell. `column` is the current
+    /// one by default.
+    pub fn table_set_bg_color(
+        &self,
+        target: TableBgTarget,
+        color: impl Into<Color>,
+        column: Option<i32>,
+    ) {
+        let mut column = column;
+        unsafe {
+            self.imgui().TableSetBgColor(
+                self.raw_context(),
+                target.to_raw(self.imgui()),
+                color.into().to_rgba() as i32,
+                opt_ptr(column.as_mut()),
+            )
+        }
+    }
+
+    /// Sort order of a table with `TableFlags::SORTABLE`, if it has changed
+    /// since the last call (including the first one). Call after the columns
+    /// are set up.
+    pub fn table_sort_specs(&self) -> Option<Vec<TableSortSpec>> {
+        let mut has_specs = false;
+        let need_sort = unsafe {
+            self.imgui()
+                .TableNeedSort(self.raw_context(), &mut has_specs)
+        };
+        if !need_sort || !has_specs {
+            return None;
+        }
+        let mut specs = Vec::new();
+        for id in 0.. {
+            let (mut user_id, mut index, mut order, mut direction) = (0, 0, 0, 0);
+            let exists = unsafe {
+                self.imgui().TableGetColumnSortSpecs(
+                    self.raw_context(),
+                    id,
+                    &mut user_id,
+                    &mut index,
+                    &mut order,
+                    &mut direction,
+                )
+            };
+            if !exists {
+                break;
+            }
+            specs.push(TableSortSpec {
+                column_user_id: user_id,
+                column_index: index,
+                sort_order: order,
+                direction: SortDirection::from_raw(self.imgui(), direction)
+                    .unwrap_or(SortDirection::None),
+            });
+        }
+        Some(specs)
+    }
+}
+
+/// Sorting by a column, see [`Ui::table_sort_specs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableSortSpec {
+    pub column_user_id: i32,
+    pub column_index: i32,
+    /// Position among the sorted columns, 0 is the primary one.
+    pub sort_order: i32,
+    pub direction: SortDirection,
+}
+
+/// List clipper of a context, attached to it on creation, so it is not
+/// garbage-collected between the frames.
+#[derive(Debug, Default)]
+pub(crate) struct ContextClipper {
+    clipper: Option<ListClipper>,
+    in_use: Cell<bool>,
+}
+impl ContextClipper {
+    /// # Safety
+    ///
+    /// `ctx` has to be valid.
+    pub(crate) unsafe fn attach(imgui: &ImGui, ctx: Context) -> Self {
+        let clipper = imgui.CreateListClipper(ctx);
+        if clipper.is_null() {
+            return Self::default();
+        }
+        imgui.Attach(ctx, clipper);
+        Self {
+            clipper: Some(clipper),
+            in_use: Cell::new(false),
+        }
+    }
+
+    /// The clipper, if it is not used by an outer call.
+    fn take(&self) -> Option<ListClipper> {
+        match self.in_use.replace(true) {
+            true => None,
+            false => self.clipper,
+        }
+    }
+
+    fn put_back(&self) {
+        self.in_use.set(false);
+    }
+}
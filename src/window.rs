@@ -0,0 +1,264 @@
+//! Top-level and child windows.
+
+use std::ptr::null_mut;
+
+use crate::{
+    scratch::{opt_ptr, ImStr},
+    Cond, Ui, Vec2, WindowFlags,
+};
+
+/// Builder of a window, see [`Ui::window`].
+///
+/// Everything set on the builder is applied with `SetNextWindow*` right
+/// before `Begin`, and `End` is always called, whether the window is visible
+/// or not.
+#[must_use = "call .build() to draw the window"]
+pub struct Window<'ui, L> {
+    ui: &'ui Ui<'ui>,
+    name: L,
+    flags: WindowFlags,
+    opened: Option<&'ui mut bool>,
+    cond: Option<Cond>,
+    position: Option<Vec2>,
+    pivot: Option<Vec2>,
+    size: Option<Vec2>,
+    size_constraints: Option<(Vec2, Vec2)>,
+    collapsed: Option<bool>,
+    bg_alpha: Option<f64>,
+    focused: bool,
+}
+impl<'ui, L: ImStr> Window<'ui, L> {
+    pub fn flags(mut self, flags: WindowFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Show the close button, which sets `opened` to `false`.
+    ///
+    /// The window is drawn regardless of the value, so the caller decides
+    /// what to do after it is closed, e.g. stop the timer that draws it.
+    pub fn opened(mut self, opened: &'ui mut bool) -> Self {
+        self.opened = Some(opened);
+        self
+    }
+
+    /// Condition under which position, size and collapsed state are applied.
+    /// By default they are applied every frame.
+    pub fn cond(mut self, cond: Cond) -> Self {
+        self.cond = Some(cond);
+        self
+    }
+
+    /// Position in screen coordinates.
+    pub fn position(mut self, pos: impl Into<Vec2>) -> Self {
+        self.position = Some(pos.into());
+        self
+    }
+
+    /// Point of the window placed at the position: `(0.5, 0.5)` centers it.
+    pub fn pivot(mut self, pivot: impl Into<Vec2>) -> Self {
+        self.pivot = Some(pivot.into());
+        self
+    }
+
+    /// Size of the window. Zero for an axis fits it to the contents.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Limit resizing. Use `f64::MAX` for no limit of an axis.
+    pub fn size_constraints(mut self, min: impl Into<Vec2>, max: impl Into<Vec2>) -> Self {
+        self.size_constraints = Some((min.into(), max.into()));
+        self
+    }
+
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = Some(collapsed);
+        self
+    }
+
+    /// Opacity of the background, overriding the style.
+    pub fn bg_alpha(mut self, alpha: f64) -> Self {
+        self.bg_alpha = Some(alpha);
+        self
+    }
+
+    /// Bring the window to front and focus it.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Draw the window with contents built by `f`.
+    ///
+    /// Returns `None` if the window is collapsed or clipped, and `f` is not
+    /// called.
+    pub fn build<R>(self, f: impl FnOnce(&Ui<'ui>) -> R) -> Option<R> {
+        let (imgui, ctx) = (self.ui.imgui(), self.ui.raw_context());
+        let mut cond = self.cond.map(|cond| cond.to_raw(imgui));
+        let cond = opt_ptr(cond.as_mut());
+        unsafe {
+            if let Some(pos) = self.position {
+                let mut pivot = self.pivot;
+                let (pivot_x, pivot_y) = match pivot.as_mut() {
+                    None => (null_mut(), null_mut()),
+                    Some(Vec2 { x, y }) => (x as *mut f64, y as *mut f64),
+                };
+                imgui.SetNextWindowPos(ctx, pos.x, pos.y, cond, pivot_x, pivot_y);
+            }
+            if let Some(size) = self.size {
+                imgui.SetNextWindowSize(ctx, size.x, size.y, cond);
+            }
+            if let Some((min, max)) = self.size_constraints {
+                imgui.SetNextWindowSizeConstraints(ctx, min.x, min.y, max.x, max.y);
+            }
+            if let Some(collapsed) = self.collapsed {
+                imgui.SetNextWindowCollapsed(ctx, collapsed, cond);
+            }
+            if let Some(alpha) = self.bg_alpha {
+                imgui.SetNextWindowBgAlpha(ctx, alpha);
+            }
+            if self.focused {
+                imgui.SetNextWindowFocus(ctx);
+            }
+        }
+        let mut flags = self.flags.to_raw(imgui);
+        let visible = self.ui.with_str(&self.name, |name| unsafe {
+            imgui.Begin(ctx, name, opt_ptr(self.opened), &mut flags)
+        });
+        let result = match visible {
+            true => Some(f(self.ui)),
+            false => None,
+        };
+        unsafe { imgui.End(ctx) };
+        result
+    }
+}
+
+/// Builder of a child window, see [`Ui::child`].
+#[must_use = "call .build() to draw the child window"]
+pub struct Child<'ui, L> {
+    ui: &'ui Ui<'ui>,
+    id: L,
+    size: Vec2,
+    border: bool,
+    flags: WindowFlags,
+}
+impl<'ui, L: ImStr> Child<'ui, L> {
+    /// Size of the child. Zero for an axis uses the remaining space, negative
+    /// leaves that much space to the right or bottom.
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    pub fn border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn flags(mut self, flags: WindowFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Draw the child with contents built by `f`.
+    ///
+    /// Returns `None` if the child is clipped, and `f` is not called.
+    /// `EndChild` is called in both cases.
+    pub fn build<R>(self, f: impl FnOnce(&Ui<'ui>) -> R) -> Option<R> {
+        let (imgui, ctx) = (self.ui.imgui(), self.ui.raw_context());
+        let Vec2 {
+            x: mut width,
+            y: mut height,
+        } = self.size;
+        let (mut border, mut flags) = (self.border, self.flags.to_raw(imgui));
+        let visible = self.ui.with_str(&self.id, |id| unsafe {
+            imgui.BeginChild(ctx, id, &mut width, &mut height, &mut border, &mut flags)
+        });
+        let result = match visible {
+            true => Some(f(self.ui)),
+            false => None,
+        };
+        unsafe { imgui.EndChild(ctx) };
+        result
+    }
+}
+
+impl<'frame> Ui<'frame> {
+    /// Top-level window.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::{Cond, Ui};
+    /// # fn f(ui: &Ui, open: &mut bool) {
+    /// ui.window("settings")
+    ///     .size((400.0, 300.0))
+    ///     .cond(Cond::FirstUseEver)
+    ///     .opened(open)
+    ///     .build(|ui| ui.text("Hello!"));
+    /// # }
+    /// ```
+    pub fn window<'ui, L: ImStr>(&'ui self, name: L) -> Window<'ui, L> {
+        Window {
+            ui: self,
+            name,
+            flags: WindowFlags::empty(),
+            opened: None,
+            cond: None,
+            position: None,
+            pivot: None,
+            size: None,
+            size_constraints: None,
+            collapsed: None,
+            bg_alpha: None,
+            focused: false,
+        }
+    }
+
+    /// Scrolling region inside the current window.
+    ///
+    /// ```no_run
+    /// # use reaper_imgui::Ui;
+    /// # fn f(ui: &Ui, lines: &[String]) {
+    /// ui.child("log")
+    ///     .size((0.0, -ui.frame_height_with_spacing()))
+    ///     .border(true)
+    ///     .build(|ui| lines.iter().for_each(|line| ui.text(line)));
+    /// # }
+    /// ```
+    pub fn child<'ui, L: ImStr>(&'ui self, id: L) -> Child<'ui, L> {
+        Child {
+            ui: self,
+            id,
+            size: Vec2::ZERO,
+            border: false,
+            flags: WindowFlags::empty(),
+        }
+    }
+
+    /// Child window looking like a framed widget.
+    ///
+    /// Returns `None` if the frame is clipped, and `f` is not called.
+    /// `EndChildFrame` is called in both cases.
+    pub fn child_frame<R>(
+        &self,
+        id: impl ImStr,
+        size: impl Into<Vec2>,
+        flags: WindowFlags,
+        f: impl FnOnce(&Self) -> R,
+    ) -> Option<R> {
+        let size = size.into();
+        let mut flags = flags.to_raw(self.imgui());
+        let visible = self.with_str(&id, |id| unsafe {
+            self.imgui()
+                .BeginChildFrame(self.raw_context(), id, size.x, size.y, &mut flags)
+        });
+        let result = match visible {
+            true => Some(f(self)),
+            false => None,
+        };
+        unsafe { self.imgui().EndChildFrame(self.raw_context()) };
+        result
+    }
+}